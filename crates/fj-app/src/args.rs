@@ -24,6 +24,10 @@ pub struct Args {
     /// Model deviation tolerance
     #[clap[short, long, parse(try_from_str = parse_tolerance)]]
     pub tolerance: Option<Tolerance>,
+
+    /// Snap the model's coordinates to a grid of this size
+    #[clap(long, parse(try_from_str = parse_snap_grid))]
+    pub snap_grid: Option<Scalar>,
 }
 
 impl Args {
@@ -66,3 +70,33 @@ fn parse_tolerance(input: &str) -> anyhow::Result<Tolerance> {
 
     Ok(tolerance)
 }
+
+fn parse_snap_grid(input: &str) -> anyhow::Result<Scalar> {
+    let grid_size = f64::from_str(input)?;
+    if !(grid_size.is_finite() && grid_size > 0.) {
+        return Err(anyhow!(
+            "Snap grid size must be a finite number larger than zero"
+        ));
+    }
+
+    Ok(Scalar::from_f64(grid_size))
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use super::parse_snap_grid;
+
+    #[test]
+    fn parse_snap_grid_accepts_positive_sizes() {
+        assert_eq!(parse_snap_grid("0.5").unwrap(), Scalar::from_f64(0.5));
+    }
+
+    #[test]
+    fn parse_snap_grid_rejects_invalid_sizes() {
+        for input in ["0", "-1", "NaN", "inf", "-inf", "x"] {
+            assert!(parse_snap_grid(input).is_err(), "Accepted `{input}`");
+        }
+    }
+}
@@ -61,6 +61,7 @@ fn main() -> anyhow::Result<()> {
 
     let shape_processor = ShapeProcessor {
         tolerance: args.tolerance,
+        snap_grid: args.snap_grid,
     };
 
     if let Some(path) = args.export {
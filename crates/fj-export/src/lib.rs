@@ -14,26 +14,70 @@
 
 #![warn(missing_docs)]
 
-use std::{fs::File, path::Path};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs::File,
+    io::{self, Write},
+    path::Path,
+};
 
 use thiserror::Error;
 
-use fj_interop::mesh::Mesh;
-use fj_math::{Point, Triangle};
+use fj_interop::mesh::{Color, Mesh};
+use fj_math::{Point, Scalar, Triangle, Vector};
 
 /// Export the provided mesh to the file at the given path.
 ///
 /// This function will create a file if it does not exist, and will truncate it if it does.
 ///
-/// Currently 3MF & STL file types are supported. The case insensitive file extension of
+/// Currently 3MF, STL, OBJ, PLY & GLB file types are supported. The case insensitive file extension of
 /// the provided path is used to switch between supported types.
+///
+/// PLY files are written in the binary format.
+///
+/// OBJ files are accompanied by an MTL file with the same name, which defines
+/// the colors of the mesh, unless the mesh is empty.
 pub fn export(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
     match path.extension() {
         Some(extension) if extension.to_ascii_uppercase() == "3MF" => {
-            export_3mf(mesh, path)
+            export_3mf(mesh, File::create(path)?)
         }
         Some(extension) if extension.to_ascii_uppercase() == "STL" => {
-            export_stl(mesh, path)
+            if u32::try_from(mesh.triangles().count()).is_err() {
+                return Err(Error::InvalidTriangleCount);
+            }
+
+            export_stl(mesh, File::create(path)?)?;
+            Ok(())
+        }
+        Some(extension) if extension.to_ascii_uppercase() == "OBJ" => {
+            if mesh.triangles().next().is_none() {
+                export_obj(mesh, File::create(path)?)?;
+                return Ok(());
+            }
+
+            let mtl_path = path.with_extension("mtl");
+            let mtl_file_name = mtl_path
+                .file_name()
+                .expect("Path has an extension, so it has a file name")
+                .to_string_lossy()
+                .into_owned();
+
+            export_obj_with_materials(
+                mesh,
+                File::create(path)?,
+                File::create(&mtl_path)?,
+                &mtl_file_name,
+            )?;
+            Ok(())
+        }
+        Some(extension) if extension.to_ascii_uppercase() == "GLB" => {
+            export_gltf(mesh, File::create(path)?)?;
+            Ok(())
+        }
+        Some(extension) if extension.to_ascii_uppercase() == "PLY" => {
+            export_ply(mesh, File::create(path)?, true)?;
+            Ok(())
         }
         Some(extension) => Err(Error::InvalidExtension(
             extension.to_string_lossy().into_owned(),
@@ -42,32 +86,164 @@ pub fn export(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
     }
 }
 
-fn export_3mf(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
-    let vertices = mesh.vertices().map(|vertex| vertex.into()).collect();
-
-    let indices: Vec<_> = mesh.indices().collect();
-    let triangles = indices
-        .chunks(3)
-        .map(|triangle| {
-            [
-                triangle[0] as usize,
-                triangle[1] as usize,
-                triangle[2] as usize,
-            ]
-        })
-        .collect();
+/// Export the provided mesh as 3MF
+///
+/// Writes a 3MF package, which is a ZIP archive that contains the model as
+/// XML. The vertices of the mesh, which are already deduplicated, are written
+/// once, and triangles refer to them by index.
+///
+/// Each color of the mesh becomes a base material, which the triangles of that
+/// color refer to. This allows slicers that support multi-material printing to
+/// print the model in color.
+pub fn export_3mf(
+    mesh: &Mesh<Point<3>>,
+    mut writer: impl Write,
+) -> Result<(), Error> {
+    let mut archive = zip::ZipWriter::new(io::Cursor::new(Vec::new()));
+    let options = zip::write::FileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated);
 
-    let mesh = threemf::TriangleMesh {
-        vertices,
-        triangles,
-    };
+    archive.start_file("[Content_Types].xml", options)?;
+    archive.write_all(THREE_MF_CONTENT_TYPES.as_bytes())?;
+
+    archive.start_file("_rels/.rels", options)?;
+    archive.write_all(THREE_MF_RELATIONSHIPS.as_bytes())?;
+
+    archive.start_file("3D/3dmodel.model", options)?;
+    write_3mf_model(mesh, &mut archive)?;
+
+    let archive = archive.finish()?;
+    writer.write_all(archive.get_ref())?;
+
+    Ok(())
+}
+
+const THREE_MF_CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+  <Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
+  <Default Extension="model" ContentType="application/vnd.ms-package.3dmanufacturing-3dmodel+xml"/>
+</Types>
+"#;
+
+const THREE_MF_RELATIONSHIPS: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+  <Relationship Target="/3D/3dmodel.model" Id="rel0" Type="http://schemas.microsoft.com/3dmanufacturing/2013/01/3dmodel"/>
+</Relationships>
+"#;
+
+fn write_3mf_model(
+    mesh: &Mesh<Point<3>>,
+    mut writer: impl Write,
+) -> io::Result<()> {
+    const MATERIALS_ID: u32 = 1;
+    const OBJECT_ID: u32 = 2;
 
-    threemf::write(path, &mesh)?;
+    let colors = mesh
+        .triangles()
+        .map(|triangle| triangle.color)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .enumerate()
+        .map(|(index, color)| (color, index))
+        .collect::<BTreeMap<_, _>>();
+
+    writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        writer,
+        r#"<model unit="millimeter" xml:lang="en-US" xmlns="http://schemas.microsoft.com/3dmanufacturing/core/2015/02">"#
+    )?;
+    writeln!(writer, "  <resources>")?;
+
+    // A `basematerials` element must not be empty, so it's only written, if
+    // there's at least one triangle.
+    if !colors.is_empty() {
+        writeln!(writer, r#"    <basematerials id="{}">"#, MATERIALS_ID)?;
+        for &color in colors.keys() {
+            let [r, g, b, a] = color.0;
+            writeln!(
+                writer,
+                r##"      <base name="{}" displaycolor="#{:02X}{:02X}{:02X}{:02X}"/>"##,
+                escape_xml(&material_name(color)),
+                r,
+                g,
+                b,
+                a,
+            )?;
+        }
+        writeln!(writer, "    </basematerials>")?;
+    }
+
+    writeln!(writer, r#"    <object id="{}" type="model">"#, OBJECT_ID)?;
+    writeln!(writer, "      <mesh>")?;
+
+    writeln!(writer, "        <vertices>")?;
+    for vertex in mesh.vertices() {
+        let [x, y, z] = vertex.coords.components;
+        writeln!(
+            writer,
+            r#"          <vertex x="{}" y="{}" z="{}"/>"#,
+            x, y, z
+        )?;
+    }
+    writeln!(writer, "        </vertices>")?;
+
+    writeln!(writer, "        <triangles>")?;
+    let indices = mesh.indices().collect::<Vec<_>>();
+    for (triangle, indices) in mesh.triangles().zip(indices.chunks(3)) {
+        writeln!(
+            writer,
+            r#"          <triangle v1="{}" v2="{}" v3="{}" pid="{}" p1="{}"/>"#,
+            indices[0],
+            indices[1],
+            indices[2],
+            MATERIALS_ID,
+            colors[&triangle.color],
+        )?;
+    }
+    writeln!(writer, "        </triangles>")?;
+
+    writeln!(writer, "      </mesh>")?;
+    writeln!(writer, "    </object>")?;
+    writeln!(writer, "  </resources>")?;
+    writeln!(writer, "  <build>")?;
+    writeln!(writer, r#"    <item objectid="{}"/>"#, OBJECT_ID)?;
+    writeln!(writer, "  </build>")?;
+    writeln!(writer, "</model>")?;
 
     Ok(())
 }
 
-fn export_stl(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
+/// Escape a string, so it can be used in an XML attribute value
+fn escape_xml(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// Export the provided mesh as binary STL
+///
+/// Writes one STL triangle per triangle of the mesh, with a normal that is
+/// computed from the winding of its vertices. Counter-clockwise triangles, as
+/// seen from the front, result in normals that point towards the viewer, as
+/// STL expects.
+///
+/// Returns an error of kind [`io::ErrorKind::InvalidInput`], if the mesh has
+/// more triangles than binary STL supports.
+pub fn export_stl(
+    mesh: &Mesh<Point<3>>,
+    mut writer: impl Write,
+) -> io::Result<()> {
     let points = mesh
         .triangles()
         .map(|triangle| triangle.inner.points())
@@ -94,24 +270,375 @@ fn export_stl(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
         })
         .collect::<Vec<_>>();
 
-    let mut file = File::create(path)?;
+    let num_triangles = triangles.len().try_into().map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "maximum triangle count exceeded",
+        )
+    })?;
 
     let binary_stl_file = stl::BinaryStlFile {
         header: stl::BinaryStlHeader {
             header: [0u8; 80],
-            num_triangles: triangles
-                .len()
-                .try_into()
-                .map_err(|_| Error::InvalidTriangleCount)?,
+            num_triangles,
         },
         triangles,
     };
 
-    stl::write_stl(&mut file, &binary_stl_file)?;
+    stl::write_stl(&mut writer, &binary_stl_file)
+}
+
+/// Export the provided mesh as Wavefront OBJ
+///
+/// Writes the vertices of the mesh, which are already deduplicated, and one
+/// face per triangle that refers to them by index. The colors of the mesh are
+/// not exported. Use [`export_obj_with_materials`] for that.
+pub fn export_obj(mesh: &Mesh<Point<3>>, writer: impl Write) -> io::Result<()> {
+    write_obj(mesh, writer, None)
+}
+
+/// Export the provided mesh as Wavefront OBJ, with a companion MTL file
+///
+/// Like [`export_obj`], but also writes a material for each color of the mesh
+/// to `mtl_writer`. The faces of the OBJ file are grouped by color, and refer
+/// to those materials. `mtl_file_name` is the name under which the OBJ file
+/// refers to the MTL file, relative to the OBJ file.
+pub fn export_obj_with_materials(
+    mesh: &Mesh<Point<3>>,
+    obj_writer: impl Write,
+    mut mtl_writer: impl Write,
+    mtl_file_name: &str,
+) -> io::Result<()> {
+    let colors = mesh
+        .triangles()
+        .map(|triangle| triangle.color)
+        .collect::<BTreeSet<_>>();
+
+    for color in colors {
+        let [r, g, b, a] = color.0.map(|channel| f64::from(channel) / 255.);
+
+        writeln!(mtl_writer, "newmtl {}", material_name(color))?;
+        writeln!(mtl_writer, "Kd {} {} {}", r, g, b)?;
+        writeln!(mtl_writer, "d {}", a)?;
+    }
+
+    write_obj(mesh, obj_writer, Some(mtl_file_name))
+}
+
+fn write_obj(
+    mesh: &Mesh<Point<3>>,
+    mut writer: impl Write,
+    mtl_file_name: Option<&str>,
+) -> io::Result<()> {
+    writeln!(writer, "# Exported by Fornjot")?;
+
+    if let Some(mtl_file_name) = mtl_file_name {
+        writeln!(writer, "mtllib {}", mtl_file_name)?;
+    }
+
+    for vertex in mesh.vertices() {
+        let [x, y, z] = vertex.coords.components;
+        writeln!(writer, "v {} {} {}", x, y, z)?;
+    }
+
+    let indices = mesh.indices().collect::<Vec<_>>();
+    let mut faces_by_color: BTreeMap<Color, Vec<&[u32]>> = BTreeMap::new();
+    for (triangle, indices) in mesh.triangles().zip(indices.chunks(3)) {
+        faces_by_color
+            .entry(triangle.color)
+            .or_default()
+            .push(indices);
+    }
+
+    for (color, faces) in faces_by_color {
+        if mtl_file_name.is_some() {
+            writeln!(writer, "usemtl {}", material_name(color))?;
+        }
+
+        for face in faces {
+            // Indices in OBJ files start at 1.
+            let [a, b, c] =
+                [face[0], face[1], face[2]].map(|i| u64::from(i) + 1);
+            writeln!(writer, "f {} {} {}", a, b, c)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Export the provided mesh as PLY
+///
+/// Writes the vertices of the mesh, which are already deduplicated, and one
+/// face per triangle that refers to them by index. Writes the little-endian
+/// binary format, if `binary` is `true`, the ASCII format otherwise.
+///
+/// PLY supports colors per vertex, while the colors of the mesh are defined
+/// per triangle. Each vertex gets the color of the first triangle that refers
+/// to it.
+pub fn export_ply(
+    mesh: &Mesh<Point<3>>,
+    mut writer: impl Write,
+    binary: bool,
+) -> io::Result<()> {
+    let vertices = mesh.vertices().collect::<Vec<_>>();
+    let indices = mesh.indices().collect::<Vec<_>>();
+
+    let colors = vertex_colors(mesh);
+
+    let format = if binary {
+        "binary_little_endian"
+    } else {
+        "ascii"
+    };
+
+    writeln!(writer, "ply")?;
+    writeln!(writer, "format {} 1.0", format)?;
+    writeln!(writer, "comment Exported by Fornjot")?;
+    writeln!(writer, "element vertex {}", vertices.len())?;
+    for property in ["x", "y", "z"] {
+        writeln!(writer, "property float {}", property)?;
+    }
+    for property in ["red", "green", "blue", "alpha"] {
+        writeln!(writer, "property uchar {}", property)?;
+    }
+    writeln!(writer, "element face {}", indices.len() / 3)?;
+    writeln!(writer, "property list uchar uint vertex_indices")?;
+    writeln!(writer, "end_header")?;
+
+    for (vertex, color) in vertices.iter().zip(colors) {
+        let [x, y, z] = vertex.coords.components.map(|s| s.into_f32());
+
+        if binary {
+            for coord in [x, y, z] {
+                writer.write_all(&coord.to_le_bytes())?;
+            }
+            writer.write_all(&color.0)?;
+        } else {
+            let [r, g, b, a] = color.0;
+            writeln!(writer, "{} {} {} {} {} {} {}", x, y, z, r, g, b, a)?;
+        }
+    }
+
+    for face in indices.chunks(3) {
+        if binary {
+            writer.write_all(&[3])?;
+            for &i in face {
+                writer.write_all(&i.to_le_bytes())?;
+            }
+        } else {
+            writeln!(writer, "3 {} {} {}", face[0], face[1], face[2])?;
+        }
+    }
 
     Ok(())
 }
 
+/// Export the provided mesh as binary glTF (GLB)
+///
+/// Writes a self-contained GLB file with a single mesh, whose vertices are
+/// the already deduplicated vertices of the mesh. Each vertex has a position,
+/// a normal, and a color.
+///
+/// The normal of a vertex is the average of the normals of the triangles that
+/// refer to it, weighted by their area, which makes the model shade smoothly.
+/// Colors are defined per vertex, like in [`export_ply`].
+pub fn export_gltf(
+    mesh: &Mesh<Point<3>>,
+    mut writer: impl Write,
+) -> io::Result<()> {
+    const ARRAY_BUFFER: u32 = 34962;
+    const ELEMENT_ARRAY_BUFFER: u32 = 34963;
+    const UNSIGNED_BYTE: u32 = 5121;
+    const UNSIGNED_INT: u32 = 5125;
+    const FLOAT: u32 = 5126;
+
+    let vertices = mesh.vertices().collect::<Vec<_>>();
+    let indices = mesh.indices().collect::<Vec<_>>();
+    let colors = vertex_colors(mesh);
+
+    let mut normals = vec![Vector::from([0., 0., 0.]); vertices.len()];
+    for triangle in indices.chunks(3) {
+        let [a, b, c] = [0, 1, 2].map(|i| vertices[triangle[i] as usize]);
+
+        // The magnitude of the cross product is proportional to the area of
+        // the triangle, which weights the average accordingly.
+        let normal = (b - a).cross(&(c - a));
+        for &i in triangle {
+            normals[i as usize] = normals[i as usize] + normal;
+        }
+    }
+
+    let positions = vertices
+        .iter()
+        .map(|vertex| vertex.coords.components.map(|s| s.into_f32()))
+        .collect::<Vec<_>>();
+    let normals = normals
+        .into_iter()
+        .map(|normal| {
+            if normal.magnitude() == Scalar::ZERO {
+                [0., 0., 1.]
+            } else {
+                normal.normalize().components.map(|s| s.into_f32())
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // All elements are 4 bytes or multiples thereof, so all buffer views are
+    // aligned to 4 bytes, as glTF requires.
+    let mut buffer = Vec::new();
+    let mut buffer_views = Vec::new();
+    let mut push_view = |bytes: Vec<u8>, target: u32| {
+        buffer_views.push(format!(
+            r#"{{"buffer":0,"byteOffset":{},"byteLength":{},"target":{}}}"#,
+            buffer.len(),
+            bytes.len(),
+            target,
+        ));
+        buffer.extend(bytes);
+    };
+
+    push_view(
+        positions
+            .iter()
+            .flatten()
+            .flat_map(|coord| coord.to_le_bytes())
+            .collect(),
+        ARRAY_BUFFER,
+    );
+    push_view(
+        normals
+            .iter()
+            .flatten()
+            .flat_map(|coord| coord.to_le_bytes())
+            .collect(),
+        ARRAY_BUFFER,
+    );
+    push_view(
+        colors.iter().flat_map(|color| color.0).collect(),
+        ARRAY_BUFFER,
+    );
+    push_view(
+        indices.iter().flat_map(|i| i.to_le_bytes()).collect(),
+        ELEMENT_ARRAY_BUFFER,
+    );
+
+    // glTF doesn't allow empty accessors, so an empty mesh results in a scene
+    // without any nodes.
+    let json = if indices.is_empty() {
+        r#"{"asset":{"version":"2.0","generator":"Fornjot"},"scene":0,"scenes":[{}]}"#
+            .to_string()
+    } else {
+        let [min, max] = [f32::min, f32::max].map(|f| {
+            let bound = positions
+                .iter()
+                .copied()
+                .reduce(|a, b| [0, 1, 2].map(|i| f(a[i], b[i])))
+                .expect("Mesh with triangles has vertices");
+            format!("[{},{},{}]", bound[0], bound[1], bound[2])
+        });
+
+        let accessors = [
+            format!(
+                r#"{{"bufferView":0,"componentType":{},"count":{},"type":"VEC3","min":{},"max":{}}}"#,
+                FLOAT,
+                vertices.len(),
+                min,
+                max,
+            ),
+            format!(
+                r#"{{"bufferView":1,"componentType":{},"count":{},"type":"VEC3"}}"#,
+                FLOAT,
+                vertices.len(),
+            ),
+            format!(
+                r#"{{"bufferView":2,"componentType":{},"normalized":true,"count":{},"type":"VEC4"}}"#,
+                UNSIGNED_BYTE,
+                vertices.len(),
+            ),
+            format!(
+                r#"{{"bufferView":3,"componentType":{},"count":{},"type":"SCALAR"}}"#,
+                UNSIGNED_INT,
+                indices.len(),
+            ),
+        ];
+
+        format!(
+            concat!(
+                r#"{{"asset":{{"version":"2.0","generator":"Fornjot"}},"#,
+                r#""scene":0,"scenes":[{{"nodes":[0]}}],"#,
+                r#""nodes":[{{"mesh":0}}],"#,
+                r#""meshes":[{{"primitives":[{{"#,
+                r#""attributes":{{"POSITION":0,"NORMAL":1,"COLOR_0":2}},"#,
+                r#""indices":3,"mode":4}}]}}],"#,
+                r#""accessors":[{}],"bufferViews":[{}],"#,
+                r#""buffers":[{{"byteLength":{}}}]}}"#,
+            ),
+            accessors.join(","),
+            buffer_views.join(","),
+            buffer.len(),
+        )
+    };
+
+    // Chunks must be padded to 4 bytes. The JSON chunk is padded with spaces,
+    // the binary chunk with zeros.
+    let mut json = json.into_bytes();
+    while json.len() % 4 != 0 {
+        json.push(b' ');
+    }
+    while buffer.len() % 4 != 0 {
+        buffer.push(0);
+    }
+
+    let mut chunks = vec![(json.as_slice(), b"JSON")];
+    if !indices.is_empty() {
+        chunks.push((buffer.as_slice(), b"BIN\0"));
+    }
+
+    let length =
+        12 + chunks.iter().map(|(data, _)| 8 + data.len()).sum::<usize>();
+    let length = u32::try_from(length).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "Mesh too large for binary glTF",
+        )
+    })?;
+
+    writer.write_all(b"glTF")?;
+    writer.write_all(&2u32.to_le_bytes())?;
+    writer.write_all(&length.to_le_bytes())?;
+
+    for (data, kind) in chunks {
+        // The total length fits into a `u32`, so each chunk does too.
+        writer.write_all(&(data.len() as u32).to_le_bytes())?;
+        writer.write_all(kind)?;
+        writer.write_all(data)?;
+    }
+
+    Ok(())
+}
+
+/// Compute the color of each vertex
+///
+/// The colors of a mesh are defined per triangle. Each vertex gets the color
+/// of the first triangle that refers to it.
+fn vertex_colors(mesh: &Mesh<Point<3>>) -> Vec<Color> {
+    let mut colors = vec![None; mesh.vertices().count()];
+
+    let indices = mesh.indices().collect::<Vec<_>>();
+    for (triangle, indices) in mesh.triangles().zip(indices.chunks(3)) {
+        for &i in indices {
+            colors[i as usize].get_or_insert(triangle.color);
+        }
+    }
+
+    colors.into_iter().map(Option::unwrap_or_default).collect()
+}
+
+fn material_name(color: Color) -> String {
+    let [r, g, b, a] = color.0;
+    format!("color_{:02x}{:02x}{:02x}{:02x}", r, g, b, a)
+}
+
 /// An error that can occur while exporting
 #[derive(Debug, Error)]
 pub enum Error {
@@ -131,7 +658,257 @@ pub enum Error {
     #[error("maximum triangle count exceeded")]
     InvalidTriangleCount,
 
-    /// Threemf error whilst exporting to 3MF file
-    #[error("threemf error whilst exporting to 3MF file")]
-    ThreeMF(#[from] threemf::Error),
+    /// ZIP error whilst exporting to 3MF file
+    #[error("ZIP error whilst exporting to 3MF file")]
+    Zip(#[from] zip::result::ZipError),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Read};
+
+    use fj_interop::mesh::{Color, Mesh};
+    use fj_math::Point;
+
+    use super::{
+        escape_xml, export_3mf, export_gltf, export_obj,
+        export_obj_with_materials, export_ply, export_stl,
+    };
+
+    #[test]
+    fn stl_single_triangle() {
+        let mut mesh = Mesh::new();
+        mesh.push_triangle(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]].map(Point::from),
+            Color::default(),
+        );
+
+        let mut stl = Vec::new();
+        export_stl(&mesh, &mut stl).unwrap();
+
+        // 80 bytes of header, the triangle count, and 50 bytes per triangle.
+        assert_eq!(stl.len(), 84 + 50);
+        assert_eq!(stl[..80], [0; 80]);
+        assert_eq!(stl[80..84], 1u32.to_le_bytes());
+
+        let floats = stl[84..132]
+            .chunks(4)
+            .map(|bytes| f32::from_le_bytes(bytes.try_into().unwrap()))
+            .collect::<Vec<_>>();
+
+        // The triangle is counter-clockwise, when seen from above, so its
+        // normal points up.
+        assert_eq!(floats[..3], [0., 0., 1.]);
+        assert_eq!(floats[3..], [0., 0., 0., 1., 0., 0., 0., 1., 0.]);
+        assert_eq!(stl[132..134], [0, 0]);
+    }
+
+    #[test]
+    fn three_mf() {
+        let red = Color([255, 0, 0, 255]);
+        let blue = Color([0, 0, 255, 128]);
+
+        let mut mesh = Mesh::new();
+        mesh.push_triangle(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]].map(Point::from),
+            red,
+        );
+        mesh.push_triangle(
+            [[1., 0., 0.], [1., 1., 0.], [0., 1., 0.]].map(Point::from),
+            blue,
+        );
+
+        let mut three_mf = Vec::new();
+        export_3mf(&mesh, &mut three_mf).unwrap();
+
+        // Reading the archive requires a valid central directory.
+        let mut archive =
+            zip::ZipArchive::new(io::Cursor::new(three_mf)).unwrap();
+        assert!(archive.by_name("[Content_Types].xml").is_ok());
+        assert!(archive.by_name("_rels/.rels").is_ok());
+
+        let mut model = String::new();
+        archive
+            .by_name("3D/3dmodel.model")
+            .unwrap()
+            .read_to_string(&mut model)
+            .unwrap();
+
+        assert_eq!(model.matches("<vertex ").count(), 4);
+        assert!(model.contains(r##"displaycolor="#0000FF80""##));
+        assert!(model.contains(r##"displaycolor="#FF0000FF""##));
+
+        // Colors are sorted, so blue comes first.
+        assert!(model
+            .contains(r#"<triangle v1="0" v2="1" v3="2" pid="1" p1="1"/>"#));
+        assert!(model
+            .contains(r#"<triangle v1="1" v2="3" v3="2" pid="1" p1="0"/>"#));
+    }
+
+    #[test]
+    fn xml_escaping() {
+        assert_eq!(
+            escape_xml(r#"<a & 'b' "c">"#),
+            "&lt;a &amp; &apos;b&apos; &quot;c&quot;&gt;"
+        );
+    }
+
+    #[test]
+    fn obj() {
+        let red = Color([255, 0, 0, 255]);
+        let blue = Color([0, 0, 255, 255]);
+
+        let mut mesh = Mesh::new();
+        mesh.push_triangle(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]].map(Point::from),
+            blue,
+        );
+        mesh.push_triangle(
+            [[1., 0., 0.], [1., 1., 0.], [0., 1., 0.]].map(Point::from),
+            red,
+        );
+
+        let mut obj = Vec::new();
+        export_obj(&mesh, &mut obj).unwrap();
+        assert_eq!(
+            String::from_utf8(obj).unwrap(),
+            "# Exported by Fornjot\n\
+            v 0 0 0\nv 1 0 0\nv 0 1 0\nv 1 1 0\n\
+            f 1 2 3\nf 2 4 3\n"
+        );
+
+        let mut obj = Vec::new();
+        let mut mtl = Vec::new();
+        export_obj_with_materials(&mesh, &mut obj, &mut mtl, "model.mtl")
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(obj).unwrap(),
+            "# Exported by Fornjot\nmtllib model.mtl\n\
+            v 0 0 0\nv 1 0 0\nv 0 1 0\nv 1 1 0\n\
+            usemtl color_0000ffff\nf 1 2 3\n\
+            usemtl color_ff0000ff\nf 2 4 3\n"
+        );
+        assert_eq!(
+            String::from_utf8(mtl).unwrap(),
+            "newmtl color_0000ffff\nKd 0 0 1\nd 1\n\
+            newmtl color_ff0000ff\nKd 1 0 0\nd 1\n"
+        );
+    }
+
+    #[test]
+    fn ply() {
+        let red = Color([255, 0, 0, 255]);
+        let blue = Color([0, 0, 255, 128]);
+
+        let mut mesh = Mesh::new();
+        mesh.push_triangle(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]].map(Point::from),
+            red,
+        );
+        mesh.push_triangle(
+            [[1., 0., 0.], [1., 1., 0.], [0., 1., 0.]].map(Point::from),
+            blue,
+        );
+
+        let header = |format: &str| {
+            format!(
+                "ply\nformat {} 1.0\ncomment Exported by Fornjot\n\
+                element vertex 4\n\
+                property float x\nproperty float y\nproperty float z\n\
+                property uchar red\nproperty uchar green\n\
+                property uchar blue\nproperty uchar alpha\n\
+                element face 2\n\
+                property list uchar uint vertex_indices\nend_header\n",
+                format
+            )
+        };
+
+        let mut ply = Vec::new();
+        export_ply(&mesh, &mut ply, false).unwrap();
+        assert_eq!(
+            String::from_utf8(ply).unwrap(),
+            header("ascii")
+                + "0 0 0 255 0 0 255\n1 0 0 255 0 0 255\n\
+                0 1 0 255 0 0 255\n1 1 0 0 0 255 128\n\
+                3 0 1 2\n3 1 3 2\n"
+        );
+
+        let mut ply = Vec::new();
+        export_ply(&mesh, &mut ply, true).unwrap();
+
+        let header = header("binary_little_endian");
+        assert_eq!(ply[..header.len()], *header.as_bytes());
+
+        // Each vertex has three floats and four color channels. Each face has
+        // its vertex count and three indices.
+        let body = &ply[header.len()..];
+        assert_eq!(body.len(), 4 * (3 * 4 + 4) + 2 * (1 + 3 * 4));
+
+        let last_vertex = &body[3 * 16..4 * 16];
+        assert_eq!(last_vertex[..4], 1f32.to_le_bytes());
+        assert_eq!(last_vertex[12..], [0, 0, 255, 128]);
+
+        let last_face = &body[4 * 16 + 13..];
+        assert_eq!(last_face[0], 3);
+        assert_eq!(last_face[1..5], 1u32.to_le_bytes());
+    }
+
+    #[test]
+    fn gltf() {
+        let red = Color([255, 0, 0, 255]);
+
+        let mut mesh = Mesh::new();
+        mesh.push_triangle(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]].map(Point::from),
+            red,
+        );
+        mesh.push_triangle(
+            [[1., 0., 0.], [1., 1., 0.], [0., 1., 0.]].map(Point::from),
+            red,
+        );
+
+        let mut glb = Vec::new();
+        export_gltf(&mesh, &mut glb).unwrap();
+
+        let read_u32 = |offset: usize| {
+            u32::from_le_bytes(glb[offset..offset + 4].try_into().unwrap())
+                as usize
+        };
+
+        assert_eq!(glb[..4], *b"glTF");
+        assert_eq!(read_u32(4), 2);
+        assert_eq!(read_u32(8), glb.len());
+
+        let json_length = read_u32(12);
+        assert_eq!(glb[16..20], *b"JSON");
+        assert_eq!(json_length % 4, 0);
+
+        let json = std::str::from_utf8(&glb[20..20 + json_length]).unwrap();
+        assert!(json.contains(r#""min":[0,0,0],"max":[1,1,0]"#));
+        assert!(json.contains(r#""normalized":true"#));
+
+        let bin = 20 + json_length;
+        let bin_length = read_u32(bin);
+        assert_eq!(glb[bin + 4..bin + 8], *b"BIN\0");
+
+        // Positions, normals, colors, and indices.
+        assert_eq!(bin_length, 4 * 12 + 4 * 12 + 4 * 4 + 6 * 4);
+
+        // The mesh is flat, so all normals point up.
+        let normals = &glb[bin + 8 + 4 * 12..bin + 8 + 4 * 24];
+        for normal in normals.chunks(12) {
+            let normal = normal
+                .chunks(4)
+                .map(|bytes| f32::from_le_bytes(bytes.try_into().unwrap()))
+                .collect::<Vec<_>>();
+            assert_eq!(normal, [0., 0., 1.]);
+        }
+    }
+
+    #[test]
+    fn obj_empty_mesh() {
+        let mut obj = Vec::new();
+        export_obj(&Mesh::new(), &mut obj).unwrap();
+        assert_eq!(String::from_utf8(obj).unwrap(), "# Exported by Fornjot\n");
+    }
 }
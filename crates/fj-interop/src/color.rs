@@ -0,0 +1,201 @@
+//! Color conversion, blending, and palettes
+//!
+//! See [`Color`] and [`palette`].
+
+use crate::mesh::Color;
+
+impl Color {
+    /// Construct a color from hue, saturation, and value
+    ///
+    /// `hue` is given in degrees, and wraps around at 360. `saturation` and
+    /// `value` are expected to be in the range `[0, 1]`, and are clamped to
+    /// that range. The resulting color is fully opaque.
+    pub fn from_hsv(hue: f64, saturation: f64, value: f64) -> Self {
+        let hue = hue.rem_euclid(360.) / 60.;
+        let saturation = saturation.clamp(0., 1.);
+        let value = value.clamp(0., 1.);
+
+        let chroma = value * saturation;
+        let x = chroma * (1. - (hue % 2. - 1.).abs());
+        let m = value - chroma;
+
+        let [r, g, b] = match hue as u8 {
+            0 => [chroma, x, 0.],
+            1 => [x, chroma, 0.],
+            2 => [0., chroma, x],
+            3 => [0., x, chroma],
+            4 => [x, 0., chroma],
+            _ => [chroma, 0., x],
+        };
+
+        let [r, g, b] = [r, g, b].map(|channel| to_u8(channel + m));
+        Self([r, g, b, 255])
+    }
+
+    /// Blend two colors
+    ///
+    /// Returns `a` for `t == 0`, `b` for `t == 1`, and interpolates linearly
+    /// in between. `t` is clamped to the range `[0, 1]`.
+    ///
+    /// The color channels are interpolated in linear RGB, not in sRGB, which
+    /// the channels of [`Color`] are defined in. This avoids the dark, muddy
+    /// intermediate colors that blending in sRGB produces. The alpha channel
+    /// is not gamma-encoded, and is interpolated directly.
+    pub fn blend(a: Self, b: Self, t: f64) -> Self {
+        let t = t.clamp(0., 1.);
+        let lerp = |a: f64, b: f64| a + (b - a) * t;
+
+        let [r, g, b_, alpha] = [0, 1, 2, 3].map(|i| {
+            let [a, b] = [a.0[i], b.0[i]].map(|c| f64::from(c) / 255.);
+
+            if i == 3 {
+                to_u8(lerp(a, b))
+            } else {
+                let linear = lerp(srgb_to_linear(a), srgb_to_linear(b));
+                to_u8(linear_to_srgb(linear))
+            }
+        });
+
+        Self([r, g, b_, alpha])
+    }
+
+    /// Interpolate linearly between two colors
+    ///
+    /// Returns `self` for `t == 0`, `other` for `t == 1`, and interpolates
+    /// linearly in between. `t` is clamped to the range `[0, 1]`, and the
+    /// resulting channels are rounded to the nearest value.
+    ///
+    /// Unlike [`Color::blend`], this interpolates the channels directly, as
+    /// they are stored. This is cheaper, and the result is easier to predict,
+    /// which makes it suitable for gradients along a model.
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        let t = t.clamp(0., 1.);
+
+        let channels = [0, 1, 2, 3].map(|i| {
+            let [a, b] = [self.0[i], other.0[i]].map(f32::from);
+            (a + (b - a) * t).round() as u8
+        });
+
+        Self(channels)
+    }
+
+    /// Return a copy of this color, with a different alpha channel
+    pub fn with_alpha(mut self, alpha: u8) -> Self {
+        self.0[3] = alpha;
+        self
+    }
+
+    /// Convert the color into 8-bit RGBA channels
+    pub fn to_rgba8(self) -> [u8; 4] {
+        self.0
+    }
+}
+
+/// Generate a palette of distinguishable colors
+///
+/// Returns `n` fully opaque colors. Their hues are spread using the golden
+/// angle, which means adjacent colors in the palette are always far apart,
+/// and the palette stays distinguishable no matter how many colors are
+/// requested. The same `n` always results in the same palette.
+pub fn palette(n: usize) -> Vec<Color> {
+    const GOLDEN_ANGLE: f64 = 137.507_764_050_037_85;
+
+    (0..n)
+        .map(|i| {
+            let hue = i as f64 * GOLDEN_ANGLE;
+
+            // Alternate the value a bit, to make colors with similar hues
+            // easier to tell apart.
+            let value = if i % 2 == 0 { 0.95 } else { 0.75 };
+
+            Color::from_hsv(hue, 0.7, value)
+        })
+        .collect()
+}
+
+fn srgb_to_linear(c: f64) -> f64 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: f64) -> f64 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1. / 2.4) - 0.055
+    }
+}
+
+fn to_u8(c: f64) -> u8 {
+    (c.clamp(0., 1.) * 255.).round() as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mesh::Color;
+
+    use super::palette;
+
+    #[test]
+    fn from_hsv() {
+        assert_eq!(Color::from_hsv(0., 1., 1.), Color([255, 0, 0, 255]));
+        assert_eq!(Color::from_hsv(120., 1., 1.), Color([0, 255, 0, 255]));
+        assert_eq!(Color::from_hsv(240., 1., 1.), Color([0, 0, 255, 255]));
+        assert_eq!(Color::from_hsv(360., 1., 1.), Color([255, 0, 0, 255]));
+        assert_eq!(Color::from_hsv(60., 0., 0.5), Color([128, 128, 128, 255]));
+    }
+
+    #[test]
+    fn blend() {
+        let black = Color([0, 0, 0, 0]);
+        let white = Color([255, 255, 255, 255]);
+
+        assert_eq!(Color::blend(black, white, 0.), black);
+        assert_eq!(Color::blend(black, white, 1.), white);
+
+        // Blending happens in linear RGB, so the middle is brighter than the
+        // sRGB value 128, while the alpha channel is interpolated directly.
+        assert_eq!(
+            Color::blend(black, white, 0.5).to_rgba8(),
+            [188, 188, 188, 128]
+        );
+    }
+
+    #[test]
+    fn lerp() {
+        let a = Color([0, 100, 255, 255]);
+        let b = Color([255, 101, 0, 0]);
+
+        assert_eq!(a.lerp(b, 0.), a);
+        assert_eq!(a.lerp(b, 1.), b);
+
+        // Channels are rounded, not truncated.
+        assert_eq!(a.lerp(b, 0.5), Color([128, 101, 128, 128]));
+        assert_eq!(a.lerp(b, 0.25), Color([64, 100, 191, 191]));
+
+        // `t` is clamped.
+        assert_eq!(a.lerp(b, -1.), a);
+        assert_eq!(a.lerp(b, 2.), b);
+    }
+
+    #[test]
+    fn with_alpha() {
+        let color = Color([1, 2, 3, 255]).with_alpha(128);
+        assert_eq!(color, Color([1, 2, 3, 128]));
+    }
+
+    #[test]
+    fn palette_is_distinct() {
+        let colors = palette(16);
+        assert_eq!(colors.len(), 16);
+
+        for (i, a) in colors.iter().enumerate() {
+            for b in &colors[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+    }
+}
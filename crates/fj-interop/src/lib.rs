@@ -14,7 +14,9 @@
 
 #![warn(missing_docs)]
 
+pub mod color;
 pub mod debug;
 pub mod mesh;
+pub mod mesh_csg;
 pub mod processed_shape;
 pub mod status_report;
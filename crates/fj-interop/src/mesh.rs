@@ -1,8 +1,12 @@
 //! A triangle mesh
 
-use std::{collections::HashMap, hash::Hash};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    hash::Hash,
+    iter, mem,
+};
 
-use fj_math::Point;
+use fj_math::{Aabb, Point, Scalar, Vector};
 
 /// A triangle mesh
 pub struct Mesh<V> {
@@ -88,6 +92,583 @@ impl Mesh<Point<3>> {
             color,
         });
     }
+
+    /// Check the mesh for problems
+    ///
+    /// Returns a report that lists all problems that were found. See
+    /// [`MeshReport`] for the kinds of problems that are checked.
+    pub fn validate(&self) -> MeshReport {
+        let mut report = MeshReport::default();
+
+        let triangles = self
+            .indices
+            .chunks(3)
+            .map(|triangle| [triangle[0], triangle[1], triangle[2]])
+            .collect::<Vec<_>>();
+
+        // For each edge, the directions in which triangles refer to it. `true`
+        // means from the lower to the higher index.
+        let mut edges: BTreeMap<[Index; 2], Vec<bool>> = BTreeMap::new();
+        let mut triangles_by_vertex: BTreeMap<Index, Vec<usize>> =
+            BTreeMap::new();
+
+        for (i, &[a, b, c]) in triangles.iter().enumerate() {
+            let [pa, pb, pc] =
+                [a, b, c].map(|index| self.vertices[index as usize]);
+            let area = (pb - pa).cross(&(pc - pa)).magnitude();
+            if a == b || b == c || c == a || area == Scalar::ZERO {
+                report.degenerate_triangles.push(i);
+            }
+
+            for [start, end] in [[a, b], [b, c], [c, a]] {
+                if start == end {
+                    continue;
+                }
+
+                edges
+                    .entry([start.min(end), start.max(end)])
+                    .or_default()
+                    .push(start < end);
+            }
+
+            for index in BTreeSet::from([a, b, c]) {
+                triangles_by_vertex.entry(index).or_default().push(i);
+            }
+        }
+
+        for (edge, directions) in edges {
+            match directions.as_slice() {
+                [_] => report.boundary_edges.push(edge),
+                [a, b] => {
+                    if a == b {
+                        report.inconsistent_winding.push(edge);
+                    }
+                }
+                _ => report.non_manifold_edges.push(edge),
+            }
+        }
+
+        for (&vertex, incident) in &triangles_by_vertex {
+            let num_fans = count_fans(vertex, incident, &triangles);
+            if num_fans > 1 {
+                report.non_manifold_vertices.push(vertex);
+            }
+        }
+
+        let mut indices_by_position = BTreeMap::new();
+        for (i, &position) in self.vertices.iter().enumerate() {
+            let i = i as Index;
+
+            if let Some(&first) = indices_by_position.get(&position) {
+                report.duplicate_vertices.push([first, i]);
+            } else {
+                indices_by_position.insert(position, i);
+            }
+
+            if !triangles_by_vertex.contains_key(&i) {
+                report.unreferenced_vertices.push(i);
+            }
+        }
+
+        report
+    }
+
+    /// Recolor the triangles that fall into the provided regions
+    ///
+    /// A triangle falls into a region, if its centroid does. This means
+    /// triangles that span the boundary of a region are recolored based on
+    /// where most of them lies. If a triangle falls into multiple regions, the
+    /// region that comes last wins.
+    ///
+    /// Returns the number of triangles that were recolored.
+    pub fn recolor_regions(
+        &mut self,
+        regions: impl IntoIterator<Item = (Region, Color)>,
+    ) -> usize {
+        let regions = regions.into_iter().collect::<Vec<_>>();
+        let mut num_recolored = 0;
+
+        for triangle in &mut self.triangles {
+            let [a, b, c] = triangle.inner.points();
+            let centroid = Point {
+                coords: (a.coords + b.coords + c.coords) / 3.,
+            };
+
+            let color = regions
+                .iter()
+                .rev()
+                .find(|(region, _)| region.contains(&centroid))
+                .map(|&(_, color)| color);
+
+            if let Some(color) = color {
+                triangle.color = color;
+                num_recolored += 1;
+            }
+        }
+
+        num_recolored
+    }
+
+    /// Merge another mesh into this one
+    ///
+    /// Adds all triangles of `other` to this mesh. Vertices of `other` that
+    /// are within `tolerance` of a vertex of this mesh are replaced with that
+    /// vertex. This closes the cracks that would otherwise be left where the
+    /// meshes share an edge, but approximated it slightly differently.
+    /// Triangles that degenerate as a result are dropped.
+    pub fn merge(
+        &mut self,
+        other: Mesh<Point<3>>,
+        tolerance: impl Into<Scalar>,
+    ) {
+        let tolerance = tolerance.into().into_f64();
+
+        // Sort the vertices of this mesh into a grid, so vertices near a given
+        // point can be found without looking at all of them.
+        let cell_of = |point: Point<3>| {
+            point
+                .coords
+                .components
+                .map(|coord| (coord.into_f64() / tolerance).floor() as i64)
+        };
+        let mut grid: HashMap<[i64; 3], Vec<Point<3>>> = HashMap::new();
+        if tolerance > 0. {
+            for &vertex in &self.vertices {
+                grid.entry(cell_of(vertex)).or_default().push(vertex);
+            }
+        }
+
+        let snapped = other
+            .vertices
+            .iter()
+            .map(|&vertex| {
+                if tolerance <= 0. {
+                    return (vertex, vertex);
+                }
+
+                let [x, y, z] = cell_of(vertex);
+                let mut nearest = None;
+                for dx in -1..=1 {
+                    for dy in -1..=1 {
+                        for dz in -1..=1 {
+                            let cell = [x + dx, y + dy, z + dz];
+                            for &candidate in
+                                grid.get(&cell).into_iter().flatten()
+                            {
+                                let distance =
+                                    (candidate - vertex).magnitude().into_f64();
+                                let is_nearer = match nearest {
+                                    Some((_, nearest)) => distance < nearest,
+                                    None => true,
+                                };
+                                if distance <= tolerance && is_nearer {
+                                    nearest = Some((candidate, distance));
+                                }
+                            }
+                        }
+                    }
+                }
+
+                let snapped =
+                    nearest.map_or(vertex, |(candidate, _)| candidate);
+                (vertex, snapped)
+            })
+            .collect::<HashMap<_, _>>();
+
+        for triangle in other.triangles {
+            let points = triangle.inner.points().map(|point| snapped[&point]);
+
+            if let Ok(inner) = fj_math::Triangle::from_points(points) {
+                self.push_triangle(inner, triangle.color);
+            }
+        }
+    }
+
+    /// Reduce the number of triangles, while preserving the shape of the mesh
+    ///
+    /// Collapses edges, by merging one of their vertices into the other, as
+    /// long as every vertex of the original mesh stays within `target_error`
+    /// of the simplified mesh. Vertices on boundary edges, or where triangles
+    /// of different colors meet, are never removed. Neither are collapses
+    /// made that would flip the normal of a triangle.
+    ///
+    /// Returns the number of triangles that were removed.
+    pub fn simplify(&mut self, target_error: impl Into<Scalar>) -> usize {
+        let mut simplifier = Simplifier::new(self, target_error.into());
+
+        loop {
+            let mut num_collapsed = 0;
+
+            for a in 0..simplifier.positions.len() {
+                let collapse = simplifier
+                    .neighbors(a)
+                    .into_iter()
+                    .filter_map(|b| simplifier.check_collapse(a, b))
+                    .min_by_key(|collapse| collapse.error);
+
+                if let Some(collapse) = collapse {
+                    simplifier.collapse(collapse);
+                    num_collapsed += 1;
+                }
+            }
+
+            if num_collapsed == 0 {
+                break;
+            }
+        }
+
+        let num_triangles = self.triangles.len();
+
+        let mut simplified = Mesh::new();
+        for (vertices, color) in simplifier.triangles.into_iter().flatten() {
+            let points = vertices.map(|index| simplifier.positions[index]);
+
+            if let Ok(inner) = fj_math::Triangle::from_points(points) {
+                simplified.push_triangle(inner, color);
+            }
+        }
+        *self = simplified;
+
+        num_triangles - self.triangles.len()
+    }
+}
+
+/// A region in 3D space, used by [`Mesh::recolor_regions`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Region {
+    /// The region inside of an axis-aligned bounding box
+    Aabb(Aabb<3>),
+
+    /// The half-space on the side of a plane that its normal points to
+    HalfSpace {
+        /// A point on the plane
+        point: Point<3>,
+
+        /// The normal of the plane, pointing into the half-space
+        normal: Vector<3>,
+    },
+}
+
+impl Region {
+    /// Determine whether the region contains a point
+    ///
+    /// Points on the boundary of the region are considered to be contained.
+    pub fn contains(&self, point: &Point<3>) -> bool {
+        match self {
+            Self::Aabb(aabb) => aabb.contains(*point),
+            Self::HalfSpace {
+                point: origin,
+                normal,
+            } => (*point - *origin).dot(normal) >= Scalar::ZERO,
+        }
+    }
+}
+
+/// Count the fans of triangles around a vertex
+///
+/// Triangles that share an edge at the vertex are part of the same fan. In a
+/// manifold mesh, the triangles around each vertex form a single fan.
+fn count_fans(
+    vertex: Index,
+    incident: &[usize],
+    triangles: &[[Index; 3]],
+) -> usize {
+    let shares_edge = |a: usize, b: usize| {
+        triangles[a]
+            .iter()
+            .any(|&index| index != vertex && triangles[b].contains(&index))
+    };
+
+    let mut visited = vec![false; incident.len()];
+    let mut num_fans = 0;
+
+    for start in 0..incident.len() {
+        if visited[start] {
+            continue;
+        }
+
+        num_fans += 1;
+        visited[start] = true;
+
+        let mut stack = vec![start];
+        while let Some(current) = stack.pop() {
+            for next in 0..incident.len() {
+                if !visited[next]
+                    && shares_edge(incident[current], incident[next])
+                {
+                    visited[next] = true;
+                    stack.push(next);
+                }
+            }
+        }
+    }
+
+    num_fans
+}
+
+/// The working state of [`Mesh::simplify`]
+struct Simplifier {
+    positions: Vec<Point<3>>,
+
+    /// The vertices and color of each triangle, `None` if it's been removed
+    triangles: Vec<Option<([usize; 3], Color)>>,
+
+    /// The triangles that refer to each vertex
+    incident: Vec<Vec<usize>>,
+
+    /// Vertices of the original mesh that have been removed
+    ///
+    /// Each of them has been assigned to a triangle, which it is within the
+    /// target error of.
+    removed: Vec<Vec<Point<3>>>,
+
+    target_error: Scalar,
+}
+
+impl Simplifier {
+    fn new(mesh: &Mesh<Point<3>>, target_error: Scalar) -> Self {
+        let triangles = mesh
+            .indices
+            .chunks(3)
+            .zip(&mesh.triangles)
+            .map(|(indices, triangle)| {
+                let vertices =
+                    [indices[0], indices[1], indices[2]].map(|i| i as usize);
+                Some((vertices, triangle.color))
+            })
+            .collect::<Vec<_>>();
+
+        let mut incident = vec![Vec::new(); mesh.vertices.len()];
+        for (i, (vertices, _)) in triangles.iter().flatten().enumerate() {
+            for &vertex in vertices {
+                incident[vertex].push(i);
+            }
+        }
+
+        Self {
+            positions: mesh.vertices.clone(),
+            removed: vec![Vec::new(); triangles.len()],
+            triangles,
+            incident,
+            target_error,
+        }
+    }
+
+    fn triangle(&self, i: usize) -> ([usize; 3], Color) {
+        self.triangles[i].expect("Incident triangle has been removed")
+    }
+
+    fn neighbors(&self, vertex: usize) -> BTreeSet<usize> {
+        self.incident[vertex]
+            .iter()
+            .flat_map(|&i| self.triangle(i).0)
+            .filter(|&other| other != vertex)
+            .collect()
+    }
+
+    /// Check whether a vertex is surrounded by a closed fan of triangles
+    ///
+    /// That is the case, if every edge at the vertex is shared by exactly two
+    /// of its triangles.
+    fn is_interior(&self, vertex: usize) -> bool {
+        let mut num_triangles_by_edge: BTreeMap<usize, usize> = BTreeMap::new();
+        for &i in &self.incident[vertex] {
+            for other in self.triangle(i).0 {
+                if other != vertex {
+                    *num_triangles_by_edge.entry(other).or_default() += 1;
+                }
+            }
+        }
+
+        num_triangles_by_edge.values().all(|&num| num == 2)
+    }
+
+    /// Check whether vertex `a` can be merged into vertex `b`
+    fn check_collapse(&self, a: usize, b: usize) -> Option<Collapse> {
+        // Removing any other vertex would change the boundary of the mesh, or
+        // the borders between its colors.
+        let colors = self.incident[a]
+            .iter()
+            .map(|&i| self.triangle(i).1)
+            .collect::<BTreeSet<_>>();
+        if colors.len() != 1 || !self.is_interior(a) {
+            return None;
+        }
+
+        // The only vertices that `a` and `b` have in common must be those of
+        // the two triangles that share their edge. Otherwise the collapse
+        // would create a non-manifold edge. Those vertices each lose a
+        // triangle, and need to keep enough to stay closed, or at least one.
+        let neighbors_of_b = self.neighbors(b);
+        let shared = self
+            .neighbors(a)
+            .into_iter()
+            .filter(|vertex| neighbors_of_b.contains(vertex))
+            .collect::<Vec<_>>();
+        let has_too_few_triangles = |vertex: usize| {
+            let min = if self.is_interior(vertex) { 3 } else { 1 };
+            self.incident[vertex].len() <= min
+        };
+        if shared.len() != 2 || shared.iter().any(|&v| has_too_few_triangles(v))
+        {
+            return None;
+        }
+
+        // The triangles that share the edge are removed. All other triangles
+        // around `a` are moved to `b`, and must not flip.
+        let mut moved = Vec::new();
+        for &i in &self.incident[a] {
+            let (vertices, _) = self.triangle(i);
+            if vertices.contains(&b) {
+                continue;
+            }
+
+            let before = vertices.map(|vertex| self.positions[vertex]);
+            let after = vertices.map(|vertex| {
+                self.positions[if vertex == a { b } else { vertex }]
+            });
+
+            if normal(after).dot(&normal(before)) <= Scalar::ZERO {
+                return None;
+            }
+
+            moved.push((i, after));
+        }
+
+        // The removed vertex, as well as all vertices that were assigned to
+        // the triangles around it, need to stay close to the moved triangles.
+        let points = iter::once(self.positions[a]).chain(
+            self.incident[a]
+                .iter()
+                .flat_map(|&i| self.removed[i].iter().copied()),
+        );
+
+        let mut error = Scalar::ZERO;
+        let mut assignments = Vec::new();
+        for point in points {
+            let (i, distance) = moved
+                .iter()
+                .map(|&(i, triangle)| {
+                    (i, distance_to_triangle(point, triangle))
+                })
+                .min_by_key(|&(_, distance)| distance)?;
+
+            if distance > self.target_error {
+                return None;
+            }
+
+            error = error.max(distance);
+            assignments.push((i, point));
+        }
+
+        Some(Collapse {
+            a,
+            b,
+            error,
+            assignments,
+        })
+    }
+
+    fn collapse(&mut self, collapse: Collapse) {
+        let Collapse {
+            a, b, assignments, ..
+        } = collapse;
+
+        for i in mem::take(&mut self.incident[a]) {
+            let (vertices, color) = self.triangle(i);
+            self.removed[i].clear();
+
+            if vertices.contains(&b) {
+                self.triangles[i] = None;
+                for vertex in vertices {
+                    self.incident[vertex].retain(|&other| other != i);
+                }
+            } else {
+                let vertices =
+                    vertices.map(|vertex| if vertex == a { b } else { vertex });
+                self.triangles[i] = Some((vertices, color));
+                self.incident[b].push(i);
+            }
+        }
+
+        for (i, point) in assignments {
+            self.removed[i].push(point);
+        }
+    }
+}
+
+/// An edge collapse that has been checked by [`Simplifier::check_collapse`]
+struct Collapse {
+    /// The vertex that is removed
+    a: usize,
+
+    /// The vertex that `a` is merged into
+    b: usize,
+
+    /// The largest distance of a removed vertex from the simplified mesh
+    error: Scalar,
+
+    /// The removed vertices, and the triangles they are assigned to
+    assignments: Vec<(usize, Point<3>)>,
+}
+
+fn normal([a, b, c]: [Point<3>; 3]) -> Vector<3> {
+    (b - a).cross(&(c - a))
+}
+
+/// Compute the distance between a point and a triangle
+///
+/// Based on the closest-point computation in "Real-Time Collision Detection" by
+/// Christer Ericson.
+fn distance_to_triangle(point: Point<3>, [a, b, c]: [Point<3>; 3]) -> Scalar {
+    let closest = || {
+        let ab = b - a;
+        let ac = c - a;
+
+        let ap = point - a;
+        let d1 = ab.dot(&ap);
+        let d2 = ac.dot(&ap);
+        if d1 <= Scalar::ZERO && d2 <= Scalar::ZERO {
+            return a;
+        }
+
+        let bp = point - b;
+        let d3 = ab.dot(&bp);
+        let d4 = ac.dot(&bp);
+        if d3 >= Scalar::ZERO && d4 <= d3 {
+            return b;
+        }
+
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= Scalar::ZERO && d1 >= Scalar::ZERO && d3 <= Scalar::ZERO {
+            return a + ab * (d1 / (d1 - d3));
+        }
+
+        let cp = point - c;
+        let d5 = ab.dot(&cp);
+        let d6 = ac.dot(&cp);
+        if d6 >= Scalar::ZERO && d5 <= d6 {
+            return c;
+        }
+
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= Scalar::ZERO && d2 >= Scalar::ZERO && d6 <= Scalar::ZERO {
+            return a + ac * (d2 / (d2 - d6));
+        }
+
+        let va = d3 * d6 - d5 * d4;
+        if va <= Scalar::ZERO
+            && d4 - d3 >= Scalar::ZERO
+            && d5 - d6 >= Scalar::ZERO
+        {
+            return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+
+        let denom = va + vb + vc;
+        a + ab * (vb / denom) + ac * (vc / denom)
+    };
+
+    (point - closest()).magnitude()
 }
 
 // This needs to be a manual implementation. Deriving `Default` would require
@@ -106,6 +687,61 @@ impl<V> Default for Mesh<V> {
 /// An index that refers to a vertex in a mesh
 pub type Index = u32;
 
+/// The problems found by [`Mesh::validate`]
+///
+/// Each kind of problem is listed separately, together with the indices
+/// involved. Edges are identified by the indices of their vertices, with the
+/// lower index first.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MeshReport {
+    /// Triangles that don't span any area, by triangle index
+    pub degenerate_triangles: Vec<usize>,
+
+    /// Edges that are shared by more than two triangles
+    pub non_manifold_edges: Vec<[Index; 2]>,
+
+    /// Vertices whose triangles don't form a single connected fan
+    pub non_manifold_vertices: Vec<Index>,
+
+    /// Pairs of distinct vertices at the same position
+    pub duplicate_vertices: Vec<[Index; 2]>,
+
+    /// Vertices that aren't referred to by any triangle
+    pub unreferenced_vertices: Vec<Index>,
+
+    /// Edges that are only used by a single triangle
+    ///
+    /// These are expected in open meshes, but indicate holes in meshes that
+    /// should be closed.
+    pub boundary_edges: Vec<[Index; 2]>,
+
+    /// Edges whose two triangles traverse them in the same direction
+    ///
+    /// This means the triangles are wound inconsistently.
+    pub inconsistent_winding: Vec<[Index; 2]>,
+}
+
+impl MeshReport {
+    /// Indicate whether no problems were found
+    ///
+    /// This includes the check for boundary edges, meaning a mesh is only
+    /// considered valid, if it is closed.
+    pub fn is_valid(&self) -> bool {
+        self.is_closed()
+            && self.degenerate_triangles.is_empty()
+            && self.non_manifold_edges.is_empty()
+            && self.non_manifold_vertices.is_empty()
+            && self.duplicate_vertices.is_empty()
+            && self.unreferenced_vertices.is_empty()
+            && self.inconsistent_winding.is_empty()
+    }
+
+    /// Indicate whether the mesh is closed, meaning it has no boundary edges
+    pub fn is_closed(&self) -> bool {
+        self.boundary_edges.is_empty()
+    }
+}
+
 /// A triangle
 ///
 /// Extension of [`fj_math::Triangle`] that also includes a color.
@@ -128,3 +764,213 @@ impl Default for Color {
         Self([255, 0, 0, 255])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::TAU;
+
+    use fj_math::{Aabb, Point, Scalar, Vector};
+
+    use super::{distance_to_triangle, Color, Mesh, Region};
+
+    #[test]
+    fn validate() {
+        let o = Point::from([0., 0., 0.]);
+        let x = Point::from([1., 0., 0.]);
+        let y = Point::from([0., 1., 0.]);
+        let z = Point::from([0., 0., 1.]);
+
+        let tetrahedron = [[o, y, x], [o, x, z], [o, z, y], [x, y, z]];
+
+        let mut mesh = Mesh::new();
+        for triangle in tetrahedron {
+            mesh.push_triangle(triangle, Color::default());
+        }
+        assert!(mesh.validate().is_valid());
+
+        // Leave out one of the triangles.
+        let mut mesh = Mesh::new();
+        for triangle in &tetrahedron[..3] {
+            mesh.push_triangle(*triangle, Color::default());
+        }
+        let report = mesh.validate();
+        assert!(!report.is_closed());
+        assert_eq!(report.boundary_edges.len(), 3);
+        assert!(report.inconsistent_winding.is_empty());
+
+        // Flip one of the triangles.
+        let mut mesh = Mesh::new();
+        for triangle in &tetrahedron[..3] {
+            mesh.push_triangle(*triangle, Color::default());
+        }
+        mesh.push_triangle([x, z, y], Color::default());
+        let report = mesh.validate();
+        assert!(report.is_closed());
+        assert_eq!(report.inconsistent_winding.len(), 3);
+    }
+
+    #[test]
+    fn merge() {
+        let mut a = Mesh::new();
+        a.push_triangle(
+            [[0., 0., 0.], [1., 0., 0.], [1., 1., 0.]],
+            Color::default(),
+        );
+        a.push_triangle(
+            [[0., 0., 0.], [1., 1., 0.], [0., 1., 0.]],
+            Color::default(),
+        );
+
+        // The shared edge is slightly off, as if it had been approximated
+        // separately.
+        let offset = 1e-9;
+        let mut b = Mesh::new();
+        b.push_triangle(
+            [[1. + offset, 0., 0.], [2., 0., 0.], [2., 1., 0.]],
+            Color::default(),
+        );
+        b.push_triangle(
+            [[1. + offset, 0., 0.], [2., 1., 0.], [1., 1. - offset, 0.]],
+            Color::default(),
+        );
+
+        let num_vertices = a.vertices().count() + b.vertices().count();
+        assert_eq!(num_vertices, 8);
+
+        a.merge(b, 1e-6);
+        assert_eq!(a.vertices().count(), 6);
+        assert_eq!(a.triangles().count(), 4);
+
+        // The shared edge is an inner edge now, and only the outer boundary
+        // of the combined rectangle is left open.
+        let report = a.validate();
+        assert_eq!(report.boundary_edges.len(), 6);
+        assert!(report.inconsistent_winding.is_empty());
+    }
+
+    #[test]
+    fn recolor_regions() {
+        let red = Color([255, 0, 0, 255]);
+        let blue = Color([0, 0, 255, 255]);
+        let green = Color([0, 255, 0, 255]);
+
+        let mut mesh = Mesh::new();
+        for z in [0., 5., 10., 15.] {
+            mesh.push_triangle(
+                [[0., 0., z], [1., 0., z], [0., 1., z + 3.]],
+                red,
+            );
+        }
+
+        // The triangle at `z == 10` spans the plane, but its centroid is
+        // above it.
+        let above_plane = Region::HalfSpace {
+            point: Point::from([0., 0., 10.]),
+            normal: Vector::unit_z(),
+        };
+        let near_origin = Region::Aabb(Aabb::<3>::from_points([
+            [-1., -1., -1.],
+            [2., 2., 2.],
+        ]));
+
+        let num_recolored =
+            mesh.recolor_regions([(above_plane, blue), (near_origin, green)]);
+        assert_eq!(num_recolored, 3);
+
+        let colors = mesh
+            .triangles()
+            .map(|triangle| triangle.color)
+            .collect::<Vec<_>>();
+        assert_eq!(colors, [green, red, blue, blue]);
+    }
+
+    #[test]
+    fn simplify() {
+        // A finely tessellated cylinder, as it would result from sweeping a
+        // circle. The sides are split into multiple rows.
+        let num_segments = 128;
+        let heights = [0., 0.25, 0.5, 0.75, 1.];
+
+        let point = |i: usize, z: f64| {
+            let angle = TAU * (i % num_segments) as f64 / num_segments as f64;
+            Point::from([angle.cos(), angle.sin(), z])
+        };
+
+        let mut mesh = Mesh::new();
+        for i in 0..num_segments {
+            let bottom = Point::from([0., 0., 0.]);
+            let top = Point::from([0., 0., 1.]);
+
+            mesh.push_triangle([bottom, point(i + 1, 0.), point(i, 0.)], red());
+            mesh.push_triangle([top, point(i, 1.), point(i + 1, 1.)], red());
+
+            for z in heights.windows(2) {
+                let [z0, z1] = [z[0], z[1]];
+
+                mesh.push_triangle(
+                    [point(i, z0), point(i + 1, z0), point(i + 1, z1)],
+                    red(),
+                );
+                mesh.push_triangle(
+                    [point(i, z0), point(i + 1, z1), point(i, z1)],
+                    red(),
+                );
+            }
+        }
+        assert!(mesh.validate().is_valid());
+
+        let original = mesh.vertices().collect::<Vec<_>>();
+        let num_triangles = mesh.triangles().count();
+
+        let target_error = Scalar::from(1e-2);
+        let num_removed = mesh.simplify(target_error);
+
+        assert!(num_removed > num_triangles / 2);
+        assert_eq!(num_triangles - num_removed, mesh.triangles().count());
+
+        // The mesh is still closed, and no triangles have been flipped.
+        assert!(mesh.validate().is_valid());
+
+        for point in original {
+            let distance = mesh
+                .triangles()
+                .map(|triangle| {
+                    distance_to_triangle(point, triangle.inner.points())
+                })
+                .min()
+                .unwrap();
+            assert!(distance <= target_error);
+        }
+    }
+
+    #[test]
+    fn simplify_preserves_boundary() {
+        // A flat grid, whose interior vertices are all redundant.
+        let mut mesh = Mesh::new();
+        for x in 0..4 {
+            for y in 0..4 {
+                let [x, y] = [x, y].map(f64::from);
+                mesh.push_triangle(
+                    [[x, y, 0.], [x + 1., y, 0.], [x + 1., y + 1., 0.]],
+                    red(),
+                );
+                mesh.push_triangle(
+                    [[x, y, 0.], [x + 1., y + 1., 0.], [x, y + 1., 0.]],
+                    red(),
+                );
+            }
+        }
+        assert_eq!(mesh.validate().boundary_edges.len(), 16);
+
+        mesh.simplify(1e-9);
+
+        // The interior vertices are gone, but the boundary is untouched.
+        assert_eq!(mesh.vertices().count(), 16);
+        assert_eq!(mesh.validate().boundary_edges.len(), 16);
+        assert!(mesh.validate().inconsistent_winding.is_empty());
+    }
+
+    fn red() -> Color {
+        Color([255, 0, 0, 255])
+    }
+}
@@ -0,0 +1,502 @@
+//! Boolean operations on triangle meshes
+//!
+//! This is a pragmatic fallback for Boolean operations, until those are
+//! supported by the kernel. It operates on the triangle meshes that result from
+//! triangulating a shape, not on the shapes themselves. That makes it robust,
+//! but the results are only as accurate as the meshes that go in.
+//!
+//! The implementation is based on binary space partitioning (BSP) trees, as
+//! described by [csg.js]. It requires both meshes to be closed, with all
+//! triangles wound counter-clockwise, as seen from the outside.
+//!
+//! The color of each triangle in the result is taken from the mesh that the
+//! triangle originates from.
+//!
+//! [csg.js]: https://github.com/evanw/csg.js
+
+use std::mem;
+
+use fj_math::{Point, Scalar, Triangle, Vector};
+
+use crate::mesh::{Color, Mesh};
+
+/// Compute the union of two triangle meshes
+pub fn union(a: &Mesh<Point<3>>, b: &Mesh<Point<3>>) -> Mesh<Point<3>> {
+    let mut a = Node::new(polygons_from_mesh(a));
+    let mut b = Node::new(polygons_from_mesh(b));
+
+    a.clip_to(&b);
+    b.clip_to(&a);
+    b.invert();
+    b.clip_to(&a);
+    b.invert();
+    a.build(b.all_polygons());
+
+    mesh_from_polygons(a.all_polygons())
+}
+
+/// Compute the difference of two triangle meshes
+///
+/// Returns a mesh of the volume that is inside of `a`, but outside of `b`.
+pub fn difference(a: &Mesh<Point<3>>, b: &Mesh<Point<3>>) -> Mesh<Point<3>> {
+    let mut a = Node::new(polygons_from_mesh(a));
+    let mut b = Node::new(polygons_from_mesh(b));
+
+    a.invert();
+    a.clip_to(&b);
+    b.clip_to(&a);
+    b.invert();
+    b.clip_to(&a);
+    b.invert();
+    a.build(b.all_polygons());
+    a.invert();
+
+    mesh_from_polygons(a.all_polygons())
+}
+
+/// Compute the intersection of two triangle meshes
+pub fn intersection(a: &Mesh<Point<3>>, b: &Mesh<Point<3>>) -> Mesh<Point<3>> {
+    let mut a = Node::new(polygons_from_mesh(a));
+    let mut b = Node::new(polygons_from_mesh(b));
+
+    a.invert();
+    b.clip_to(&a);
+    b.invert();
+    a.clip_to(&b);
+    b.clip_to(&a);
+    a.build(b.all_polygons());
+    a.invert();
+
+    mesh_from_polygons(a.all_polygons())
+}
+
+/// The distance from a plane, within which points are considered on the plane
+const EPSILON: f64 = 1e-5;
+
+fn polygons_from_mesh(mesh: &Mesh<Point<3>>) -> Vec<Polygon> {
+    mesh.triangles()
+        .filter_map(|triangle| {
+            let vertices = triangle.inner.points();
+            let plane = Plane::from_points(vertices)?;
+
+            Some(Polygon {
+                vertices: vertices.to_vec(),
+                plane,
+                color: triangle.color,
+            })
+        })
+        .collect()
+}
+
+fn mesh_from_polygons(polygons: Vec<Polygon>) -> Mesh<Point<3>> {
+    let mut mesh = Mesh::new();
+
+    for polygon in polygons {
+        // All polygons are convex, as they result from splitting triangles
+        // along planes. This means a simple triangle fan will do.
+        let (&first, rest) = polygon
+            .vertices
+            .split_first()
+            .expect("Polygon has no vertices");
+
+        for pair in rest.windows(2) {
+            // Splitting polygons can create slivers, which are too thin to form
+            // valid triangles. They don't contribute anything to the result,
+            // so we can just skip them.
+            if let Ok(triangle) =
+                Triangle::from_points([first, pair[0], pair[1]])
+            {
+                mesh.push_triangle(triangle, polygon.color);
+            }
+        }
+    }
+
+    mesh
+}
+
+#[derive(Clone, Debug)]
+struct Polygon {
+    vertices: Vec<Point<3>>,
+    plane: Plane,
+    color: Color,
+}
+
+impl Polygon {
+    fn flip(&mut self) {
+        self.vertices.reverse();
+        self.plane.flip();
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Plane {
+    normal: Vector<3>,
+    distance: Scalar,
+}
+
+impl Plane {
+    fn from_points([a, b, c]: [Point<3>; 3]) -> Option<Self> {
+        let normal = (b - a).cross(&(c - a));
+        if normal.magnitude() == Scalar::ZERO {
+            return None;
+        }
+
+        let normal = normal.normalize();
+        let distance = normal.dot(&a.coords);
+
+        Some(Self { normal, distance })
+    }
+
+    fn flip(&mut self) {
+        self.normal = -self.normal;
+        self.distance = -self.distance;
+    }
+
+    fn side_of(&self, point: &Point<3>) -> Side {
+        let epsilon = Scalar::from_f64(EPSILON);
+        let t = self.normal.dot(&point.coords) - self.distance;
+
+        if t < -epsilon {
+            Side::BACK
+        } else if t > epsilon {
+            Side::FRONT
+        } else {
+            Side::COPLANAR
+        }
+    }
+
+    fn split_polygon(&self, polygon: Polygon) -> Split {
+        let sides = polygon
+            .vertices
+            .iter()
+            .map(|vertex| self.side_of(vertex))
+            .collect::<Vec<_>>();
+        let polygon_side =
+            sides.iter().fold(Side::COPLANAR, |a, &b| Side(a.0 | b.0));
+
+        match polygon_side {
+            Side::COPLANAR => {
+                let front_facing =
+                    self.normal.dot(&polygon.plane.normal) > Scalar::ZERO;
+                Split::Coplanar {
+                    polygon,
+                    front_facing,
+                }
+            }
+            Side::FRONT => Split::Front(polygon),
+            Side::BACK => Split::Back(polygon),
+            _ => {
+                let mut front = Vec::new();
+                let mut back = Vec::new();
+
+                let num_vertices = polygon.vertices.len();
+                for i in 0..num_vertices {
+                    let j = (i + 1) % num_vertices;
+
+                    let [side_i, side_j] = [sides[i], sides[j]];
+                    let [vertex_i, vertex_j] =
+                        [polygon.vertices[i], polygon.vertices[j]];
+
+                    if side_i != Side::BACK {
+                        front.push(vertex_i);
+                    }
+                    if side_i != Side::FRONT {
+                        back.push(vertex_i);
+                    }
+
+                    if Side(side_i.0 | side_j.0) == Side::SPANNING {
+                        let direction = vertex_j - vertex_i;
+                        let t = (self.distance
+                            - self.normal.dot(&vertex_i.coords))
+                            / self.normal.dot(&direction);
+                        let vertex = vertex_i + direction * t;
+
+                        front.push(vertex);
+                        back.push(vertex);
+                    }
+                }
+
+                let [front, back] = [front, back].map(|vertices| {
+                    if vertices.len() < 3 {
+                        return None;
+                    }
+
+                    Some(Polygon {
+                        vertices,
+                        plane: polygon.plane,
+                        color: polygon.color,
+                    })
+                });
+
+                Split::Spanning { front, back }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Side(u8);
+
+impl Side {
+    const COPLANAR: Self = Self(0);
+    const FRONT: Self = Self(1);
+    const BACK: Self = Self(2);
+    const SPANNING: Self = Self(3);
+}
+
+enum Split {
+    Coplanar {
+        polygon: Polygon,
+        front_facing: bool,
+    },
+    Front(Polygon),
+    Back(Polygon),
+    Spanning {
+        front: Option<Polygon>,
+        back: Option<Polygon>,
+    },
+}
+
+/// A node of a BSP tree
+///
+/// The trees get as deep as the number of planes in a mesh, in the worst case.
+/// All operations on them use an explicit stack instead of recursion, to not
+/// overflow the call stack when processing large meshes.
+#[derive(Debug, Default)]
+struct Node {
+    plane: Option<Plane>,
+    front: Option<Box<Node>>,
+    back: Option<Box<Node>>,
+    polygons: Vec<Polygon>,
+}
+
+impl Node {
+    fn new(polygons: Vec<Polygon>) -> Self {
+        let mut node = Self::default();
+        node.build(polygons);
+        node
+    }
+
+    /// Convert solid space to empty space, and vice versa
+    fn invert(&mut self) {
+        let mut nodes = vec![self];
+
+        while let Some(node) = nodes.pop() {
+            for polygon in &mut node.polygons {
+                polygon.flip();
+            }
+            if let Some(plane) = &mut node.plane {
+                plane.flip();
+            }
+
+            mem::swap(&mut node.front, &mut node.back);
+
+            nodes.extend(node.front.as_deref_mut());
+            nodes.extend(node.back.as_deref_mut());
+        }
+    }
+
+    /// Remove all parts of `polygons` that are inside of this tree
+    fn clip_polygons(&self, polygons: Vec<Polygon>) -> Vec<Polygon> {
+        let mut clipped = Vec::new();
+        let mut nodes = vec![(self, polygons)];
+
+        while let Some((node, polygons)) = nodes.pop() {
+            let plane = match node.plane {
+                Some(plane) => plane,
+                None => {
+                    clipped.extend(polygons);
+                    continue;
+                }
+            };
+
+            let mut front = Vec::new();
+            let mut back = Vec::new();
+
+            for polygon in polygons {
+                match plane.split_polygon(polygon) {
+                    Split::Coplanar {
+                        polygon,
+                        front_facing,
+                    } => {
+                        if front_facing {
+                            front.push(polygon);
+                        } else {
+                            back.push(polygon);
+                        }
+                    }
+                    Split::Front(polygon) => front.push(polygon),
+                    Split::Back(polygon) => back.push(polygon),
+                    Split::Spanning { front: f, back: b } => {
+                        front.extend(f);
+                        back.extend(b);
+                    }
+                }
+            }
+
+            // Polygons in front of a leaf are outside of the tree and are kept.
+            // Those behind a leaf are inside and are dropped.
+            match node.front.as_deref() {
+                Some(node) => nodes.push((node, front)),
+                None => clipped.extend(front),
+            }
+            if let Some(node) = node.back.as_deref() {
+                nodes.push((node, back));
+            }
+        }
+
+        clipped
+    }
+
+    /// Remove all parts of this tree that are inside of `other`
+    fn clip_to(&mut self, other: &Node) {
+        let mut nodes = vec![self];
+
+        while let Some(node) = nodes.pop() {
+            node.polygons = other.clip_polygons(mem::take(&mut node.polygons));
+
+            nodes.extend(node.front.as_deref_mut());
+            nodes.extend(node.back.as_deref_mut());
+        }
+    }
+
+    fn all_polygons(&self) -> Vec<Polygon> {
+        let mut polygons = Vec::new();
+        let mut nodes = vec![self];
+
+        while let Some(node) = nodes.pop() {
+            polygons.extend(node.polygons.iter().cloned());
+
+            nodes.extend(node.front.as_deref());
+            nodes.extend(node.back.as_deref());
+        }
+
+        polygons
+    }
+
+    fn build(&mut self, polygons: Vec<Polygon>) {
+        let mut nodes = vec![(self, polygons)];
+
+        while let Some((node, polygons)) = nodes.pop() {
+            let first = match polygons.first() {
+                Some(polygon) => polygon,
+                None => continue,
+            };
+            let plane = *node.plane.get_or_insert(first.plane);
+
+            let mut front = Vec::new();
+            let mut back = Vec::new();
+
+            for polygon in polygons {
+                match plane.split_polygon(polygon) {
+                    Split::Coplanar { polygon, .. } => {
+                        node.polygons.push(polygon)
+                    }
+                    Split::Front(polygon) => front.push(polygon),
+                    Split::Back(polygon) => back.push(polygon),
+                    Split::Spanning { front: f, back: b } => {
+                        front.extend(f);
+                        back.extend(b);
+                    }
+                }
+            }
+
+            if !front.is_empty() {
+                let child: &mut Node =
+                    node.front.get_or_insert_with(Default::default);
+                nodes.push((child, front));
+            }
+            if !back.is_empty() {
+                let child: &mut Node =
+                    node.back.get_or_insert_with(Default::default);
+                nodes.push((child, back));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar, Vector};
+
+    use crate::mesh::{Color, Mesh};
+
+    #[test]
+    fn union() {
+        let [a, b] = overlapping_cubes();
+        let mesh = super::union(&a, &b);
+
+        assert_volume(&mesh, 1.71875);
+    }
+
+    #[test]
+    fn difference() {
+        let [a, b] = overlapping_cubes();
+        let mesh = super::difference(&a, &b);
+
+        assert_volume(&mesh, 0.71875);
+
+        // The face where `b` cut into `a` retains the color of `b`.
+        assert!(mesh.triangles().any(|triangle| triangle.color == BLUE));
+        assert!(mesh.triangles().any(|triangle| triangle.color == RED));
+    }
+
+    #[test]
+    fn intersection() {
+        let [a, b] = overlapping_cubes();
+        let mesh = super::intersection(&a, &b);
+
+        assert_volume(&mesh, 0.28125);
+    }
+
+    const RED: Color = Color([255, 0, 0, 255]);
+    const BLUE: Color = Color([0, 0, 255, 255]);
+
+    // The overlap of the cubes has a volume of 0.5 * 0.75 * 0.75 = 0.28125.
+    fn overlapping_cubes() -> [Mesh<Point<3>>; 2] {
+        [cube([0., 0., 0.], RED), cube([0.5, 0.25, 0.25], BLUE)]
+    }
+
+    fn cube(offset: [f64; 3], color: Color) -> Mesh<Point<3>> {
+        let offset = Vector::from(offset);
+        let corners = (0..8)
+            .map(|i| {
+                let corner = [i & 1, (i >> 1) & 1, (i >> 2) & 1].map(f64::from);
+                Point::from(corner) + offset
+            })
+            .collect::<Vec<_>>();
+
+        // The sides of the cube, wound counter-clockwise when viewed from
+        // the outside.
+        let sides = [
+            [0, 2, 3, 1],
+            [4, 5, 7, 6],
+            [0, 1, 5, 4],
+            [2, 6, 7, 3],
+            [0, 4, 6, 2],
+            [1, 3, 7, 5],
+        ];
+
+        let mut mesh = Mesh::new();
+        for [a, b, c, d] in sides {
+            let [a, b, c, d] = [a, b, c, d].map(|i| corners[i]);
+            mesh.push_triangle([a, b, c], color);
+            mesh.push_triangle([a, c, d], color);
+        }
+
+        mesh
+    }
+
+    fn assert_volume(mesh: &Mesh<Point<3>>, expected: f64) {
+        let volume = mesh
+            .triangles()
+            .map(|triangle| {
+                let [a, b, c] = triangle.inner.points();
+                a.coords.dot(&b.coords.cross(&c.coords)) / 6.
+            })
+            .fold(Scalar::ZERO, |a, b| a + b);
+
+        let error = (volume - Scalar::from(expected)).abs();
+        assert!(error < Scalar::from(1e-9), "Unexpected volume: {volume:?}");
+    }
+}
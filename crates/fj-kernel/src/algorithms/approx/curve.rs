@@ -13,7 +13,11 @@ use std::collections::BTreeMap;
 
 use crate::objects::{Curve, GlobalCurve};
 
-use super::{path::RangeOnPath, Approx, ApproxPoint, Tolerance};
+use super::{
+    edge::{EdgeApprox, EdgeKey},
+    path::RangeOnPath,
+    Approx, ApproxPoint, Tolerance,
+};
 
 impl Approx for (&Curve, RangeOnPath) {
     type Approximation = CurveApprox;
@@ -94,9 +98,15 @@ impl CurveApprox {
 }
 
 /// A cache for results of an approximation
+///
+/// Besides the approximations of curves, this also caches the approximations
+/// of edges, so all half-edges that refer to the same edge are approximated
+/// using the same points. Using the same cache for all faces of a shell makes
+/// sure the approximation is watertight.
 #[derive(Default)]
 pub struct CurveCache {
     inner: BTreeMap<(GlobalCurve, RangeOnPath), GlobalCurveApprox>,
+    edges: BTreeMap<EdgeKey, Vec<EdgeApprox>>,
 }
 
 impl CurveCache {
@@ -122,6 +132,32 @@ impl CurveCache {
     ) -> Option<GlobalCurveApprox> {
         self.inner.get(&key).cloned()
     }
+
+    /// Insert the approximation of an edge
+    pub(super) fn insert_edge(&mut self, key: EdgeKey, approx: EdgeApprox) {
+        self.edges.entry(key).or_default().push(approx);
+    }
+
+    /// Access the approximations of all edges with the given key
+    ///
+    /// Multiple edges can have the same key, if they are bounded by the same
+    /// vertices on the same curve, but cover different parts of it.
+    pub(super) fn get_edges(&self, key: &EdgeKey) -> &[EdgeApprox] {
+        self.edges.get(key).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Move all approximations from another cache into this one
+    ///
+    /// The approximations of edges from `other` are added after the ones with
+    /// the same key that are already in this cache.
+    #[cfg(feature = "rayon")]
+    pub(super) fn merge(&mut self, other: Self) {
+        self.inner.extend(other.inner);
+
+        for (key, approxs) in other.edges {
+            self.edges.entry(key).or_default().extend(approxs);
+        }
+    }
 }
 
 /// An approximation of a [`GlobalCurve`]
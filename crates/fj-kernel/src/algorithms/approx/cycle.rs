@@ -2,12 +2,14 @@
 //!
 //! See [`CycleApprox`].
 
-use fj_math::Segment;
+use fj_math::{Aabb, Scalar, Segment};
 
 use crate::objects::Cycle;
 
 use super::{
-    curve::CurveCache, edge::HalfEdgeApprox, Approx, ApproxPoint, Tolerance,
+    curve::{CurveApprox, CurveCache},
+    edge::HalfEdgeApprox,
+    Approx, ApproxPoint, Tolerance,
 };
 
 impl Approx for &Cycle {
@@ -53,6 +55,85 @@ impl CycleApprox {
         points
     }
 
+    /// Drop detail that is smaller than the provided minimum feature size
+    ///
+    /// Edges whose approximation fits into a bounding box with a diagonal
+    /// smaller than `min_feature_size` are replaced by a single straight
+    /// segment between their vertices. The decision is based only on the
+    /// global form of the points, so the two half-edges that share a global
+    /// edge are simplified in the same way.
+    ///
+    /// If the whole cycle is smaller than `min_feature_size`, or if the
+    /// simplification would leave fewer than three points, the cycle is
+    /// returned unchanged, as it can't be simplified without collapsing it.
+    ///
+    /// This is applied on top of an approximation, so it doesn't replace the
+    /// tolerance: The [`Tolerance`] defines how far the approximation may
+    /// deviate from the geometry that is kept, while `min_feature_size`
+    /// defines which geometry is kept in the first place. Where detail is
+    /// dropped, the result may deviate from the original shape by up to
+    /// `min_feature_size`.
+    #[must_use]
+    pub fn with_min_feature_size(
+        self,
+        min_feature_size: impl Into<Scalar>,
+    ) -> Self {
+        let min_feature_size = min_feature_size.into();
+
+        if extent(&self.points()) < min_feature_size {
+            return self;
+        }
+
+        let num_half_edges = self.half_edges.len();
+        let is_collapsed = self
+            .half_edges
+            .iter()
+            .enumerate()
+            .map(|(i, half_edge)| {
+                let last = &self.half_edges[(i + 1) % num_half_edges].first;
+
+                let mut points = half_edge.points();
+                points.push(last.clone());
+
+                extent(&points) < min_feature_size
+            })
+            .collect::<Vec<_>>();
+
+        let num_points = self
+            .half_edges
+            .iter()
+            .zip(&is_collapsed)
+            .map(|(half_edge, &is_collapsed)| {
+                if is_collapsed {
+                    1
+                } else {
+                    1 + half_edge.curve_approx.points.len()
+                }
+            })
+            .sum::<usize>();
+        if num_points < 3 {
+            return self;
+        }
+
+        let half_edges = self
+            .half_edges
+            .into_iter()
+            .zip(is_collapsed)
+            .map(|(half_edge, is_collapsed)| {
+                if is_collapsed {
+                    HalfEdgeApprox {
+                        first: half_edge.first,
+                        curve_approx: CurveApprox::empty(),
+                    }
+                } else {
+                    half_edge
+                }
+            })
+            .collect();
+
+        CycleApprox { half_edges }
+    }
+
     /// Construct the segments that approximate the cycle
     pub fn segments(&self) -> Vec<Segment<3>> {
         let mut segments = Vec::new();
@@ -69,3 +150,14 @@ impl CycleApprox {
         segments
     }
 }
+
+/// Compute the diagonal of the bounding box of some approximated points
+pub(super) fn extent(points: &[ApproxPoint<2>]) -> Scalar {
+    if points.is_empty() {
+        return Scalar::ZERO;
+    }
+
+    Aabb::<3>::from_points(points.iter().map(|point| point.global_form))
+        .size()
+        .magnitude()
+}
@@ -4,8 +4,22 @@
 //! approximation of its curve. The second vertex is left off, as edge
 //! approximations are usually used to build cycle approximations, and this way,
 //! the caller doesn't have to call with duplicate vertices.
+//!
+//! ## Watertightness
+//!
+//! The faces that share an edge refer to it through different half-edges,
+//! whose curves might be defined differently, even if they describe the same
+//! geometry. To make sure the approximations of those half-edges fit together
+//! without gaps, the points that approximate an edge are computed once, for
+//! the first half-edge that refers to it. All other half-edges that refer to
+//! the same edge reuse those exact points.
 
-use crate::objects::HalfEdge;
+use fj_math::{Point, Scalar, Vector};
+
+use crate::{
+    objects::{GlobalVertex, HalfEdge},
+    path::GlobalPath,
+};
 
 use super::{
     curve::{CurveApprox, CurveCache},
@@ -22,24 +36,98 @@ impl Approx for &HalfEdge {
         tolerance: impl Into<Tolerance>,
         cache: &mut Self::Cache,
     ) -> Self::Approximation {
-        let &[a, b] = self.vertices();
-        let boundary = [a, b].map(|vertex| vertex.position());
-        let range = RangeOnPath { boundary };
+        let tolerance = tolerance.into();
+
+        if let Some(approx) = approx_from_cache(self, tolerance, cache) {
+            return approx;
+        }
+
+        let approx =
+            (self.curve(), range_of(self)).approx_with_cache(tolerance, cache);
 
-        let first = ApproxPoint::new(
-            a.surface_form().position(),
-            a.global_form().position(),
+        let (key, midpoint) = key_and_midpoint(self);
+        let points = approx.points.iter().map(|point| point.global_form);
+        cache.insert_edge(
+            key,
+            EdgeApprox {
+                midpoint,
+                points: points.collect(),
+            },
         );
-        let curve_approx =
-            (self.curve(), range).approx_with_cache(tolerance, cache);
 
         HalfEdgeApprox {
-            first,
-            curve_approx,
+            first: first_point(self),
+            curve_approx: approx,
         }
     }
 }
 
+/// Approximate a half-edge, reusing the cached approximation of its edge
+///
+/// Returns `None`, if the cache doesn't contain an approximation of the edge.
+pub(super) fn approx_from_cache(
+    half_edge: &HalfEdge,
+    tolerance: Tolerance,
+    cache: &CurveCache,
+) -> Option<HalfEdgeApprox> {
+    let existing = find_edge_approx(half_edge, tolerance, cache)?;
+    let curve_approx =
+        reuse_edge_approx(half_edge, range_of(half_edge), &existing.points);
+
+    Some(HalfEdgeApprox {
+        first: first_point(half_edge),
+        curve_approx,
+    })
+}
+
+/// Find the cached approximation of a half-edge's edge
+pub(super) fn find_edge_approx<'c>(
+    half_edge: &HalfEdge,
+    tolerance: Tolerance,
+    cache: &'c CurveCache,
+) -> Option<&'c EdgeApprox> {
+    let (key, midpoint) = key_and_midpoint(half_edge);
+    cache
+        .get_edges(&key)
+        .iter()
+        .find(|approx| is_same_edge(approx.midpoint, midpoint, tolerance))
+}
+
+/// Compute the key of a half-edge's edge, and the point in its middle
+///
+/// Edges with the same key might still cover different parts of the same
+/// curve. The point in the middle of the edge tells them apart. See
+/// [`is_same_edge`].
+pub(super) fn key_and_midpoint(half_edge: &HalfEdge) -> (EdgeKey, Point<3>) {
+    let key = EdgeKey::from_half_edge(half_edge);
+    let midpoint = {
+        let [a, b] = range_of(half_edge).boundary.map(|point| point.t);
+        let path = half_edge.curve().global_form().path();
+        path.point_from_path_coords([(a + b) / 2.])
+    };
+
+    (key, midpoint)
+}
+
+/// Decide whether two edges with the same key are the same edge
+pub(super) fn is_same_edge(
+    midpoint_a: Point<3>,
+    midpoint_b: Point<3>,
+    tolerance: Tolerance,
+) -> bool {
+    (midpoint_a - midpoint_b).magnitude() <= tolerance.inner()
+}
+
+fn range_of(half_edge: &HalfEdge) -> RangeOnPath {
+    let boundary = half_edge.vertices().map(|vertex| vertex.position());
+    RangeOnPath { boundary }
+}
+
+fn first_point(half_edge: &HalfEdge) -> ApproxPoint<2> {
+    let [a, _] = half_edge.vertices();
+    ApproxPoint::new(a.surface_form().position(), a.global_form().position())
+}
+
 /// An approximation of an [`HalfEdge`]
 #[derive(Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct HalfEdgeApprox {
@@ -61,3 +149,249 @@ impl HalfEdgeApprox {
         points
     }
 }
+
+/// Identifies an edge, for the purpose of sharing its approximation
+///
+/// Half-edges that refer to the same edge have equal keys, even if their
+/// curves are defined differently (for example, circles with different start
+/// points or opposite directions).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub(super) struct EdgeKey {
+    vertices: [GlobalVertex; 2],
+    curve: CurveKey,
+}
+
+impl EdgeKey {
+    fn from_half_edge(half_edge: &HalfEdge) -> Self {
+        let mut vertices = *half_edge.global_form().vertices();
+        vertices.sort();
+
+        let curve = match half_edge.curve().global_form().path() {
+            GlobalPath::Circle(circle) => {
+                let normal = circle.a().cross(&circle.b()).normalize();
+
+                // Circles that run in opposite directions have opposite
+                // normals, but describe the same geometry.
+                let is_negative = normal
+                    .components
+                    .into_iter()
+                    .find(|&component| component != Scalar::ZERO)
+                    .map(|component| component < Scalar::ZERO)
+                    .unwrap_or(false);
+                let normal = if is_negative { -normal } else { normal };
+
+                CurveKey::Circle {
+                    center: circle.center(),
+                    radius: circle.radius(),
+                    normal,
+                }
+            }
+            // A line is fully defined by the vertices of the edge.
+            GlobalPath::Line(_) => CurveKey::Line,
+        };
+
+        Self { vertices, curve }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+enum CurveKey {
+    Circle {
+        center: Point<3>,
+        radius: Scalar,
+        normal: Vector<3>,
+    },
+    Line,
+}
+
+/// The cached approximation of an edge
+pub(super) struct EdgeApprox {
+    /// The point in the middle of the edge
+    midpoint: Point<3>,
+
+    /// The points that approximate the edge, in global coordinates
+    points: Vec<Point<3>>,
+}
+
+/// Reuse the approximation of an edge for another half-edge
+///
+/// Converts the global points of the existing approximation into the
+/// coordinates of `half_edge`'s curve, and orders them according to `range`.
+fn reuse_edge_approx(
+    half_edge: &HalfEdge,
+    range: RangeOnPath,
+    points: &[Point<3>],
+) -> CurveApprox {
+    let curve = half_edge.curve();
+    let path = curve.global_form().path();
+
+    let [a, b] = range.boundary.map(|point| point.t);
+    let [min, max] = if a < b { [a, b] } else { [b, a] };
+
+    let mut points = points
+        .iter()
+        .map(|&point_global| {
+            let mut t = path.point_to_path_coords(point_global).t;
+
+            // Circle coordinates wrap around. Move the coordinate into the
+            // range, if it's outside of it.
+            if let GlobalPath::Circle(_) = path {
+                while t < min {
+                    t += Scalar::TAU;
+                }
+                while t > max {
+                    t -= Scalar::TAU;
+                }
+            }
+
+            (Point::from([t]), point_global)
+        })
+        .collect::<Vec<_>>();
+
+    points.sort_by_key(|(point_curve, _)| *point_curve);
+    if a > b {
+        points.reverse();
+    }
+
+    CurveApprox::empty().with_points(points.into_iter().map(
+        |(point_curve, point_global)| {
+            let point_surface =
+                curve.path().point_from_path_coords(point_curve);
+            ApproxPoint::new(point_surface, point_global)
+                .with_source((*curve, point_curve))
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use fj_math::{Circle, Point, Scalar};
+
+    use crate::{
+        algorithms::approx::{curve::CurveCache, Approx},
+        objects::{
+            Curve, GlobalCurve, GlobalVertex, HalfEdge, Surface, SurfaceVertex,
+            Vertex,
+        },
+        path::{GlobalPath, SurfacePath},
+    };
+
+    #[test]
+    fn shared_edge_is_approximated_identically() {
+        // Two quarter circles between the same vertices, but defined on
+        // circles with different start points.
+        let vertices = [[1., 0.], [0., 1.]];
+        let quarter = Scalar::PI.into_f64() / 2.;
+        let a = arc([[1., 0.], [0., 1.]], [0., quarter], vertices);
+        let b = arc([[0., -1.], [1., 0.]], [quarter, quarter * 2.], vertices);
+
+        let global_points = |half_edge: &HalfEdge, cache: &mut CurveCache| {
+            half_edge
+                .approx_with_cache(0.01, cache)
+                .curve_approx
+                .points
+                .into_iter()
+                .map(|point| point.global_form)
+                .collect::<BTreeSet<Point<3>>>()
+        };
+
+        // Approximated independently, the points don't match.
+        let points_a = global_points(&a, &mut CurveCache::new());
+        let points_b = global_points(&b, &mut CurveCache::new());
+        assert_ne!(points_a, points_b);
+
+        // Approximated using the same cache, they do.
+        let mut cache = CurveCache::new();
+        let points_a = global_points(&a, &mut cache);
+        let points_b = global_points(&b, &mut cache);
+        assert_eq!(points_a, points_b);
+
+        // The reused points have been converted to the coordinates of the
+        // other curve.
+        for point in b.approx_with_cache(0.01, &mut cache).curve_approx.points {
+            let point_global =
+                Surface::xy_plane().point_from_surface_coords(point.local_form);
+            assert!(
+                (point_global - point.global_form).magnitude()
+                    < Scalar::from(1e-12)
+            );
+        }
+    }
+
+    #[test]
+    fn arc_is_approximated_over_its_span() {
+        let tolerance = 0.01;
+        let surface = Surface::xy_plane();
+
+        let circle = HalfEdge::build(surface).circle_from_radius(1.);
+        let half_circle = HalfEdge::build(surface).arc_from_center_and_radius(
+            [0., 0.],
+            1.,
+            0.,
+            Scalar::PI,
+        );
+
+        let num_points = |half_edge: &HalfEdge| {
+            half_edge.approx(tolerance).points().len() as i64
+        };
+
+        let num_circle = num_points(&circle);
+        let num_half_circle = num_points(&half_circle);
+
+        assert!((num_half_circle * 2 - num_circle).abs() <= 2);
+    }
+
+    #[test]
+    fn different_arcs_between_same_vertices() {
+        // The two halves of a circle are bounded by the same vertices.
+        let circle = [[1., 0.], [0., 1.]];
+        let pi = Scalar::PI.into_f64();
+
+        let upper = arc(circle, [0., pi], [[1., 0.], [-1., 0.]]);
+        let lower = arc(circle, [pi, pi * 2.], [[-1., 0.], [1., 0.]]);
+
+        let mut cache = CurveCache::new();
+        let upper = upper.approx_with_cache(0.01, &mut cache);
+        let lower = lower.approx_with_cache(0.01, &mut cache);
+
+        assert!(!upper.curve_approx.points.is_empty());
+        assert!(!lower.curve_approx.points.is_empty());
+
+        for point in upper.curve_approx.points {
+            assert!(point.global_form.y > Scalar::ZERO);
+        }
+        for point in lower.curve_approx.points {
+            assert!(point.global_form.y < Scalar::ZERO);
+        }
+    }
+
+    fn arc(
+        [a, b]: [[f64; 2]; 2],
+        range: [f64; 2],
+        vertices: [[f64; 2]; 2],
+    ) -> HalfEdge {
+        let surface = Surface::xy_plane();
+
+        let circle = Circle::new([0., 0.], a, b);
+        let global_circle =
+            Circle::new([0., 0., 0.], [a[0], a[1], 0.], [b[0], b[1], 0.]);
+
+        let curve = Curve::new(
+            surface,
+            SurfacePath::Circle(circle),
+            GlobalCurve::from_path(GlobalPath::Circle(global_circle)),
+        );
+
+        let [a, b] = [0, 1].map(|i| {
+            let [u, v] = vertices[i];
+            let global_form = GlobalVertex::from_position([u, v, 0.]);
+            let surface_form = SurfaceVertex::new([u, v], surface, global_form);
+
+            Vertex::new([range[i]], curve, surface_form, global_form)
+        });
+
+        HalfEdge::from_curve_and_vertices(curve, [a, b])
+    }
+}
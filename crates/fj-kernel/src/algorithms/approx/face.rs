@@ -5,6 +5,7 @@
 use std::collections::BTreeSet;
 
 use fj_interop::mesh::Color;
+use fj_math::Scalar;
 
 use crate::{
     algorithms::validate::ValidationConfig,
@@ -12,7 +13,9 @@ use crate::{
 };
 
 use super::{
-    curve::CurveCache, cycle::CycleApprox, Approx, ApproxPoint, Tolerance,
+    curve::CurveCache,
+    cycle::{extent, CycleApprox},
+    Approx, ApproxPoint, Tolerance,
 };
 
 impl Approx for &Faces {
@@ -26,11 +29,15 @@ impl Approx for &Faces {
     ) -> Self::Approximation {
         let tolerance = tolerance.into();
 
+        #[cfg(not(feature = "rayon"))]
         let approx = self
             .into_iter()
             .map(|face| face.approx_with_cache(tolerance, cache))
             .collect();
 
+        #[cfg(feature = "rayon")]
+        let approx = super::parallel::approx_faces(self, tolerance, cache);
+
         let min_distance = ValidationConfig::default().distinct_min_distance;
         let mut all_points: BTreeSet<ApproxPoint<2>> = BTreeSet::new();
 
@@ -137,4 +144,69 @@ impl FaceApprox {
 
         points
     }
+
+    /// Drop detail that is smaller than the provided minimum feature size
+    ///
+    /// Interior cycles (holes) that are smaller than `min_feature_size` are
+    /// removed. Within the remaining cycles, small edges are simplified, as
+    /// described in [`CycleApprox::with_min_feature_size`], which also
+    /// explains how this interacts with the [`Tolerance`] of the
+    /// approximation.
+    ///
+    /// The face itself is always kept, even if it is smaller than
+    /// `min_feature_size`, as removing it would leave a gap in the shell it
+    /// belongs to.
+    #[must_use]
+    pub fn with_min_feature_size(
+        self,
+        min_feature_size: impl Into<Scalar>,
+    ) -> Self {
+        let min_feature_size = min_feature_size.into();
+
+        let exterior = self.exterior.with_min_feature_size(min_feature_size);
+        let interiors = self
+            .interiors
+            .into_iter()
+            .filter(|cycle| extent(&cycle.points()) >= min_feature_size)
+            .map(|cycle| cycle.with_min_feature_size(min_feature_size))
+            .collect();
+
+        Self {
+            exterior,
+            interiors,
+            color: self.color,
+            coord_handedness: self.coord_handedness,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algorithms::approx::Approx,
+        objects::{Face, Surface},
+    };
+
+    #[test]
+    fn min_feature_size() {
+        let surface = Surface::xy_plane();
+
+        let face = Face::build(surface)
+            .polygon_from_points([[-4., -4.], [4., -4.], [4., 4.], [-4., 4.]])
+            .with_hole([[-2., -0.1], [-2., 0.1], [-1.8, 0.1], [-1.8, -0.1]])
+            .with_hole([[1., -1.], [1., 1.], [3., 1.], [3., -1.]])
+            .into_face();
+
+        let approx = (&face).approx(0.01);
+        assert_eq!(approx.interiors.len(), 2);
+
+        let approx = approx.with_min_feature_size(0.5);
+        assert_eq!(approx.interiors.len(), 1);
+
+        // The remaining hole's edges are larger than the minimum feature size,
+        // so they are kept as they are.
+        let hole = approx.interiors.iter().next().unwrap();
+        assert_eq!(hole.half_edges.len(), 4);
+        assert_eq!(hole.points().len(), 5);
+    }
 }
@@ -4,6 +4,8 @@ pub mod curve;
 pub mod cycle;
 pub mod edge;
 pub mod face;
+#[cfg(feature = "rayon")]
+mod parallel;
 pub mod path;
 pub mod shell;
 pub mod sketch;
@@ -15,7 +17,7 @@ use std::{
     cmp::Ordering,
     fmt::Debug,
     hash::{Hash, Hasher},
-    rc::Rc,
+    sync::Arc,
 };
 
 use fj_math::Point;
@@ -62,7 +64,7 @@ pub struct ApproxPoint<const D: usize> {
     pub global_form: Point<3>,
 
     /// The optional source of the point
-    pub source: Option<Rc<dyn Source>>,
+    pub source: Option<Arc<dyn Source>>,
 }
 
 impl<const D: usize> ApproxPoint<D> {
@@ -78,7 +80,7 @@ impl<const D: usize> ApproxPoint<D> {
     /// Attach a source to the point
     pub fn with_source(self, source: impl Source) -> Self {
         Self {
-            source: Some(Rc::new(source)),
+            source: Some(Arc::new(source)),
             ..self
         }
     }
@@ -117,6 +119,8 @@ impl<const D: usize> PartialOrd for ApproxPoint<D> {
 }
 
 /// The source of an [`ApproxPoint`]
-pub trait Source: Any + Debug {}
+///
+/// Sources must be thread-safe, so approximations can be computed in parallel.
+pub trait Source: Any + Debug + Send + Sync {}
 
 impl Source for (Curve, Point<1>) {}
@@ -0,0 +1,223 @@
+//! Parallel face approximation
+//!
+//! Faces can be approximated independently of each other, except for the
+//! edges they share. As explained in [`super::edge`], the points of a shared
+//! edge are computed once, and reused by all half-edges that refer to that
+//! edge. The approximation is split into steps, to keep that property:
+//!
+//! 1. Go through all half-edges, in the order in which the serial
+//!    approximation would visit them. Decide which of them need to be
+//!    approximated (the first half-edge that refers to an edge), and which can
+//!    reuse an existing approximation.
+//! 2. Approximate the half-edges from the first step in parallel, each with
+//!    its own cache.
+//! 3. Merge those caches into the shared one, in the original order.
+//! 4. Approximate the remaining half-edges in parallel, reusing the
+//!    approximations from the shared cache.
+//!
+//! The result is identical to the result of the serial approximation.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use fj_math::Point;
+use rayon::prelude::*;
+
+use crate::objects::{Cycle, Face, Faces};
+
+use super::{
+    curve::CurveCache,
+    cycle::CycleApprox,
+    edge::{self, EdgeKey, HalfEdgeApprox},
+    face::FaceApprox,
+    Approx, Tolerance,
+};
+
+/// Approximate faces in parallel
+pub(super) fn approx_faces(
+    faces: &Faces,
+    tolerance: Tolerance,
+    cache: &mut CurveCache,
+) -> BTreeSet<FaceApprox> {
+    let faces = faces.into_iter().collect::<Vec<_>>();
+    let half_edges = faces
+        .iter()
+        .flat_map(|&face| cycles_of(face))
+        .flat_map(|cycle| cycle.half_edges())
+        .collect::<Vec<_>>();
+
+    // Step 1: Find the half-edges that need to be approximated.
+    let mut pending: BTreeMap<EdgeKey, Vec<Point<3>>> = BTreeMap::new();
+    let needs_approx = half_edges
+        .iter()
+        .map(|&half_edge| {
+            if edge::find_edge_approx(half_edge, tolerance, cache).is_some() {
+                return false;
+            }
+
+            let (key, midpoint) = edge::key_and_midpoint(half_edge);
+            let midpoints = pending.entry(key).or_default();
+            if midpoints
+                .iter()
+                .any(|&other| edge::is_same_edge(other, midpoint, tolerance))
+            {
+                return false;
+            }
+
+            midpoints.push(midpoint);
+            true
+        })
+        .collect::<Vec<_>>();
+
+    // Step 2: Approximate them in parallel.
+    let approximated = half_edges
+        .par_iter()
+        .zip(&needs_approx)
+        .filter(|(_, needs_approx)| **needs_approx)
+        .map(|(&half_edge, _)| {
+            let mut cache = CurveCache::new();
+            let approx = half_edge.approx_with_cache(tolerance, &mut cache);
+            (approx, cache)
+        })
+        .collect::<Vec<_>>();
+
+    // Step 3: Merge the results into the shared cache.
+    let mut approximated = approximated
+        .into_iter()
+        .map(|(approx, local_cache)| {
+            cache.merge(local_cache);
+            approx
+        })
+        .collect::<Vec<_>>()
+        .into_iter();
+
+    // Step 4: Reuse the cached approximations for all other half-edges.
+    let cache: &CurveCache = cache;
+    let reused = half_edges
+        .par_iter()
+        .zip(&needs_approx)
+        .map(|(&half_edge, &needs_approx)| {
+            if needs_approx {
+                return None;
+            }
+
+            let approx = edge::approx_from_cache(half_edge, tolerance, cache)
+                .expect("Edge approximation should have been cached");
+            Some(approx)
+        })
+        .collect::<Vec<_>>();
+
+    let mut half_edge_approxs = reused.into_iter().map(|approx| match approx {
+        Some(approx) => approx,
+        None => approximated
+            .next()
+            .expect("Expected approximation for every half-edge"),
+    });
+
+    let mut approx_cycle = |cycle: &Cycle| {
+        let half_edges = cycle
+            .half_edges()
+            .map(|_| {
+                half_edge_approxs
+                    .next()
+                    .expect("Expected approximation for every half-edge")
+            })
+            .collect();
+        CycleApprox { half_edges }
+    };
+
+    faces
+        .into_iter()
+        .map(|face| {
+            let exterior = approx_cycle(face.exterior());
+            let interiors = face.interiors().map(&mut approx_cycle).collect();
+
+            FaceApprox {
+                exterior,
+                interiors,
+                color: face.color(),
+                coord_handedness: face.coord_handedness(),
+            }
+        })
+        .collect()
+}
+
+/// Access the cycles of a face, in the order the serial approximation uses
+fn cycles_of(face: &Face) -> impl Iterator<Item = &Cycle> {
+    [face.exterior()].into_iter().chain(face.interiors())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::{
+        algorithms::{
+            approx::{curve::CurveCache, face::FaceApprox, Approx, Tolerance},
+            sweep::Sweep,
+            transform::TransformObject,
+        },
+        objects::{Cycle, Face, Faces, HalfEdge, Shell, Surface},
+    };
+
+    use super::approx_faces;
+
+    #[test]
+    fn cube_matches_serial_approximation() {
+        let shell = Shell::build().cube_from_edge_length(1.);
+        assert_matches_serial(shell.faces(), 0.01);
+    }
+
+    #[test]
+    fn cylinder_matches_serial_approximation() {
+        let shell = cylinder();
+        assert_matches_serial(shell.faces(), 0.01);
+        assert_matches_serial(shell.faces(), 0.0001);
+    }
+
+    // Approximating this many faces with such a small tolerance takes a while,
+    // so this only runs on request. Run it with
+    // `cargo test --release --features rayon -- --ignored`.
+    #[test]
+    #[ignore]
+    fn many_faces_match_serial_approximation() {
+        let tolerance = Tolerance::from_scalar(0.00001).unwrap();
+
+        let mut faces = Faces::new();
+        for i in 0..64 {
+            let shell = cylinder().translate([f64::from(i) * 3., 0., 0.]);
+            faces.extend(shell.faces().clone());
+        }
+
+        let serial = serial_approx(&faces, tolerance);
+        let parallel = approx_faces(&faces, tolerance, &mut CurveCache::new());
+
+        assert_eq!(serial, parallel);
+    }
+
+    fn cylinder() -> Shell {
+        let surface = Surface::xy_plane();
+        let half_edge = HalfEdge::build(surface).circle_from_radius(1.);
+        let cycle = Cycle::new(surface, [half_edge]);
+        Face::new(surface, cycle).sweep([0., 0., 1.])
+    }
+
+    fn assert_matches_serial(faces: &Faces, tolerance: f64) {
+        let tolerance = Tolerance::from_scalar(tolerance).unwrap();
+
+        let serial = serial_approx(faces, tolerance);
+        let parallel = approx_faces(faces, tolerance, &mut CurveCache::new());
+
+        assert_eq!(serial, parallel);
+    }
+
+    fn serial_approx(
+        faces: &Faces,
+        tolerance: Tolerance,
+    ) -> BTreeSet<FaceApprox> {
+        let mut cache = CurveCache::new();
+        faces
+            .into_iter()
+            .map(|face| face.approx_with_cache(tolerance, &mut cache))
+            .collect()
+    }
+}
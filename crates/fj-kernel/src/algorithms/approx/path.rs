@@ -30,7 +30,7 @@
 
 use std::iter;
 
-use fj_math::{Circle, Point, Scalar, Sign};
+use fj_math::{Circle, EllipticalArc, Point, Scalar, Sign, Vector};
 
 use crate::path::GlobalPath;
 
@@ -56,6 +56,19 @@ impl Approx for (GlobalPath, RangeOnPath) {
     }
 }
 
+impl<const D: usize> Approx for &EllipticalArc<D> {
+    type Approximation = Vec<(Point<1>, Point<D>)>;
+    type Cache = ();
+
+    fn approx_with_cache(
+        self,
+        tolerance: impl Into<Tolerance>,
+        (): &mut Self::Cache,
+    ) -> Self::Approximation {
+        approx_elliptical_arc(self, tolerance.into())
+    }
+}
+
 /// The range on which a path should be approximated
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
 pub struct RangeOnPath {
@@ -95,6 +108,148 @@ fn approx_circle<const D: usize>(
     points
 }
 
+/// Approximate an elliptical arc
+///
+/// Unlike a circle, an ellipse doesn't have constant curvature, so the points
+/// are spaced adaptively: They are close together where the ellipse is curved
+/// strongly, and farther apart where it's flatter. The boundaries of the arc
+/// are not included, like for the approximation of paths.
+///
+/// The points are taken from an approximation of the full ellipse, so the
+/// result is deterministic, regardless of the range of the arc.
+fn approx_elliptical_arc<const D: usize>(
+    arc: &EllipticalArc<D>,
+    tolerance: Tolerance,
+) -> Vec<(Point<1>, Point<D>)> {
+    let [start, end] = arc.range();
+    let [min, max] = if start < end {
+        [start, end]
+    } else {
+        [end, start]
+    };
+
+    let params = params_for_ellipse(arc, tolerance);
+
+    // The range of the arc might span multiple turns, none of which need to
+    // start at zero.
+    let first_turn = (min / Scalar::TAU).floor().into_f64() as i64;
+    let last_turn = (max / Scalar::TAU).floor().into_f64() as i64;
+
+    let mut points = Vec::new();
+    for turn in first_turn..=last_turn {
+        let offset = Scalar::TAU * Scalar::from_f64(turn as f64);
+
+        for &t in &params {
+            let t = t + offset;
+
+            if t > min && t < max {
+                let point_curve = Point::from([t]);
+                points.push((
+                    point_curve,
+                    arc.point_from_arc_coords(point_curve),
+                ));
+            }
+        }
+    }
+
+    if end < start {
+        points.reverse();
+    }
+
+    points
+}
+
+/// Compute the angles that approximate a full ellipse
+///
+/// Returns angles between `0.` (inclusive) and `PI * 2.` (exclusive). Each
+/// step is estimated from the local curvature of the ellipse, then refined, if
+/// the chord deviates from the ellipse by more than `tolerance`.
+fn params_for_ellipse<const D: usize>(
+    arc: &EllipticalArc<D>,
+    tolerance: Tolerance,
+) -> Vec<Scalar> {
+    // Like a circle, an ellipse can't be approximated with fewer than three
+    // segments.
+    let max_step = Scalar::TAU / 3.;
+    let min_step = Scalar::TAU * Scalar::from_f64(1e-9);
+
+    let point_at = |t: Scalar| arc.point_from_arc_coords([t]);
+    let deviation = |t: Scalar, step: Scalar| {
+        let [a, b] = [t, t + step].map(point_at);
+        let mid = point_at(t + step / 2.);
+
+        let chord = b - a;
+        let to_mid = mid - a;
+        let projected = chord * (to_mid.dot(&chord) / chord.dot(&chord));
+
+        (to_mid - projected).magnitude()
+    };
+
+    let mut params = Vec::new();
+    let mut t = Scalar::ZERO;
+
+    while t < Scalar::TAU {
+        params.push(t);
+
+        // For small steps, the chord deviates from the curve by about
+        // `curvature * speed^2 * step^2 / 8`, where `speed` is the magnitude
+        // of the first derivative.
+        let (sin, cos) = t.sin_cos();
+        let first: Vector<D> = arc.b() * cos - arc.a() * sin;
+        let second: Vector<D> = -(arc.a() * cos + arc.b() * sin);
+
+        let speed = first.magnitude();
+        let cross = {
+            let square = first.dot(&first) * second.dot(&second)
+                - first.dot(&second) * first.dot(&second);
+            Scalar::from_f64(square.into_f64().max(0.).sqrt())
+        };
+
+        let mut step = if cross > Scalar::ZERO {
+            let square = tolerance.inner() * speed * 8. / cross;
+            Scalar::from_f64(square.into_f64().sqrt()).min(max_step)
+        } else {
+            max_step
+        };
+
+        // The estimate assumes constant curvature over the step. Where the
+        // curvature increases, it might be too optimistic. Shrink the step in
+        // small increments, to not make it much smaller than necessary.
+        while deviation(t, step) > tolerance.inner() && step > min_step {
+            step *= 0.9;
+        }
+
+        t += step;
+    }
+
+    params
+}
+
+/// Compute the number of segments required to approximate a circle
+///
+/// Returns the smallest number of segments `n`, for which the sagitta of each
+/// segment, `radius * (1 - cos(PI / n))`, doesn't exceed `tolerance`. This is
+/// the maximum distance between the circle and the polygon that approximates
+/// it, which means large circles get more segments than small ones at the same
+/// tolerance.
+///
+/// The result is never smaller than 3, as fewer segments can't approximate a
+/// circle.
+pub fn num_segments_for_circle(
+    radius: impl Into<Scalar>,
+    tolerance: impl Into<Tolerance>,
+) -> usize {
+    let radius = radius.into();
+    let tolerance = tolerance.into();
+
+    // If the tolerance is at least as large as the radius, any polygon is
+    // accurate enough. Limiting the ratio keeps `acos` in its domain.
+    let ratio = Scalar::min(tolerance.inner() / radius, Scalar::ONE);
+
+    let num_segments = (Scalar::PI / (Scalar::ONE - ratio).acos()).ceil();
+    (num_segments.into_f64() as usize).max(3)
+}
+
 struct PathApproxParams {
     increment: Scalar,
 }
@@ -106,12 +261,8 @@ impl PathApproxParams {
     ) -> Self {
         let radius = circle.a().magnitude();
 
-        let num_vertices_to_approx_full_circle = Scalar::max(
-            Scalar::PI
-                / (Scalar::ONE - (tolerance.into().inner() / radius)).acos(),
-            3.,
-        )
-        .ceil();
+        let num_vertices_to_approx_full_circle =
+            Scalar::from_f64(num_segments_for_circle(radius, tolerance) as f64);
 
         let increment = Scalar::TAU / num_vertices_to_approx_full_circle;
 
@@ -163,13 +314,39 @@ impl PathApproxParams {
 
 #[cfg(test)]
 mod tests {
-    use std::f64::consts::TAU;
+    use std::{
+        f64::consts::{FRAC_PI_2, TAU},
+        iter,
+    };
 
-    use fj_math::{Circle, Point, Scalar};
+    use fj_math::{Circle, EllipticalArc, Point, Scalar};
 
-    use crate::algorithms::approx::{path::RangeOnPath, Tolerance};
+    use crate::algorithms::approx::{path::RangeOnPath, Approx, Tolerance};
 
-    use super::PathApproxParams;
+    use super::{num_segments_for_circle, PathApproxParams};
+
+    #[test]
+    fn segments_for_circle() {
+        assert_eq!(num_segments_for_circle(1., 0.1), 7);
+        assert_eq!(num_segments_for_circle(1., 0.01), 23);
+        assert_eq!(num_segments_for_circle(100., 0.01), 223);
+
+        // Tiny circles, or huge tolerances, don't result in degenerate
+        // approximations.
+        assert_eq!(num_segments_for_circle(0.001, 0.01), 3);
+        assert_eq!(num_segments_for_circle(1., 5.), 3);
+
+        // The result is the smallest number of segments within tolerance.
+        for (radius, tolerance) in [(1., 0.001), (50., 0.01), (0.5, 0.0001)] {
+            let sagitta = |n: usize| {
+                radius * (1. - (std::f64::consts::PI / n as f64).cos())
+            };
+
+            let n = num_segments_for_circle(radius, tolerance);
+            assert!(sagitta(n) <= tolerance);
+            assert!(sagitta(n - 1) > tolerance);
+        }
+    }
 
     #[test]
     fn increment_for_circle() {
@@ -238,4 +415,53 @@ mod tests {
             assert_eq!(points, expected_points);
         }
     }
+
+    #[test]
+    fn points_for_elliptical_arc() {
+        // The ellipse is curved strongly near the ends of its major axis, at
+        // the angles `0` and `PI`, and is much flatter near the ends of its
+        // minor axis.
+        let tolerance = 0.001;
+        let arc_around = |t: f64| {
+            EllipticalArc::new([0., 0.], [4., 0.], [0., 1.], [t - 0.5, t + 0.5])
+        };
+
+        let high_curvature = arc_around(0.).approx(tolerance);
+        let low_curvature = arc_around(FRAC_PI_2).approx(tolerance);
+        assert!(high_curvature.len() > low_curvature.len() * 3 / 2);
+
+        for (arc, points) in [
+            (arc_around(0.), high_curvature),
+            (arc_around(FRAC_PI_2), low_curvature),
+        ] {
+            let [start, end] = arc.range();
+            let coords = iter::once(start)
+                .chain(points.iter().map(|(point, _)| point.t))
+                .chain(iter::once(end))
+                .collect::<Vec<_>>();
+
+            // All points are within the arc, in order, and no chord deviates
+            // from the ellipse by more than the tolerance.
+            for coords in coords.windows(2) {
+                let [a, b] = [coords[0], coords[1]];
+                assert!(a < b);
+
+                let [a, mid, b] = [a, (a + b) / 2., b]
+                    .map(|t| arc.point_from_arc_coords([t]));
+                let chord = (b - a).normalize();
+                let to_mid = mid - a;
+                let distance =
+                    (to_mid - chord * to_mid.dot(&chord)).magnitude();
+                assert!(distance <= Scalar::from(tolerance));
+            }
+        }
+
+        // Reversing the arc reverses the points.
+        let forward = arc_around(0.).approx(tolerance);
+        let mut backward =
+            EllipticalArc::new([0., 0.], [4., 0.], [0., 1.], [0.5, -0.5])
+                .approx(tolerance);
+        backward.reverse();
+        assert_eq!(forward, backward);
+    }
 }
@@ -2,7 +2,7 @@
 //!
 //! See [`Tolerance`].
 
-use fj_math::Scalar;
+use fj_math::{Aabb, Scalar};
 
 /// A tolerance value
 ///
@@ -40,6 +40,38 @@ impl Tolerance {
         Ok(Self(scalar))
     }
 
+    /// Construct a `Tolerance` that is relative to the size of a model
+    ///
+    /// The tolerance is `relative` times the largest extent of `aabb`. A
+    /// relative tolerance of `0.001` means that the approximation deviates
+    /// from the model by no more than a thousandth of its size.
+    ///
+    /// If `aabb` has zero size, there's nothing to scale the tolerance to, and
+    /// `relative` is used as an absolute tolerance instead.
+    ///
+    /// Returns an error, if `relative` is not larger than zero.
+    pub fn from_bounding_box(
+        aabb: &Aabb<3>,
+        relative: impl Into<Scalar>,
+    ) -> Result<Self, InvalidTolerance> {
+        let relative = relative.into();
+
+        if relative <= Scalar::ZERO {
+            return Err(InvalidTolerance(relative));
+        }
+
+        let max_extent = aabb
+            .size()
+            .components
+            .into_iter()
+            .fold(Scalar::ZERO, Scalar::max);
+        if max_extent == Scalar::ZERO {
+            return Self::from_scalar(relative);
+        }
+
+        Self::from_scalar(relative * max_extent)
+    }
+
     /// Return the [`Scalar`] that defines the tolerance
     pub fn inner(&self) -> Scalar {
         self.0
@@ -60,3 +92,26 @@ where
 #[derive(Debug, thiserror::Error)]
 #[error("Invalid tolerance ({0}); must be above zero")]
 pub struct InvalidTolerance(Scalar);
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Aabb, Scalar};
+
+    use super::Tolerance;
+
+    #[test]
+    fn from_bounding_box() {
+        let aabb = Aabb::<3>::from_points([[0., 0., 0.], [10., 200., 1.]]);
+
+        let tolerance = Tolerance::from_bounding_box(&aabb, 0.001).unwrap();
+        assert_eq!(tolerance.inner(), Scalar::from(0.2));
+
+        assert!(Tolerance::from_bounding_box(&aabb, 0.).is_err());
+        assert!(Tolerance::from_bounding_box(&aabb, -0.001).is_err());
+
+        // A bounding box with zero size falls back to an absolute tolerance.
+        let point = Aabb::<3>::from_points([[1., 2., 3.]]);
+        let tolerance = Tolerance::from_bounding_box(&point, 0.001).unwrap();
+        assert_eq!(tolerance.inner(), Scalar::from(0.001));
+    }
+}
@@ -0,0 +1,240 @@
+//! Cutting off the corners of polygons
+//!
+//! See [`Chamfer`].
+
+use fj_math::{Point, Scalar};
+
+use crate::objects::{Cycle, Face, HalfEdge};
+
+use super::fillet::polygon_from_cycle;
+
+/// Cut off the corners of a polygon
+pub trait Chamfer: Sized {
+    /// Replace each convex corner with a straight segment
+    ///
+    /// The two edges that meet at a convex corner are shortened by `distance`,
+    /// and a line segment is inserted between their new ends. Concave corners,
+    /// and corners at which the edges are collinear, are left as they are.
+    ///
+    /// Returns an error, if `distance` is not positive, or if the chamfers at
+    /// both ends of an edge would overlap. Every edge must keep some of its
+    /// length, after both of its ends have been cut off.
+    ///
+    /// # Implementation Note
+    ///
+    /// Only polygons are supported, meaning all edges must be line segments.
+    /// Returns an error, if that's not the case.
+    fn chamfer(self, distance: impl Into<Scalar>)
+        -> Result<Self, ChamferError>;
+}
+
+impl Chamfer for Cycle {
+    fn chamfer(
+        self,
+        distance: impl Into<Scalar>,
+    ) -> Result<Self, ChamferError> {
+        let distance = distance.into();
+
+        if distance <= Scalar::ZERO {
+            return Err(ChamferError::DistanceNotPositive(distance));
+        }
+
+        let surface = *self.surface();
+        let points =
+            polygon_from_cycle(&self).ok_or(ChamferError::CurvedCycle)?;
+        let num_points = points.len();
+
+        // The points where the edges before and after each corner end, after
+        // the corner has been cut off. They are identical to the corner, if it
+        // isn't cut off.
+        let corners = (0..num_points)
+            .map(|i| {
+                let prev = points[(i + num_points - 1) % num_points];
+                let point = points[i];
+                let next = points[(i + 1) % num_points];
+
+                let incoming = (point - prev).normalize();
+                let outgoing = (next - point).normalize();
+
+                // The region bounded by the cycle is on its left side, so a
+                // corner is convex, if the cycle turns left there.
+                if incoming.cross(&outgoing) > Scalar::ZERO {
+                    [point - incoming * distance, point + outgoing * distance]
+                } else {
+                    [point, point]
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // The edge `i` runs from corner `i` to corner `i + 1`. Make sure the
+        // chamfers at both of its ends leave some of it.
+        let mut segments = Vec::new();
+        for (i, [_, start]) in corners.iter().enumerate() {
+            let [end, _] = corners[(i + 1) % num_points];
+
+            let length = points[(i + 1) % num_points] - points[i];
+            let remaining = end - *start;
+
+            if remaining.dot(&length) <= Scalar::ZERO {
+                return Err(ChamferError::Overlap {
+                    distance,
+                    edge: i,
+                    length: length.magnitude(),
+                });
+            }
+
+            segments.push([*start, end]);
+        }
+
+        let mut half_edges = Vec::new();
+        for (i, segment) in segments.into_iter().enumerate() {
+            let [end, start] = corners[(i + 1) % num_points];
+
+            half_edges.push(
+                HalfEdge::build(surface).line_segment_from_points(segment),
+            );
+            if end != start {
+                half_edges.push(
+                    HalfEdge::build(surface)
+                        .line_segment_from_points([end, start]),
+                );
+            }
+        }
+
+        Ok(Cycle::new(surface, half_edges))
+    }
+}
+
+impl Chamfer for Face {
+    fn chamfer(
+        self,
+        distance: impl Into<Scalar>,
+    ) -> Result<Self, ChamferError> {
+        let distance = distance.into();
+
+        let exterior = self.exterior().clone().chamfer(distance)?;
+        let interiors = self
+            .interiors()
+            .map(|cycle| cycle.clone().chamfer(distance))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Face::new(*self.surface(), exterior)
+            .with_interiors(interiors)
+            .with_color(self.color()))
+    }
+}
+
+/// Error chamfering a polygon
+#[derive(Debug, thiserror::Error)]
+pub enum ChamferError {
+    /// The distance is zero or negative
+    #[error("Chamfer distance must be positive ({0})")]
+    DistanceNotPositive(Scalar),
+
+    /// The cycle has edges that aren't line segments
+    #[error("Only polygons can be chamfered, but cycle has curved edges")]
+    CurvedCycle,
+
+    /// The chamfers at both ends of an edge overlap
+    #[error(
+        "Chamfers with distance {distance} overlap on edge {edge} (length \
+        {length})"
+    )]
+    Overlap {
+        /// The distance of the chamfer
+        distance: Scalar,
+
+        /// The index of the edge within its cycle
+        edge: usize,
+
+        /// The length of the edge
+        length: Scalar,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::objects::{Cycle, Face, HalfEdge, Surface};
+
+    use super::{Chamfer, ChamferError};
+
+    #[test]
+    fn chamfer_rectangle() -> anyhow::Result<()> {
+        let face = Face::build(Surface::xy_plane())
+            .polygon_from_points([[0., 0.], [4., 0.], [4., 2.], [0., 2.]])
+            .into_face()
+            .chamfer(0.5)?;
+
+        let vertices = face
+            .exterior()
+            .vertices()
+            .map(|vertex| vertex.surface_form().position())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vertices,
+            [
+                [0.5, 0.],
+                [3.5, 0.],
+                [4., 0.5],
+                [4., 1.5],
+                [3.5, 2.],
+                [0.5, 2.],
+                [0., 1.5],
+                [0., 0.5],
+            ]
+            .map(Point::from)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn chamfer_only_convex_corners() -> anyhow::Result<()> {
+        // An L-shape, with a concave corner at `[1., 1.]`.
+        let face = Face::build(Surface::xy_plane())
+            .polygon_from_points([
+                [0., 0.],
+                [2., 0.],
+                [2., 1.],
+                [1., 1.],
+                [1., 2.],
+                [0., 2.],
+            ])
+            .into_face()
+            .chamfer(0.25)?;
+
+        let vertices = face
+            .exterior()
+            .vertices()
+            .map(|vertex| vertex.surface_form().position())
+            .collect::<Vec<_>>();
+        assert_eq!(vertices.len(), 5 * 2 + 1);
+        assert!(vertices.contains(&Point::from([1., 1.])));
+
+        Ok(())
+    }
+
+    #[test]
+    fn chamfer_overlap() {
+        let face = Face::build(Surface::xy_plane())
+            .polygon_from_points([[0., 0.], [4., 0.], [4., 1.], [0., 1.]])
+            .into_face();
+
+        let result = face.chamfer(Scalar::from(0.6));
+        assert!(matches!(result, Err(ChamferError::Overlap { edge: 1, .. })));
+    }
+
+    #[test]
+    fn chamfer_circle() {
+        let surface = Surface::xy_plane();
+        let circle = Cycle::new(
+            surface,
+            [HalfEdge::build(surface).circle_from_radius(1.)],
+        );
+
+        let result = circle.chamfer(0.5);
+        assert!(matches!(result, Err(ChamferError::CurvedCycle)));
+    }
+}
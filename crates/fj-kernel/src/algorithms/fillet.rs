@@ -0,0 +1,310 @@
+//! Rounding the corners of polygons
+//!
+//! See [`Fillet`].
+
+use fj_math::{Point, Scalar, Vector};
+
+use crate::{
+    objects::{Cycle, Face, HalfEdge},
+    path::SurfacePath,
+};
+
+/// Round the corners of a polygon
+pub trait Fillet: Sized {
+    /// Replace each corner with a tangent arc of the given radius
+    ///
+    /// The two edges that meet at a corner are shortened, and an arc that is
+    /// tangent to both of them is inserted between them. Corners at which the
+    /// edges are collinear are left as they are.
+    ///
+    /// Returns an error, if `radius` is not positive, or if it's too large for
+    /// the arcs to fit. Every edge must keep some of its length, after the
+    /// arcs at both of its ends have been inserted.
+    ///
+    /// # Implementation Note
+    ///
+    /// Only polygons are supported, meaning all edges must be line segments.
+    /// Returns an error, if that's not the case.
+    fn fillet(self, radius: impl Into<Scalar>) -> Result<Self, FilletError>;
+}
+
+impl Fillet for Cycle {
+    fn fillet(self, radius: impl Into<Scalar>) -> Result<Self, FilletError> {
+        let radius = radius.into();
+
+        if radius <= Scalar::ZERO {
+            return Err(FilletError::RadiusNotPositive(radius));
+        }
+
+        let surface = *self.surface();
+        let points =
+            polygon_from_cycle(&self).ok_or(FilletError::CurvedCycle)?;
+        let num_points = points.len();
+
+        let corners = (0..num_points)
+            .map(|i| {
+                let prev = points[(i + num_points - 1) % num_points];
+                let next = points[(i + 1) % num_points];
+
+                Corner::new(prev, points[i], next, radius)
+            })
+            .collect::<Vec<_>>();
+
+        // The edge `i` runs from corner `i` to corner `i + 1`. Make sure the
+        // arcs at both of its ends leave some of it.
+        for (i, corner) in corners.iter().enumerate() {
+            let next = &corners[(i + 1) % num_points];
+
+            let length = (next.point - corner.point).magnitude();
+            let setback = corner.setback() + next.setback();
+
+            if setback >= length {
+                return Err(FilletError::RadiusTooLarge {
+                    radius,
+                    edge: i,
+                    length,
+                });
+            }
+        }
+
+        let arcs = corners
+            .iter()
+            .map(|corner| {
+                corner.arc.map(|arc| {
+                    HalfEdge::build(surface).arc_from_center_and_radius(
+                        arc.center,
+                        radius,
+                        arc.start_angle,
+                        arc.start_angle + arc.turn,
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Where an arc has been inserted, the edges start and end at its
+        // vertices. Use their exact positions, so all edges connect.
+        let [starts, ends] = [1, 0].map(|i| {
+            corners
+                .iter()
+                .zip(&arcs)
+                .map(|(corner, arc)| match arc {
+                    Some(arc) => arc.vertices()[i].surface_form().position(),
+                    None => corner.point,
+                })
+                .collect::<Vec<_>>()
+        });
+
+        let mut half_edges = Vec::new();
+        for (i, arc) in arcs.into_iter().enumerate() {
+            half_edges.extend(arc);
+            half_edges.push(HalfEdge::build(surface).line_segment_from_points(
+                [starts[i], ends[(i + 1) % num_points]],
+            ));
+        }
+
+        Ok(Cycle::new(surface, half_edges))
+    }
+}
+
+impl Fillet for Face {
+    fn fillet(self, radius: impl Into<Scalar>) -> Result<Self, FilletError> {
+        let radius = radius.into();
+
+        let exterior = self.exterior().clone().fillet(radius)?;
+        let interiors = self
+            .interiors()
+            .map(|cycle| cycle.clone().fillet(radius))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Face::new(*self.surface(), exterior)
+            .with_interiors(interiors)
+            .with_color(self.color()))
+    }
+}
+
+/// Error filleting a polygon
+#[derive(Debug, thiserror::Error)]
+pub enum FilletError {
+    /// The radius is zero or negative
+    #[error("Fillet radius must be positive ({0})")]
+    RadiusNotPositive(Scalar),
+
+    /// The cycle has edges that aren't line segments
+    #[error("Only polygons can be filleted, but cycle has curved edges")]
+    CurvedCycle,
+
+    /// The arcs at both ends of an edge don't fit on it
+    #[error(
+        "Fillet radius {radius} too large for edge {edge} (length {length})"
+    )]
+    RadiusTooLarge {
+        /// The radius of the fillet
+        radius: Scalar,
+
+        /// The index of the edge within its cycle
+        edge: usize,
+
+        /// The length of the edge
+        length: Scalar,
+    },
+}
+
+/// Access the corners of a cycle that consists of line segments
+///
+/// Returns `None`, if any edge of the cycle is not a line segment.
+pub(super) fn polygon_from_cycle(cycle: &Cycle) -> Option<Vec<Point<2>>> {
+    cycle
+        .half_edges()
+        .map(|half_edge| {
+            if let SurfacePath::Circle(_) = half_edge.curve().path() {
+                return None;
+            }
+
+            let [a, _] = half_edge.vertices();
+            Some(a.surface_form().position())
+        })
+        .collect()
+}
+
+struct Corner {
+    point: Point<2>,
+    arc: Option<CornerArc>,
+}
+
+impl Corner {
+    fn new(
+        prev: Point<2>,
+        point: Point<2>,
+        next: Point<2>,
+        radius: Scalar,
+    ) -> Self {
+        let incoming = (point - prev).normalize();
+        let outgoing = (next - point).normalize();
+
+        // The signed angle by which the boundary turns at the corner. It's
+        // positive, if the boundary turns left.
+        let turn = incoming.cross(&outgoing).atan2(incoming.dot(&outgoing));
+        if turn == Scalar::ZERO {
+            return Self { point, arc: None };
+        }
+
+        // The arc is tangent to both edges. It touches them at this distance
+        // from the corner, and its center is on the side that the boundary
+        // turns towards.
+        let setback =
+            radius * Scalar::from_f64((turn.abs() / 2.).into_f64().tan());
+        let start = point - incoming * setback;
+        let to_center = Vector::from([-incoming.v, incoming.u])
+            * radius
+            * turn.sign().to_scalar();
+        let center = start + to_center;
+
+        let start_angle = {
+            let radial = start - center;
+            radial.v.atan2(radial.u)
+        };
+
+        Self {
+            point,
+            arc: Some(CornerArc {
+                center,
+                start_angle,
+                turn,
+                setback,
+            }),
+        }
+    }
+
+    fn setback(&self) -> Scalar {
+        self.arc.map(|arc| arc.setback).unwrap_or(Scalar::ZERO)
+    }
+}
+
+#[derive(Clone, Copy)]
+struct CornerArc {
+    center: Point<2>,
+    start_angle: Scalar,
+    turn: Scalar,
+    setback: Scalar,
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        objects::{Cycle, Face, HalfEdge, Surface},
+        path::SurfacePath,
+    };
+
+    use super::{Fillet, FilletError};
+
+    #[test]
+    fn fillet_rectangle() -> anyhow::Result<()> {
+        let radius = Scalar::from(0.5);
+
+        let face = Face::build(Surface::xy_plane())
+            .polygon_from_points([[0., 0.], [4., 0.], [4., 2.], [0., 2.]])
+            .into_face()
+            .fillet(radius)?;
+
+        let half_edges = face.exterior().half_edges().collect::<Vec<_>>();
+        assert_eq!(half_edges.len(), 8);
+
+        let mut num_arcs = 0;
+        for half_edge in half_edges {
+            if let SurfacePath::Circle(circle) = half_edge.curve().path() {
+                assert_eq!(circle.radius(), radius);
+                num_arcs += 1;
+
+                // The arcs are tangent to the sides of the rectangle, which
+                // means their centers are inset by the radius.
+                let [u, v] = circle.center().coords.components;
+                assert!(is_near(u, 0.5) || is_near(u, 3.5));
+                assert!(is_near(v, 0.5) || is_near(v, 1.5));
+            }
+        }
+        assert_eq!(num_arcs, 4);
+
+        // All vertices of the arcs lie on the original sides.
+        for vertex in face.exterior().vertices() {
+            let [u, v] = vertex.surface_form().position().coords.components;
+            let is_on_side = is_near(u, 0.)
+                || is_near(u, 4.)
+                || is_near(v, 0.)
+                || is_near(v, 2.);
+            assert!(is_on_side);
+        }
+
+        Ok(())
+    }
+
+    fn is_near(a: Scalar, b: f64) -> bool {
+        (a - b).abs() < Scalar::from(1e-12)
+    }
+
+    #[test]
+    fn fillet_radius_too_large() {
+        let face = Face::build(Surface::xy_plane())
+            .polygon_from_points([[0., 0.], [4., 0.], [4., 2.], [0., 2.]])
+            .into_face();
+
+        let result = face.fillet(1.);
+        assert!(matches!(
+            result,
+            Err(FilletError::RadiusTooLarge { edge: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn fillet_circle() {
+        let surface = Surface::xy_plane();
+        let circle = Cycle::new(
+            surface,
+            [HalfEdge::build(surface).circle_from_radius(1.)],
+        );
+
+        let result = circle.fillet(0.5);
+        assert!(matches!(result, Err(FilletError::CurvedCycle)));
+    }
+}
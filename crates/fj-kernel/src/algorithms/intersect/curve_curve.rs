@@ -0,0 +1,465 @@
+use fj_math::{Circle, Line, Point, Scalar, Vector, DEFAULT_EPSILON};
+
+use crate::{objects::Curve, path::SurfacePath};
+
+/// The intersection between two [`Curve`]s
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum CurveCurveIntersection {
+    /// The curves intersect at one or two points
+    Points {
+        /// The intersection points, in curve coordinates on both curves
+        ///
+        /// Each entry contains the coordinate on the first curve, then the
+        /// coordinate on the second curve. The entries are sorted by their
+        /// coordinates on the first curve.
+        points: Vec<[Point<1>; 2]>,
+    },
+
+    /// The curves are lines or circles that lie on top of each other
+    Coincident {
+        /// The points at coordinates `0` and `1` on the second curve, in curve
+        /// coordinates on the first curve
+        ///
+        /// This can be used to convert between the coordinate systems of both
+        /// curves.
+        points_on_first: [Point<1>; 2],
+    },
+}
+
+impl CurveCurveIntersection {
+    /// Compute the intersection between two curves
+    ///
+    /// Points whose distance is not larger than `tolerance` are considered
+    /// identical. This means a line that is almost tangent to a circle touches
+    /// it in one point, and two lines that are almost on top of each other are
+    /// considered coincident.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the curves are not defined in the same surface.
+    pub fn compute(
+        curves: [&Curve; 2],
+        tolerance: impl Into<Scalar>,
+    ) -> Option<Self> {
+        let [a, b] = curves;
+        let tolerance = tolerance.into();
+
+        assert_eq!(
+            a.surface(),
+            b.surface(),
+            "Can't intersect curves in different surfaces"
+        );
+
+        match (a.path(), b.path()) {
+            (SurfacePath::Line(a), SurfacePath::Line(b)) => {
+                line_line(&a, &b, tolerance)
+            }
+            (SurfacePath::Line(line), SurfacePath::Circle(circle)) => {
+                let points = line_circle(&line, &circle, tolerance)?;
+                Some(Self::Points { points })
+            }
+            (SurfacePath::Circle(circle), SurfacePath::Line(line)) => {
+                let mut points = line_circle(&line, &circle, tolerance)?
+                    .into_iter()
+                    .map(|[on_line, on_circle]| [on_circle, on_line])
+                    .collect::<Vec<_>>();
+                points.sort();
+
+                Some(Self::Points { points })
+            }
+            (SurfacePath::Circle(a), SurfacePath::Circle(b)) => {
+                circle_circle(&a, &b, tolerance)
+            }
+        }
+    }
+}
+
+fn line_line(
+    a: &Line<2>,
+    b: &Line<2>,
+    tolerance: Scalar,
+) -> Option<CurveCurveIntersection> {
+    let points_of_b = [0., 1.].map(|t| b.point_from_line_coords([t]));
+
+    let is_coincident = points_of_b
+        .iter()
+        .all(|&point| distance_to_line(a, point) <= tolerance);
+    if is_coincident {
+        return Some(CurveCurveIntersection::Coincident {
+            points_on_first: points_of_b
+                .map(|point| a.point_to_line_coords(point)),
+        });
+    }
+
+    let denominator = a.direction().cross(&b.direction());
+
+    // The sine of the angle between the lines. Lines that are this close to
+    // parallel would intersect far outside of any meaningful range.
+    let sine =
+        denominator / (a.direction().magnitude() * b.direction().magnitude());
+    if sine.approx_eq(Scalar::ZERO, DEFAULT_EPSILON) {
+        // The lines are parallel, but not coincident.
+        return None;
+    }
+
+    let origin_to_origin = b.origin() - a.origin();
+    let t = origin_to_origin.cross(&b.direction()) / denominator;
+    let s = origin_to_origin.cross(&a.direction()) / denominator;
+
+    Some(CurveCurveIntersection::Points {
+        points: vec![[Point::from([t]), Point::from([s])]],
+    })
+}
+
+/// Compute the intersection between a line and a circle
+///
+/// Returns the intersection points in curve coordinates, on the line first,
+/// then on the circle. Returns `None`, if there are no intersection points.
+fn line_circle(
+    line: &Line<2>,
+    circle: &Circle<2>,
+    tolerance: Scalar,
+) -> Option<Vec<[Point<1>; 2]>> {
+    let center = circle.center();
+    let radius = circle.radius();
+
+    let closest = line.point_to_line_coords(center);
+    let closest_point = line.point_from_line_coords(closest);
+    let distance = (center - closest_point).magnitude();
+
+    if distance > radius + tolerance {
+        return None;
+    }
+
+    if (distance - radius).abs() <= tolerance {
+        // The line touches the circle. The point on the line that is closest
+        // to the center might not be exactly on the circle, so project it
+        // there, to get the coordinate on the circle.
+        let point_on_circle = if distance == Scalar::ZERO {
+            closest_point
+        } else {
+            center + (closest_point - center) * (radius / distance)
+        };
+
+        return Some(vec![[closest, circle_coords(circle, point_on_circle)]]);
+    }
+
+    // The line crosses the circle. The intersection points are at equal
+    // distance to either side of the point closest to the center.
+    let half_chord = Scalar::from_f64(
+        (radius * radius - distance * distance).into_f64().sqrt(),
+    );
+    let offset = half_chord / line.direction().magnitude();
+
+    let points = [closest.t - offset, closest.t + offset]
+        .into_iter()
+        .map(|t| {
+            let point = line.point_from_line_coords([t]);
+            [Point::from([t]), circle_coords(circle, point)]
+        })
+        .collect();
+
+    Some(points)
+}
+
+fn circle_circle(
+    a: &Circle<2>,
+    b: &Circle<2>,
+    tolerance: Scalar,
+) -> Option<CurveCurveIntersection> {
+    let [radius_a, radius_b] = [a.radius(), b.radius()];
+
+    let center_to_center = b.center() - a.center();
+    let distance = center_to_center.magnitude();
+
+    if distance <= tolerance {
+        if (radius_a - radius_b).abs() > tolerance {
+            // The circles are concentric, but don't touch.
+            return None;
+        }
+
+        let points_of_b = [0., 1.].map(|t| b.point_from_circle_coords([t]));
+        return Some(CurveCurveIntersection::Coincident {
+            points_on_first: points_of_b.map(|point| circle_coords(a, point)),
+        });
+    }
+
+    let distance_outer = radius_a + radius_b;
+    let distance_inner = (radius_a - radius_b).abs();
+
+    if distance > distance_outer + tolerance
+        || distance < distance_inner - tolerance
+    {
+        return None;
+    }
+
+    let direction = center_to_center / distance;
+
+    let is_touching_outside = (distance - distance_outer).abs() <= tolerance;
+    let is_touching_inside = (distance - distance_inner).abs() <= tolerance;
+    if is_touching_outside || is_touching_inside {
+        // The circles touch in a point on the line through both centers. If
+        // the first circle touches the second from inside, that point is on
+        // the far side of its center.
+        let point = if is_touching_inside && radius_a < radius_b {
+            a.center() - direction * radius_a
+        } else {
+            a.center() + direction * radius_a
+        };
+
+        return Some(CurveCurveIntersection::Points {
+            points: vec![[circle_coords(a, point), circle_coords(b, point)]],
+        });
+    }
+
+    // The circles cross. The intersection points are at equal distance to
+    // either side of the line through both centers.
+    let along = (distance * distance + radius_a * radius_a
+        - radius_b * radius_b)
+        / (distance * 2.);
+    let half_chord = Scalar::from_f64(
+        (radius_a * radius_a - along * along)
+            .max(Scalar::ZERO)
+            .into_f64()
+            .sqrt(),
+    );
+
+    let base = a.center() + direction * along;
+    let perpendicular = Vector::from([-direction.v, direction.u]);
+
+    let mut points = [
+        base - perpendicular * half_chord,
+        base + perpendicular * half_chord,
+    ]
+    .into_iter()
+    .map(|point| [circle_coords(a, point), circle_coords(b, point)])
+    .collect::<Vec<_>>();
+    points.sort();
+
+    Some(CurveCurveIntersection::Points { points })
+}
+
+fn distance_to_line(line: &Line<2>, point: Point<2>) -> Scalar {
+    let closest = line.point_from_line_coords(line.point_to_line_coords(point));
+    (point - closest).magnitude()
+}
+
+/// Convert a point on a circle into circle coordinates
+///
+/// Unlike [`Circle::point_to_circle_coords`], this takes the orientation of the
+/// circle, as defined by its `a` and `b` vectors, into account.
+fn circle_coords(circle: &Circle<2>, point: Point<2>) -> Point<1> {
+    let vector = point - circle.center();
+    let angle = Scalar::atan2(vector.dot(&circle.b()), vector.dot(&circle.a()));
+
+    let coord = if angle >= Scalar::ZERO {
+        angle
+    } else {
+        angle + Scalar::TAU
+    };
+
+    Point::from([coord])
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::objects::{Curve, Surface};
+
+    use super::CurveCurveIntersection;
+
+    const TOLERANCE: f64 = 1e-9;
+
+    fn assert_points_near(
+        intersection: Option<CurveCurveIntersection>,
+        expected: &[[f64; 2]],
+    ) {
+        let points = match intersection {
+            Some(CurveCurveIntersection::Points { points }) => points,
+            intersection => panic!("Expected points, got {intersection:?}"),
+        };
+
+        assert_eq!(points.len(), expected.len());
+        for (point, expected) in points.iter().zip(expected) {
+            for (coord, expected) in point.iter().zip(expected) {
+                assert!(
+                    (coord.t - Scalar::from(*expected)).abs()
+                        < Scalar::from(1e-12)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn line_line_crossing() {
+        let surface = Surface::xy_plane();
+        let a = Curve::build(surface).line_from_points([[0., 0.], [2., 0.]]);
+        let b = Curve::build(surface).line_from_points([[1., -1.], [1., 1.]]);
+
+        let intersection = CurveCurveIntersection::compute([&a, &b], TOLERANCE);
+        assert_points_near(intersection, &[[0.5, 0.5]]);
+    }
+
+    #[test]
+    fn line_line_parallel() {
+        let surface = Surface::xy_plane();
+        let a = Curve::build(surface).line_from_points([[0., 0.], [1., 0.]]);
+        let b = Curve::build(surface).line_from_points([[0., 1.], [1., 1.]]);
+
+        let intersection = CurveCurveIntersection::compute([&a, &b], TOLERANCE);
+        assert_eq!(intersection, None);
+
+        // Lines that are parallel, except for floating-point drift.
+        let b = Curve::build(surface)
+            .line_from_points([[0., 1.], [1., 1. + 1e-15]]);
+
+        let intersection = CurveCurveIntersection::compute([&a, &b], TOLERANCE);
+        assert_eq!(intersection, None);
+    }
+
+    #[test]
+    fn line_line_coincident() {
+        let surface = Surface::xy_plane();
+        let a = Curve::build(surface).line_from_points([[0., 0.], [1., 0.]]);
+        let b = Curve::build(surface).line_from_points([[3., 0.], [1., 0.]]);
+
+        let intersection = CurveCurveIntersection::compute([&a, &b], TOLERANCE);
+        assert_eq!(
+            intersection,
+            Some(CurveCurveIntersection::Coincident {
+                points_on_first: [Point::from([3.]), Point::from([1.])],
+            })
+        );
+    }
+
+    #[test]
+    fn line_circle_miss() {
+        let surface = Surface::xy_plane();
+        let line = Curve::build(surface).line_from_points([[0., 2.], [1., 2.]]);
+        let circle = Curve::build(surface).circle_from_radius(1.);
+
+        let intersection =
+            CurveCurveIntersection::compute([&line, &circle], TOLERANCE);
+        assert_eq!(intersection, None);
+    }
+
+    #[test]
+    fn line_circle_tangent() {
+        let surface = Surface::xy_plane();
+        let circle = Curve::build(surface).circle_from_radius(1.);
+
+        // Lines that almost touch the circle, from either side, are treated as
+        // tangent.
+        for v in [1., 1. - TOLERANCE / 2., 1. + TOLERANCE / 2.] {
+            let line =
+                Curve::build(surface).line_from_points([[0., v], [1., v]]);
+
+            let intersection =
+                CurveCurveIntersection::compute([&line, &circle], TOLERANCE);
+            assert_points_near(
+                intersection,
+                &[[0., std::f64::consts::FRAC_PI_2]],
+            );
+        }
+    }
+
+    #[test]
+    fn line_circle_crossing() {
+        let surface = Surface::xy_plane();
+        let line =
+            Curve::build(surface).line_from_points([[-2., 0.], [2., 0.]]);
+        let circle = Curve::build(surface).circle_from_radius(1.);
+
+        let intersection =
+            CurveCurveIntersection::compute([&line, &circle], TOLERANCE);
+        assert_points_near(
+            intersection,
+            &[[0.25, std::f64::consts::PI], [0.75, 0.]],
+        );
+
+        // Swapping the curves swaps the coordinates, and sorts the points by
+        // their coordinates on the circle.
+        let intersection =
+            CurveCurveIntersection::compute([&circle, &line], TOLERANCE);
+        assert_points_near(
+            intersection,
+            &[[0., 0.75], [std::f64::consts::PI, 0.25]],
+        );
+    }
+
+    #[test]
+    fn circle_circle_miss() {
+        let surface = Surface::xy_plane();
+        let a = Curve::build(surface).circle_from_radius(1.);
+
+        // Apart from each other, and one inside of the other.
+        let b =
+            Curve::build(surface).circle_from_center_and_radius([3., 0.], 1.);
+        let c = Curve::build(surface).circle_from_radius(0.5);
+
+        for other in [b, c] {
+            let intersection =
+                CurveCurveIntersection::compute([&a, &other], TOLERANCE);
+            assert_eq!(intersection, None);
+        }
+    }
+
+    #[test]
+    fn circle_circle_tangent() {
+        let surface = Surface::xy_plane();
+        let a = Curve::build(surface).circle_from_radius(1.);
+
+        // Touching from outside.
+        let b =
+            Curve::build(surface).circle_from_center_and_radius([2., 0.], 1.);
+        let intersection = CurveCurveIntersection::compute([&a, &b], TOLERANCE);
+        assert_points_near(intersection, &[[0., std::f64::consts::PI]]);
+
+        // Touching from inside, in both orders.
+        let c =
+            Curve::build(surface).circle_from_center_and_radius([0.5, 0.], 0.5);
+        for curves in [[&a, &c], [&c, &a]] {
+            let intersection =
+                CurveCurveIntersection::compute(curves, TOLERANCE);
+            assert_points_near(intersection, &[[0., 0.]]);
+        }
+    }
+
+    #[test]
+    fn circle_circle_crossing() {
+        let surface = Surface::xy_plane();
+        let a = Curve::build(surface).circle_from_radius(1.);
+        let b =
+            Curve::build(surface).circle_from_center_and_radius([1., 0.], 1.);
+
+        let intersection = CurveCurveIntersection::compute([&a, &b], TOLERANCE);
+
+        let sixth = std::f64::consts::TAU / 6.;
+        assert_points_near(
+            intersection,
+            &[[sixth, sixth * 2.], [sixth * 5., sixth * 4.]],
+        );
+    }
+
+    #[test]
+    fn circle_circle_coincident() {
+        let surface = Surface::xy_plane();
+        let a = Curve::build(surface).circle_from_radius(1.);
+        let b = Curve::build(surface).circle_from_radius(1.);
+
+        let intersection = CurveCurveIntersection::compute([&a, &b], TOLERANCE);
+        let points_on_first = match intersection {
+            Some(CurveCurveIntersection::Coincident { points_on_first }) => {
+                points_on_first
+            }
+            intersection => panic!("Expected coincident, got {intersection:?}"),
+        };
+
+        for (point, expected) in points_on_first.iter().zip([0., 1.]) {
+            assert!(
+                (point.t - Scalar::from(expected)).abs() < Scalar::from(1e-12)
+            );
+        }
+    }
+}
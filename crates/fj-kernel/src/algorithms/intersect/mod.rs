@@ -2,9 +2,11 @@
 
 pub mod face_point;
 pub mod ray_edge;
+pub mod ray_exact;
 pub mod ray_face;
 pub mod ray_segment;
 
+mod curve_curve;
 mod curve_edge;
 mod curve_face;
 mod face_face;
@@ -14,11 +16,12 @@ mod surface_surface;
 use fj_math::Point;
 
 pub use self::{
+    curve_curve::CurveCurveIntersection,
     curve_edge::CurveEdgeIntersection,
     curve_face::{CurveFaceIntersection, CurveFaceIntersectionInterval},
     face_face::FaceFaceIntersection,
     line_segment::LineSegmentIntersection,
-    surface_surface::SurfaceSurfaceIntersection,
+    surface_surface::{SurfaceIntersection, SurfaceSurfaceIntersection},
 };
 
 /// Compute the intersection between a tuple of objects
@@ -0,0 +1,236 @@
+//! Exact intersection between a ray and a face, in 3D
+//!
+//! Unlike the intersection with a triangle mesh, this intersection is computed
+//! against the face's surface directly, and is not subject to the tolerance of
+//! an approximation.
+
+use fj_math::{Point, Scalar, Vector};
+
+use crate::{objects::Face, path::GlobalPath};
+
+use super::Intersect;
+
+/// A ray in 3D
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Ray {
+    /// The point where the ray originates
+    pub origin: Point<3>,
+
+    /// The direction of the ray
+    pub direction: Vector<3>,
+}
+
+impl Ray {
+    /// Compute the point at the given distance along the ray
+    ///
+    /// The distance is given in multiples of the length of the ray's direction.
+    pub fn point_at(&self, distance: Scalar) -> Point<3> {
+        self.origin + self.direction * distance
+    }
+}
+
+impl Intersect for (&Ray, &Face) {
+    type Intersection = RayHit;
+
+    /// Compute the nearest point where the ray hits the face
+    ///
+    /// Supports faces in planar and cylindrical surfaces. Cylindrical surfaces
+    /// don't need to be right cylinders, meaning the surface's `v` vector is
+    /// not required to be perpendicular to the circle that defines `u`.
+    fn intersect(self) -> Option<Self::Intersection> {
+        let (ray, face) = self;
+
+        let candidates = match face.surface().u() {
+            GlobalPath::Line(line) => {
+                ray_plane(ray, line.origin(), line.direction(), face)
+            }
+            GlobalPath::Circle(circle) => ray_cylinder(
+                ray,
+                circle.center(),
+                [circle.a(), circle.b()],
+                face,
+            ),
+        };
+
+        candidates
+            .into_iter()
+            .find_map(|(distance, point_surface)| {
+                if !face.contains_point(point_surface) {
+                    return None;
+                }
+
+                Some(RayHit {
+                    distance,
+                    point: ray.point_at(distance),
+                    point_surface,
+                })
+            })
+    }
+}
+
+/// A hit between a ray and a face
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RayHit {
+    /// The distance of the hit from the ray's origin
+    ///
+    /// Given in multiples of the length of the ray's direction.
+    pub distance: Scalar,
+
+    /// The point where the ray hits the face, in global coordinates
+    pub point: Point<3>,
+
+    /// The point where the ray hits the face, in surface coordinates
+    pub point_surface: Point<2>,
+}
+
+/// Intersect the ray with a plane
+///
+/// Returns the distance along the ray and the surface coordinates of the hit.
+fn ray_plane(
+    ray: &Ray,
+    origin: Point<3>,
+    u: Vector<3>,
+    face: &Face,
+) -> Vec<(Scalar, Point<2>)> {
+    let v = face.surface().v();
+    let normal = u.cross(&v);
+
+    let denominator = normal.dot(&ray.direction);
+    if denominator == Scalar::ZERO {
+        // The ray is parallel to the plane. Even if it lies within the plane,
+        // there's no single point where it hits.
+        return Vec::new();
+    }
+
+    let distance = normal.dot(&(origin - ray.origin)) / denominator;
+    if distance < Scalar::ZERO {
+        return Vec::new();
+    }
+
+    // Solve `point - origin = s * u + t * v`. The axes of the plane don't need
+    // to be orthogonal.
+    let w = ray.point_at(distance) - origin;
+    let uu = u.dot(&u);
+    let uv = u.dot(&v);
+    let vv = v.dot(&v);
+    let wu = w.dot(&u);
+    let wv = w.dot(&v);
+
+    let det = uu * vv - uv * uv;
+    let s = (wu * vv - wv * uv) / det;
+    let t = (wv * uu - wu * uv) / det;
+
+    vec![(distance, Point::from([s, t]))]
+}
+
+/// Intersect the ray with a cylinder
+///
+/// Returns the distances along the ray and the surface coordinates of the
+/// hits, nearest hit first.
+fn ray_cylinder(
+    ray: &Ray,
+    center: Point<3>,
+    [a, b]: [Vector<3>; 2],
+    face: &Face,
+) -> Vec<(Scalar, Point<2>)> {
+    let v = face.surface().v();
+
+    // Express vectors in the basis `a`, `b`, `v`. A point is on the cylinder,
+    // if its `a` and `b` coordinates are the cosine and sine of the same angle.
+    let det = a.dot(&b.cross(&v));
+    let to_basis = |w: Vector<3>| {
+        [
+            w.dot(&b.cross(&v)) / det,
+            a.dot(&w.cross(&v)) / det,
+            a.dot(&b.cross(&w)) / det,
+        ]
+    };
+
+    let [a0, b0, _] = to_basis(ray.origin - center);
+    let [a1, b1, _] = to_basis(ray.direction);
+
+    // Solve `(a0 + d * a1)^2 + (b0 + d * b1)^2 = 1` for the distance `d`.
+    let qa = a1 * a1 + b1 * b1;
+    let qb = (a0 * a1 + b0 * b1) * 2.;
+    let qc = a0 * a0 + b0 * b0 - Scalar::ONE;
+
+    if qa == Scalar::ZERO {
+        // The ray is parallel to the cylinder's axis.
+        return Vec::new();
+    }
+
+    let discriminant = qb * qb - qa * qc * 4.;
+    if discriminant < Scalar::ZERO {
+        return Vec::new();
+    }
+    let root = Scalar::from_f64(discriminant.into_f64().sqrt());
+
+    [(-qb - root) / (qa * 2.), (-qb + root) / (qa * 2.)]
+        .into_iter()
+        .filter(|&distance| distance >= Scalar::ZERO)
+        .map(|distance| {
+            let [a, b, v] = to_basis(ray.point_at(distance) - center);
+            let angle = Scalar::atan2(b, a);
+
+            (distance, Point::from([angle, v]))
+        })
+        .flat_map(|(distance, point)| {
+            // The face might be bounded by edges that are outside of the range
+            // `atan2` returns. Check the equivalent angles too.
+            [-Scalar::TAU, Scalar::ZERO, Scalar::TAU].map(|offset| {
+                (distance, Point::from([point.u + offset, point.v]))
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar, Vector};
+
+    use crate::{
+        algorithms::sweep::Sweep,
+        objects::{Cycle, Face, HalfEdge, Solid, Surface},
+    };
+
+    use super::Ray;
+
+    #[test]
+    fn raycast_cylinder() {
+        let surface = Surface::xy_plane();
+        let cycle = Cycle::new(
+            surface,
+            [HalfEdge::build(surface).circle_from_radius(1.)],
+        );
+        let cylinder = Solid::new()
+            .with_shells([Face::new(surface, cycle).sweep([0., 0., 2.])]);
+
+        let cast = |origin: [f64; 3], direction: [f64; 3]| {
+            let ray = Ray {
+                origin: Point::from(origin),
+                direction: Vector::from(direction),
+            };
+
+            cylinder.raycast_exact(&ray).map(|hit| hit.point)
+        };
+
+        let assert_hit = |hit: Option<Point<3>>, expected: [f64; 3]| {
+            let hit = hit.expect("Expected ray to hit");
+            let distance = (hit - Point::from(expected)).magnitude();
+            assert!(distance < Scalar::from(1e-12), "Unexpected hit: {hit:?}");
+        };
+
+        // Measure the diameter of the cylinder, by hitting it from both sides.
+        let left = cast([-5., 0., 1.], [1., 0., 0.]);
+        let right = cast([5., 0., 1.], [-1., 0., 0.]);
+        assert_hit(left, [-1., 0., 1.]);
+        assert_hit(right, [1., 0., 1.]);
+
+        // Hit the circular top face.
+        assert_hit(cast([0.5, 0., 5.], [0., 0., -1.]), [0.5, 0., 2.]);
+
+        // Miss the cylinder.
+        assert!(cast([-5., 2., 1.], [1., 0., 0.]).is_none());
+        assert!(cast([2., 0., 5.], [0., 0., -1.]).is_none());
+    }
+}
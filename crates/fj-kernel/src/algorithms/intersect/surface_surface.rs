@@ -1,4 +1,4 @@
-use fj_math::{Line, Point, Scalar, Vector};
+use fj_math::{Line, Point, Scalar, Vector, DEFAULT_EPSILON};
 
 use crate::{
     objects::{Curve, GlobalCurve, Surface},
@@ -14,6 +14,9 @@ pub struct SurfaceSurfaceIntersection {
 
 impl SurfaceSurfaceIntersection {
     /// Compute the intersection between two surfaces
+    ///
+    /// Returns `None`, if the surfaces don't intersect, or if either of them
+    /// is not a plane. Only planes are supported so far.
     pub fn compute(surfaces: [&Surface; 2]) -> Option<Self> {
         // Algorithm from Real-Time Collision Detection by Christer Ericson. See
         // section 5.4.4, Intersection of Two Planes.
@@ -22,39 +25,22 @@ impl SurfaceSurfaceIntersection {
         // coordinates for each surface.
 
         let planes_parametric = surfaces.map(|surface| {
-            let plane = PlaneParametric::extract_from_surface(surface);
-            (*surface, plane)
+            PlaneParametric::extract_from_surface(surface)
+                .map(|plane| (*surface, plane))
         });
+        let planes_parametric = match planes_parametric {
+            [Some(a), Some(b)] => [a, b],
+            _ => return None,
+        };
         let [a, b] = planes_parametric.map(|(_, plane)| {
             PlaneConstantNormal::from_parametric_plane(&plane)
         });
 
-        let direction = a.normal.cross(&b.normal);
-
-        let denom = direction.dot(&direction);
-        if denom == Scalar::ZERO {
-            // Comparing `denom` against zero looks fishy. It's probably better
-            // to compare it against an epsilon value, but I don't know how
-            // large that epsilon should be.
-            //
-            // I'll just leave it like that, until we had the opportunity to
-            // collect some experience with this code.
-            // - @hannobraun
-            return None;
-        }
-
-        let origin = (b.normal * a.distance - a.normal * b.distance)
-            .cross(&direction)
-            / denom;
-        let origin = Point { coords: origin };
-
-        let line = Line::from_origin_and_direction(origin, direction);
+        let line = intersection_line([&a, &b])?;
 
         let curves = planes_parametric.map(|(surface, plane)| {
             let local = project_line_into_plane(&line, &plane);
-            let global = GlobalPath::Line(Line::from_origin_and_direction(
-                origin, direction,
-            ));
+            let global = GlobalPath::Line(line);
 
             Curve::new(surface, local, GlobalCurve::from_path(global))
         });
@@ -65,6 +51,71 @@ impl SurfaceSurfaceIntersection {
     }
 }
 
+/// The intersection between two surfaces, in global coordinates
+///
+/// See [`Surface::intersect`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum SurfaceIntersection {
+    /// The surfaces intersect along a curve
+    Curve(GlobalCurve),
+
+    /// The surfaces are coincident
+    Coincident,
+}
+
+impl SurfaceIntersection {
+    /// Compute the intersection between two surfaces
+    ///
+    /// See [`Surface::intersect`].
+    pub fn compute(surfaces: [&Surface; 2]) -> Option<Self> {
+        let [a, b] = match surfaces.map(PlaneParametric::extract_from_surface) {
+            [Some(a), Some(b)] => [a, b].map(|plane| {
+                PlaneConstantNormal::from_parametric_plane(&plane)
+            }),
+            _ => return None,
+        };
+
+        if let Some(line) = intersection_line([&a, &b]) {
+            let curve = GlobalCurve::from_path(GlobalPath::Line(line));
+            return Some(Self::Curve(curve));
+        }
+
+        // The planes are parallel. They are coincident, if a point on one of
+        // them is on the other too.
+        let point_on_b = b.normal * b.distance;
+        a.normal
+            .dot(&point_on_b)
+            .approx_eq(a.distance, DEFAULT_EPSILON)
+            .then_some(Self::Coincident)
+    }
+}
+
+/// Compute the line along which two planes intersect
+///
+/// Returns `None`, if the planes are parallel.
+fn intersection_line([a, b]: [&PlaneConstantNormal; 2]) -> Option<Line<3>> {
+    let direction = a.normal.cross(&b.normal);
+
+    // The normals are normalized, so the length of `direction` is the sine of
+    // the angle between the planes. Treat planes as parallel, if it's within
+    // floating-point drift of zero.
+    if direction
+        .magnitude()
+        .approx_eq(Scalar::ZERO, DEFAULT_EPSILON)
+    {
+        return None;
+    }
+
+    let denom = direction.dot(&direction);
+
+    let origin = (b.normal * a.distance - a.normal * b.distance)
+        .cross(&direction)
+        / denom;
+    let origin = Point { coords: origin };
+
+    Some(Line::from_origin_and_direction(origin, direction))
+}
+
 /// A plane in parametric form
 #[derive(Clone, Copy)]
 struct PlaneParametric {
@@ -74,23 +125,20 @@ struct PlaneParametric {
 }
 
 impl PlaneParametric {
-    pub fn extract_from_surface(surface: &Surface) -> Self {
-        let (line, path) = {
-            let line = match surface.u() {
-                GlobalPath::Line(line) => line,
-                _ => todo!(
-                    "Only plane-plane intersection is currently supported."
-                ),
-            };
-
-            (line, surface.v())
+    /// Extract a plane from a `Surface`
+    ///
+    /// Returns `None`, if the surface is not a plane.
+    pub fn extract_from_surface(surface: &Surface) -> Option<Self> {
+        let line = match surface.u() {
+            GlobalPath::Line(line) => line,
+            GlobalPath::Circle(_) => return None,
         };
 
-        Self {
+        Some(Self {
             origin: line.origin(),
             u: line.direction(),
-            v: path,
-        }
+            v: surface.v(),
+        })
     }
 }
 
@@ -4,7 +4,10 @@
 //! on their respective purpose.
 
 pub mod approx;
+pub mod chamfer;
+pub mod fillet;
 pub mod intersect;
+pub mod offset;
 pub mod reverse;
 pub mod sweep;
 pub mod transform;
@@ -0,0 +1,565 @@
+//! Offsetting the boundaries of faces
+//!
+//! See [`Offset`].
+
+use fj_math::{signed_area, Point, Scalar, Vector};
+
+use crate::{
+    algorithms::{
+        approx::{Approx, Tolerance},
+        reverse::Reverse,
+    },
+    objects::{Cycle, Face, HalfEdge, Sketch},
+    path::SurfacePath,
+};
+
+/// Grow or shrink a face by moving its boundary
+pub trait Offset: Sized {
+    /// Move the boundary by the given distance
+    ///
+    /// Every edge is moved along its outward normal, which points away from
+    /// the material of the face. A positive `distance` grows the face, a
+    /// negative one shrinks it.
+    ///
+    /// Where two edges move apart, an arc that is centered on their original
+    /// corner connects them. For positive distances, this happens at convex
+    /// corners, which turns a rectangle into a rounded rectangle. Where two
+    /// edges move towards each other, they are trimmed at their intersection.
+    /// Edges that collapse completely are removed.
+    ///
+    /// Returns an error, if the boundary collapses, or if the result
+    /// intersects itself. Interior cycles of a face that collapse are not an
+    /// error. The hole they represent has been filled, and they are removed.
+    ///
+    /// # Implementation Note
+    ///
+    /// Cycles that consist of a single circle are offset exactly. All other
+    /// cycles are replaced by their approximation first, using `tolerance`.
+    /// That means any curved edges in them turn into polygonal chains.
+    fn offset(
+        self,
+        distance: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Result<Self, OffsetError>;
+}
+
+impl Offset for Cycle {
+    fn offset(
+        self,
+        distance: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Result<Self, OffsetError> {
+        let distance = distance.into();
+        let tolerance = tolerance.into();
+
+        if distance == Scalar::ZERO {
+            return Ok(self);
+        }
+
+        let surface = *self.surface();
+
+        let mut points = self
+            .approx(tolerance)
+            .points()
+            .into_iter()
+            .map(|point| point.local_form)
+            .collect::<Vec<_>>();
+
+        // The approximation of a cycle repeats its first point at the end, to
+        // close it.
+        points.pop();
+
+        // The material is on the left side of the cycle, so the outward normal
+        // points to the right. For a counter-clockwise cycle, that's away from
+        // its center.
+        let is_counter_clockwise = signed_area(&points) > Scalar::ZERO;
+
+        let half_edges = self.half_edges().collect::<Vec<_>>();
+        if let [half_edge] = half_edges.as_slice() {
+            if let SurfacePath::Circle(circle) = half_edge.curve().path() {
+                let radius = if is_counter_clockwise {
+                    circle.radius() + distance
+                } else {
+                    circle.radius() - distance
+                };
+
+                if radius <= Scalar::ZERO {
+                    return Err(OffsetError::Collapses(distance));
+                }
+
+                let half_edge = HalfEdge::build(surface)
+                    .circle_from_center_and_radius(circle.center(), radius);
+                let half_edge = if is_counter_clockwise {
+                    half_edge
+                } else {
+                    half_edge.reverse()
+                };
+
+                return Ok(Cycle::new(surface, [half_edge]));
+            }
+        }
+
+        let mut lines = (0..points.len())
+            .map(|i| {
+                let next = points[(i + 1) % points.len()];
+                OffsetLine::new([points[i], next], distance)
+            })
+            .collect::<Vec<_>>();
+
+        // The corner `i` is where line `i - 1` ends and line `i` starts.
+        // Recompute them, until no line is reversed by its corners.
+        let corners = loop {
+            let num_lines = lines.len();
+
+            if num_lines < 3 {
+                return Err(OffsetError::Collapses(distance));
+            }
+
+            let corners = (0..num_lines)
+                .map(|i| {
+                    let prev = &lines[(i + num_lines - 1) % num_lines];
+                    Corner::new(prev, &lines[i], distance)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let most_reversed = (0..num_lines)
+                .map(|i| {
+                    let start = corners[i].end();
+                    let end = corners[(i + 1) % num_lines].start();
+
+                    (i, (end - start).dot(&lines[i].direction))
+                })
+                .filter(|&(_, length)| length <= Scalar::ZERO)
+                .min_by_key(|&(_, length)| length);
+
+            match most_reversed {
+                Some((i, _)) => {
+                    lines.remove(i);
+                }
+                None => break corners,
+            }
+        };
+
+        let arcs = corners
+            .iter()
+            .map(|corner| match *corner {
+                Corner::Arc { center, angles, .. } => {
+                    let [start_angle, end_angle] = angles;
+                    Some(HalfEdge::build(surface).arc_from_center_and_radius(
+                        center,
+                        distance.abs(),
+                        start_angle,
+                        end_angle,
+                    ))
+                }
+                Corner::Trim(_) => None,
+            })
+            .collect::<Vec<_>>();
+
+        // Where an arc has been inserted, the lines start and end at its
+        // vertices. Use their exact positions, so all edges connect.
+        let [starts, ends] = [1, 0].map(|i| {
+            corners
+                .iter()
+                .zip(&arcs)
+                .map(|(corner, arc)| match (corner, arc) {
+                    (_, Some(arc)) => {
+                        arc.vertices()[i].surface_form().position()
+                    }
+                    (Corner::Trim(point), None) => *point,
+                    (Corner::Arc { .. }, None) => {
+                        unreachable!("Arc has been built for every arc corner")
+                    }
+                })
+                .collect::<Vec<_>>()
+        });
+
+        let num_corners = corners.len();
+        let mut half_edges = Vec::new();
+        for (i, arc) in arcs.into_iter().enumerate() {
+            half_edges.extend(arc);
+            half_edges.push(HalfEdge::build(surface).line_segment_from_points(
+                [starts[i], ends[(i + 1) % num_corners]],
+            ));
+        }
+
+        let cycle = Cycle::new(surface, half_edges);
+
+        let segments = segments_from_cycle(&cycle, tolerance);
+        if segments_intersect(&segments, &segments) {
+            return Err(OffsetError::SelfIntersects(distance));
+        }
+
+        Ok(cycle)
+    }
+}
+
+impl Offset for Face {
+    fn offset(
+        self,
+        distance: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Result<Self, OffsetError> {
+        let distance = distance.into();
+        let tolerance = tolerance.into();
+
+        let exterior = self.exterior().clone().offset(distance, tolerance)?;
+
+        let mut interiors = Vec::new();
+        for interior in self.interiors() {
+            match interior.clone().offset(distance, tolerance) {
+                Ok(interior) => interiors.push(interior),
+                Err(OffsetError::Collapses(_)) => {
+                    // The hole has been filled.
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        // The cycles have been checked individually. Make sure they don't
+        // intersect each other either.
+        let segments = [&exterior]
+            .into_iter()
+            .chain(&interiors)
+            .map(|cycle| segments_from_cycle(cycle, tolerance))
+            .collect::<Vec<_>>();
+        for (i, a) in segments.iter().enumerate() {
+            for b in &segments[i + 1..] {
+                if segments_intersect(a, b) {
+                    return Err(OffsetError::SelfIntersects(distance));
+                }
+            }
+        }
+
+        Ok(Face::new(*self.surface(), exterior)
+            .with_interiors(interiors)
+            .with_color(self.color()))
+    }
+}
+
+impl Offset for Sketch {
+    fn offset(
+        self,
+        distance: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Result<Self, OffsetError> {
+        let distance = distance.into();
+        let tolerance = tolerance.into();
+
+        let faces = self
+            .into_faces()
+            .into_iter()
+            .map(|face| face.offset(distance, tolerance))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Sketch::new().with_faces(faces))
+    }
+}
+
+/// Error offsetting a face
+#[derive(Debug, thiserror::Error)]
+pub enum OffsetError {
+    /// The boundary collapses, because the distance is too large
+    #[error("Offset by {0} collapses the boundary")]
+    Collapses(Scalar),
+
+    /// The offset boundary intersects itself
+    #[error("Offset by {0} results in a boundary that intersects itself")]
+    SelfIntersects(Scalar),
+}
+
+struct OffsetLine {
+    /// The point at which the original line segment starts
+    start: Point<2>,
+
+    /// The point at which the original line segment ends
+    end: Point<2>,
+
+    /// The normalized direction of the line segment
+    direction: Vector<2>,
+
+    /// The offset that moves the line segment to its new position
+    offset: Vector<2>,
+}
+
+impl OffsetLine {
+    fn new([start, end]: [Point<2>; 2], distance: Scalar) -> Self {
+        let direction = (end - start).normalize();
+
+        // Rotate the direction clockwise, to get the normal that points to the
+        // right.
+        let normal = Vector::from([direction.v, -direction.u]);
+
+        Self {
+            start,
+            end,
+            direction,
+            offset: normal * distance,
+        }
+    }
+}
+
+enum Corner {
+    /// The lines move apart and are connected by an arc
+    Arc {
+        center: Point<2>,
+        angles: [Scalar; 2],
+        points: [Point<2>; 2],
+    },
+
+    /// The lines move towards each other and are trimmed at this point
+    Trim(Point<2>),
+}
+
+impl Corner {
+    fn new(
+        incoming: &OffsetLine,
+        outgoing: &OffsetLine,
+        distance: Scalar,
+    ) -> Result<Self, OffsetError> {
+        let cross = incoming.direction.cross(&outgoing.direction);
+        let dot = incoming.direction.dot(&outgoing.direction);
+
+        // If lines have been removed, the remaining ones might not meet at one
+        // of the original points. Their corner is then where they would meet,
+        // if extended.
+        let point = if incoming.end == outgoing.start {
+            incoming.end
+        } else if cross == Scalar::ZERO {
+            return Err(OffsetError::Collapses(distance));
+        } else {
+            intersect(
+                [incoming.start, outgoing.start],
+                [incoming.direction, outgoing.direction],
+            )
+        };
+
+        // The signed angle by which the boundary turns at the corner. It's
+        // positive, if the boundary turns left.
+        let turn = cross.atan2(dot);
+
+        if turn * distance > Scalar::ZERO {
+            let points =
+                [incoming.offset, outgoing.offset].map(|offset| point + offset);
+
+            let start_angle = {
+                let radial = points[0] - point;
+                radial.v.atan2(radial.u)
+            };
+
+            return Ok(Self::Arc {
+                center: point,
+                angles: [start_angle, start_angle + turn],
+                points,
+            });
+        }
+
+        if cross == Scalar::ZERO {
+            // The lines are either collinear, in which case they both end up
+            // on the same line, or they run back on themselves and move into
+            // each other.
+            if dot < Scalar::ZERO {
+                return Err(OffsetError::Collapses(distance));
+            }
+
+            return Ok(Self::Trim(point + outgoing.offset));
+        }
+
+        let intersection = intersect(
+            [
+                incoming.start + incoming.offset,
+                outgoing.start + outgoing.offset,
+            ],
+            [incoming.direction, outgoing.direction],
+        );
+
+        Ok(Self::Trim(intersection))
+    }
+
+    /// The point at which the incoming line ends
+    fn start(&self) -> Point<2> {
+        match self {
+            Self::Arc { points, .. } => points[0],
+            Self::Trim(point) => *point,
+        }
+    }
+
+    /// The point at which the outgoing line starts
+    fn end(&self) -> Point<2> {
+        match self {
+            Self::Arc { points, .. } => points[1],
+            Self::Trim(point) => *point,
+        }
+    }
+}
+
+/// Intersect two lines, each given by a point and a direction
+///
+/// The lines must not be parallel.
+fn intersect(
+    [a, b]: [Point<2>; 2],
+    [dir_a, dir_b]: [Vector<2>; 2],
+) -> Point<2> {
+    let t = (b - a).cross(&dir_b) / dir_a.cross(&dir_b);
+    a + dir_a * t
+}
+
+fn segments_from_cycle(
+    cycle: &Cycle,
+    tolerance: Tolerance,
+) -> Vec<[Point<2>; 2]> {
+    let points = cycle
+        .approx(tolerance)
+        .points()
+        .into_iter()
+        .map(|point| point.local_form)
+        .collect::<Vec<_>>();
+
+    points
+        .windows(2)
+        .map(|points| [points[0], points[1]])
+        .collect()
+}
+
+/// Check whether any segment of `a` crosses any segment of `b`
+///
+/// Segments that touch or share end points don't count as crossing, which
+/// means adjacent segments within the same cycle are never reported.
+fn segments_intersect(a: &[[Point<2>; 2]], b: &[[Point<2>; 2]]) -> bool {
+    let side = |[a, b]: [Point<2>; 2], point: Point<2>| {
+        (b - a).cross(&(point - a)).sign().to_scalar()
+    };
+
+    a.iter().any(|&s| {
+        b.iter().any(|&t| {
+            side(s, t[0]) * side(s, t[1]) < Scalar::ZERO
+                && side(t, s[0]) * side(t, s[1]) < Scalar::ZERO
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        algorithms::reverse::Reverse,
+        objects::{Cycle, Face, HalfEdge, Surface},
+        path::SurfacePath,
+    };
+
+    use super::{Offset, OffsetError};
+
+    fn rectangle() -> Face {
+        Face::build(Surface::xy_plane())
+            .polygon_from_points([[0., 0.], [4., 0.], [4., 2.], [0., 2.]])
+            .into_face()
+    }
+
+    #[test]
+    fn offset_rectangle_outwards() -> anyhow::Result<()> {
+        let face = rectangle().offset(0.5, 0.001)?;
+
+        let half_edges = face.exterior().half_edges().collect::<Vec<_>>();
+        assert_eq!(half_edges.len(), 8);
+
+        let mut num_arcs = 0;
+        for half_edge in half_edges {
+            if let SurfacePath::Circle(circle) = half_edge.curve().path() {
+                assert_eq!(circle.radius(), Scalar::from(0.5));
+                num_arcs += 1;
+
+                // The arcs are centered on the original corners.
+                let [u, v] = circle.center().coords.components;
+                assert!(is_near(u, 0.) || is_near(u, 4.));
+                assert!(is_near(v, 0.) || is_near(v, 2.));
+            }
+        }
+        assert_eq!(num_arcs, 4);
+
+        // All vertices lie on the sides of the larger rectangle.
+        for vertex in face.exterior().vertices() {
+            let [u, v] = vertex.surface_form().position().coords.components;
+            let is_on_side = is_near(u, -0.5)
+                || is_near(u, 4.5)
+                || is_near(v, -0.5)
+                || is_near(v, 2.5);
+            assert!(is_on_side);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn offset_rectangle_inwards() -> anyhow::Result<()> {
+        let face = rectangle().offset(-0.5, 0.001)?;
+
+        // Inwards, all corners are trimmed, and the result is a rectangle.
+        assert_eq!(face.exterior().half_edges().count(), 4);
+
+        for vertex in face.exterior().vertices() {
+            let [u, v] = vertex.surface_form().position().coords.components;
+            assert!(is_near(u, 0.5) || is_near(u, 3.5));
+            assert!(is_near(v, 0.5) || is_near(v, 1.5));
+        }
+
+        let result = rectangle().offset(-1.5, 0.001);
+        assert!(matches!(result, Err(OffsetError::Collapses(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn offset_circle() -> anyhow::Result<()> {
+        let surface = Surface::xy_plane();
+        let circle = |radius: f64| {
+            Cycle::new(
+                surface,
+                [HalfEdge::build(surface).circle_from_radius(radius)],
+            )
+        };
+
+        let face = Face::new(surface, circle(2.))
+            .with_interiors([circle(1.).reverse()])
+            .offset(0.5, 0.001)?;
+
+        // Growing the face makes the outer circle larger, and the hole smaller.
+        let radii = face
+            .all_cycles()
+            .flat_map(|cycle| cycle.half_edges())
+            .map(|half_edge| match half_edge.curve().path() {
+                SurfacePath::Circle(circle) => circle.radius(),
+                SurfacePath::Line(_) => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(radii, [Scalar::from(2.5), Scalar::from(0.5)]);
+
+        // If the hole shrinks to nothing, it is removed.
+        let face = Face::new(surface, circle(2.))
+            .with_interiors([circle(1.).reverse()])
+            .offset(1.5, 0.001)?;
+        assert_eq!(face.interiors().count(), 0);
+
+        let result = Face::new(surface, circle(2.)).offset(-2., 0.001);
+        assert!(matches!(result, Err(OffsetError::Collapses(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn offset_self_intersecting() {
+        // A square with a hole close to its right side. Growing the hole
+        // makes it cross the exterior.
+        let face = Face::build(Surface::xy_plane())
+            .polygon_from_points([[0., 0.], [4., 0.], [4., 4.], [0., 4.]])
+            .with_hole([[3., 1.5], [3., 2.5], [3.5, 2.5], [3.5, 1.5]])
+            .into_face();
+
+        let result = face.offset(-1., 0.001);
+        assert!(matches!(result, Err(OffsetError::SelfIntersects(_))));
+    }
+
+    fn is_near(a: Scalar, b: f64) -> bool {
+        (a - b).abs() < Scalar::from(1e-12)
+    }
+}
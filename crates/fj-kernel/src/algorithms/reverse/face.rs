@@ -9,8 +9,37 @@ impl Reverse for Face {
         let exterior = self.exterior().clone().reverse();
         let interiors = self.interiors().map(|cycle| cycle.clone().reverse());
 
-        Face::new(surface, exterior)
+        let face = Face::new(surface, exterior)
             .with_interiors(interiors)
-            .with_color(self.color())
+            .with_color(self.color());
+
+        match self.sweep_path() {
+            Some(path) => face.with_sweep_path(path),
+            None => face,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algorithms::reverse::Reverse,
+        objects::{Face, Surface},
+    };
+
+    #[test]
+    fn reverse_face() {
+        let surface = Surface::xy_plane();
+        let face = Face::build(surface)
+            .polygon_from_points([[0., 0.], [2., 0.], [2., 1.], [0., 1.]])
+            .with_hole([[0.5, 0.25], [0.5, 0.75], [1.5, 0.75], [1.5, 0.25]])
+            .into_face();
+
+        let reversed = face.clone().reverse();
+
+        let point = [1., 0.5];
+        assert_eq!(reversed.normal_at(point), -face.normal_at(point));
+
+        assert_eq!(reversed.reverse(), face);
     }
 }
@@ -3,6 +3,7 @@
 mod cycle;
 mod edge;
 mod face;
+mod shell;
 
 /// Reverse the direction/orientation of an object
 pub trait Reverse {
@@ -0,0 +1,32 @@
+use crate::objects::Shell;
+
+use super::Reverse;
+
+impl Reverse for Shell {
+    fn reverse(self) -> Self {
+        let faces = self.into_faces().into_iter().map(Reverse::reverse);
+        Shell::new().with_faces(faces)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{algorithms::reverse::Reverse, objects::Shell};
+
+    #[test]
+    fn reverse_shell() {
+        let shell = Shell::build().cube_from_edge_length(1.);
+        let reversed = shell.clone().reverse();
+
+        assert_eq!(
+            reversed.faces().into_iter().count(),
+            shell.faces().into_iter().count()
+        );
+        for face in shell.faces() {
+            let reversed_face = face.clone().reverse();
+            assert!(reversed.find_face(&reversed_face).is_some());
+        }
+
+        assert_eq!(reversed.reverse(), shell);
+    }
+}
@@ -11,6 +11,15 @@ use crate::{
 
 use super::Sweep;
 
+/// Sweep a single half-edge into a face
+///
+/// The half-edge doesn't need to be part of a closed cycle. This makes it
+/// possible to create surfaces without creating a closed solid, for example a
+/// curved panel from a single arc, by sweeping a half-edge whose curve is a
+/// circle, but whose vertices don't span the full circle.
+///
+/// The resulting face is single-sided. To approximate or export it, wrap it
+/// in an (open) [`Shell`](crate::objects::Shell).
 impl Sweep for (HalfEdge, Color) {
     type Swept = Face;
 
@@ -164,18 +173,23 @@ impl Sweep for (HalfEdge, Color) {
             Cycle::new(surface, edges)
         };
 
-        Face::new(surface, cycle).with_color(color)
+        Face::new(surface, cycle)
+            .with_color(color)
+            .with_sweep_path(path)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use fj_interop::mesh::Color;
+    use fj_math::{Point, Scalar};
     use pretty_assertions::assert_eq;
 
     use crate::{
-        algorithms::{reverse::Reverse, sweep::Sweep},
-        objects::{Cycle, Face, HalfEdge, Surface},
+        algorithms::{
+            reverse::Reverse, sweep::Sweep, triangulate::Triangulate,
+        },
+        objects::{Curve, Cycle, Face, HalfEdge, Shell, Surface, Vertex},
     };
 
     #[test]
@@ -200,9 +214,34 @@ mod tests {
 
             let cycle = Cycle::new(surface, [bottom, right, top, left]);
 
-            Face::new(surface, cycle)
+            Face::new(surface, cycle).with_sweep_path([0., 0., 1.])
         };
 
         assert_eq!(face, expected_face);
     }
+
+    #[test]
+    fn sweep_arc() {
+        let surface = Surface::xy_plane();
+        let curve = Curve::build(surface).circle_from_radius(1.);
+
+        // Half a circle, from the positive x-axis to the negative one.
+        let vertices =
+            [Scalar::ZERO, Scalar::PI].map(|t| Vertex::on_curve(curve, [t]));
+        let half_edge = HalfEdge::from_curve_and_vertices(curve, vertices);
+
+        let face = (half_edge, Color::default()).sweep([0., 0., 1.]);
+        let panel = Shell::new().with_faces([face]);
+
+        let mesh = (&panel).triangulate(0.01);
+        assert!(mesh.triangles().next().is_some());
+
+        for point in mesh.vertices() {
+            let radius = Point::from([point.x, point.y]).coords.magnitude();
+
+            assert!((radius - Scalar::ONE).abs() < Scalar::from(1e-12));
+            assert!(point.y >= Scalar::ZERO);
+            assert!(point.z >= Scalar::ZERO && point.z <= Scalar::ONE);
+        }
+    }
 }
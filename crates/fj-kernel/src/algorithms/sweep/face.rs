@@ -157,4 +157,24 @@ mod tests {
 
         assert!(side_faces.all(|face| solid.find_face(&face).is_some()));
     }
+
+    #[test]
+    fn sweep_face_with_hole() {
+        let face = Face::build(Surface::xy_plane())
+            .polygon_from_points([[0., 0.], [3., 0.], [3., 2.], [0., 2.]])
+            .with_hole([[1., 0.5], [1., 1.5], [2., 1.5], [2., 0.5]])
+            .into_face();
+        let shell = face.sweep(UP);
+
+        // Top and bottom, and one side per edge of the exterior and the hole.
+        assert_eq!(shell.faces().into_iter().count(), 2 + 4 + 4);
+
+        // The shell is closed, which means every edge is shared by exactly two
+        // faces.
+        assert!(shell.is_closed());
+
+        // All faces, including the walls of the hole, are oriented outward.
+        let (_, num_flipped) = shell.fix_orientations();
+        assert_eq!(num_flipped, 0);
+    }
 }
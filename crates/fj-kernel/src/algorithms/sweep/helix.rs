@@ -0,0 +1,227 @@
+use fj_math::{Point, Scalar, Transform, Vector};
+
+use crate::{
+    algorithms::{
+        approx::{path::num_segments_for_circle, Tolerance},
+        reverse::Reverse,
+        transform::TransformObject,
+    },
+    builder::triangle_from_points,
+    objects::{Face, Shell, Sketch, Solid},
+};
+
+use super::{approx_face, SweepHelical};
+
+impl SweepHelical for Face {
+    type Swept = Shell;
+
+    fn sweep_helical(
+        self,
+        origin: impl Into<Point<3>>,
+        axis: impl Into<Vector<3>>,
+        pitch: impl Into<Scalar>,
+        turns: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Self::Swept {
+        let origin = origin.into();
+        let axis = axis.into().normalize();
+        let pitch = pitch.into();
+        let turns = turns.into();
+        let tolerance = tolerance.into();
+
+        assert!(
+            turns > Scalar::ZERO,
+            "Helical sweep requires a positive number of turns"
+        );
+
+        // The sides are made up of triangles, which can only connect to
+        // straight edges. Replace the face with its approximation, so the
+        // start and end faces connect to the sides.
+        let face = approx_face(self, tolerance);
+
+        let distance_from_axis =
+            |point: Point<3>| (point - origin).cross(&axis).magnitude();
+
+        let (farthest_in_surface, farthest_from_axis) = face
+            .all_cycles()
+            .flat_map(|cycle| cycle.vertices())
+            .map(|vertex| {
+                (
+                    vertex.surface_form().position(),
+                    vertex.global_form().position(),
+                )
+            })
+            .max_by_key(|&(_, point)| distance_from_axis(point))
+            .expect("Face has no vertices");
+
+        let is_negative_sweep = {
+            let normal = face.surface().normal_at(farthest_in_surface);
+
+            // The direction into which the face starts to move, per radian of
+            // rotation.
+            let motion = axis.cross(&(farthest_from_axis - origin))
+                + axis * pitch / Scalar::TAU;
+
+            normal.dot(&motion) < Scalar::ZERO
+        };
+
+        // The helix traced by the point farthest from the axis deviates the
+        // most from its approximation. Along the axis, the helix is straight,
+        // so the deviation is the same as that of a circle with the same
+        // radius.
+        let num_steps = {
+            let radius = distance_from_axis(farthest_from_axis);
+            let steps_per_turn = if radius > Scalar::ZERO {
+                num_segments_for_circle(radius, tolerance)
+            } else {
+                3
+            };
+
+            let num_steps =
+                (turns * Scalar::from_f64(steps_per_turn as f64)).ceil();
+            (num_steps.into_f64() as usize).max(1)
+        };
+
+        // The transforms that move the face to each step of the sweep. The
+        // first step is the face itself.
+        let transforms = (1..=num_steps)
+            .map(|step| {
+                let fraction = Scalar::from_f64(step as f64 / num_steps as f64);
+                let angle = Scalar::TAU * turns * fraction;
+
+                let offset = axis * pitch * turns * fraction;
+
+                Transform::translation(origin.coords + offset)
+                    * Transform::rotation(axis * angle)
+                    * Transform::translation(-origin.coords)
+            })
+            .collect::<Vec<_>>();
+        let point_at_step = |point: Point<3>, step: usize| {
+            if step == 0 {
+                point
+            } else {
+                transforms[step - 1].transform_point(&point)
+            }
+        };
+
+        let mut faces = Vec::new();
+
+        let start_face = if is_negative_sweep {
+            face.clone()
+        } else {
+            face.clone().reverse()
+        };
+        faces.push(start_face);
+
+        let end_face = {
+            let end_face = face.clone().transform(&transforms[num_steps - 1]);
+            if is_negative_sweep {
+                end_face.reverse()
+            } else {
+                end_face
+            }
+        };
+        faces.push(end_face);
+
+        for cycle in face.all_cycles() {
+            for &half_edge in cycle.half_edges() {
+                let edge = if is_negative_sweep {
+                    half_edge.reverse()
+                } else {
+                    half_edge
+                };
+
+                // The side between two steps is part of a helicoid, which is
+                // not planar. Approximate it using two triangles.
+                let [a, b] = edge
+                    .vertices()
+                    .map(|vertex| vertex.global_form().position());
+                for step in 0..num_steps {
+                    let [a0, b0, a1, b1] = [
+                        point_at_step(a, step),
+                        point_at_step(b, step),
+                        point_at_step(a, step + 1),
+                        point_at_step(b, step + 1),
+                    ];
+
+                    faces
+                        .push(triangle_from_points([a0, b0, b1], face.color()));
+                    faces
+                        .push(triangle_from_points([a0, b1, a1], face.color()));
+                }
+            }
+        }
+
+        Shell::new().with_faces(faces)
+    }
+}
+
+impl SweepHelical for Sketch {
+    type Swept = Solid;
+
+    fn sweep_helical(
+        self,
+        origin: impl Into<Point<3>>,
+        axis: impl Into<Vector<3>>,
+        pitch: impl Into<Scalar>,
+        turns: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Self::Swept {
+        let origin = origin.into();
+        let axis = axis.into();
+        let pitch = pitch.into();
+        let turns = turns.into();
+        let tolerance = tolerance.into();
+
+        let mut shells = Vec::new();
+        for face in self.into_faces() {
+            let shell =
+                face.sweep_helical(origin, axis, pitch, turns, tolerance);
+            shells.push(shell);
+        }
+
+        Solid::new().with_shells(shells)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        algorithms::{sweep::SweepHelical, transform::TransformObject},
+        objects::{Cycle, Face, HalfEdge, Surface},
+    };
+
+    #[test]
+    fn single_turn_of_circle() {
+        // A small circle next to the z-axis, which results in a spring.
+        let surface = Surface::xz_plane().translate([1., 0., 0.]);
+        let circle = Face::new(
+            surface,
+            Cycle::new(
+                surface,
+                [HalfEdge::build(surface).circle_from_radius(0.2)],
+            ),
+        );
+
+        let shell =
+            circle.sweep_helical([0., 0., 0.], [0., 0., 1.], 1., 1., 0.01);
+
+        assert!(shell.is_closed());
+
+        let (_, num_flipped) = shell.clone().fix_orientations();
+        assert_eq!(num_flipped, 0);
+
+        // The end of the spring is one pitch above its start. The circle is
+        // approximated, so its top may be slightly lower.
+        let max_z = shell
+            .faces()
+            .into_iter()
+            .flat_map(|face| face.exterior().vertices())
+            .map(|vertex| vertex.global_form().position().z)
+            .max()
+            .expect("Shell has no vertices");
+        assert!((max_z - Scalar::from(1.2)).abs() <= Scalar::from(0.01));
+    }
+}
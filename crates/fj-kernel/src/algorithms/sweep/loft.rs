@@ -0,0 +1,282 @@
+use fj_math::{Point, Scalar};
+
+use crate::{
+    algorithms::{approx::Tolerance, reverse::Reverse},
+    builder::triangle_from_points,
+    objects::{Cycle, Face, Shell},
+};
+
+use super::{approx_face, Loft};
+
+impl Loft for [Face; 2] {
+    type Lofted = Shell;
+
+    fn loft(self, tolerance: impl Into<Tolerance>) -> Self::Lofted {
+        let tolerance = tolerance.into();
+        let [bottom, top] = self.map(|face| approx_face(face, tolerance));
+
+        assert_eq!(
+            bottom.all_cycles().count(),
+            top.all_cycles().count(),
+            "Lofting requires profiles with the same number of cycles"
+        );
+
+        let num_vertices = |face: &Face| {
+            face.all_cycles()
+                .map(|cycle| cycle.vertices().count())
+                .collect::<Vec<_>>()
+        };
+        let targets = num_vertices(&bottom)
+            .into_iter()
+            .zip(num_vertices(&top))
+            .map(|(a, b)| a.max(b))
+            .collect::<Vec<_>>();
+
+        let [bottom, top] =
+            [bottom, top].map(|face| subdivide_edges(face, &targets));
+
+        loft_polygons(bottom, top)
+    }
+}
+
+/// Loft between two profiles that are bounded by straight edges
+///
+/// Corresponding cycles of the profiles must have the same number of vertices.
+/// See [`Loft::loft`].
+pub(super) fn loft_polygons(bottom: Face, top: Face) -> Shell {
+    assert_eq!(
+        bottom.all_cycles().count(),
+        top.all_cycles().count(),
+        "Lofting requires profiles with the same number of cycles"
+    );
+
+    let is_negative_loft = {
+        let first_vertex = bottom
+            .exterior()
+            .vertices()
+            .next()
+            .expect("Profile has no vertices");
+        let normal = bottom
+            .surface()
+            .normal_at(first_vertex.surface_form().position());
+
+        // The direction from the first profile to the second.
+        let direction = centroid(top.exterior()) - centroid(bottom.exterior());
+
+        let alignment = normal.dot(&direction);
+        assert!(
+            alignment != Scalar::ZERO,
+            "Lofting requires profiles that don't lie in the same plane"
+        );
+
+        alignment < Scalar::ZERO
+    };
+
+    let mut faces = Vec::new();
+
+    let (bottom_face, top_face) = if is_negative_loft {
+        (bottom.clone(), top.clone().reverse())
+    } else {
+        (bottom.clone().reverse(), top.clone())
+    };
+    faces.push(bottom_face);
+    faces.push(top_face);
+
+    for (bottom_cycle, top_cycle) in bottom.all_cycles().zip(top.all_cycles()) {
+        let [bottom_points, top_points] =
+            [bottom_cycle, top_cycle].map(|cycle| {
+                let mut points = cycle
+                    .vertices()
+                    .map(|vertex| vertex.global_form().position())
+                    .collect::<Vec<_>>();
+
+                // The sides are built from the edges of the cycles. Those
+                // need to be reversed, if the loft is negative, to make
+                // the sides face outwards.
+                if is_negative_loft {
+                    points.reverse();
+                    points.rotate_right(1);
+                }
+
+                points
+            });
+
+        assert_eq!(
+            bottom_points.len(),
+            top_points.len(),
+            "Lofting requires cycles with the same number of vertices"
+        );
+
+        let num_points = bottom_points.len();
+        for i in 0..num_points {
+            let j = (i + 1) % num_points;
+
+            let [a0, b0, a1, b1] = [
+                bottom_points[i],
+                bottom_points[j],
+                top_points[i],
+                top_points[j],
+            ];
+
+            // Where the profiles share a vertex, one of the triangles
+            // degenerates, and only the other one is kept.
+            if b0 != b1 {
+                faces.push(triangle_from_points([a0, b0, b1], bottom.color()));
+            }
+            if a0 != a1 {
+                faces.push(triangle_from_points([a0, b1, a1], bottom.color()));
+            }
+        }
+    }
+
+    Shell::new().with_faces(faces)
+}
+
+/// Split the longest edges of a face, until its cycles have enough vertices
+///
+/// `targets` contains the number of vertices for each cycle of the face, in
+/// the order of [`Face::all_cycles`]. Cycles that already have that many
+/// vertices are kept as they are. Edges are split at their middle, in surface
+/// coordinates.
+fn subdivide_edges(face: Face, targets: &[usize]) -> Face {
+    let needs_subdivision = face
+        .all_cycles()
+        .zip(targets)
+        .any(|(cycle, &target)| cycle.vertices().count() < target);
+    if !needs_subdivision {
+        return face;
+    }
+
+    let surface = *face.surface();
+    let mut cycles = face.all_cycles().zip(targets).map(|(cycle, &target)| {
+        let mut points = cycle
+            .vertices()
+            .map(|vertex| vertex.surface_form().position())
+            .collect::<Vec<_>>();
+        if points.len() >= target {
+            return cycle.clone();
+        }
+
+        while points.len() < target {
+            let num_points = points.len();
+            let longest = (0..num_points)
+                .max_by_key(|&i| {
+                    (points[(i + 1) % num_points] - points[i]).magnitude()
+                })
+                .expect("Cycle has no vertices");
+
+            let [a, b] = [points[longest], points[(longest + 1) % num_points]];
+            points.insert(longest + 1, a + (b - a) / Scalar::TWO);
+        }
+
+        Cycle::build(surface).polygon_from_points(points)
+    });
+
+    let exterior = cycles.next().expect("Face has no exterior");
+    Face::new(surface, exterior)
+        .with_interiors(cycles.collect::<Vec<_>>())
+        .with_color(face.color())
+}
+
+fn centroid(cycle: &Cycle) -> Point<3> {
+    let points = cycle
+        .vertices()
+        .map(|vertex| vertex.global_form().position())
+        .collect::<Vec<_>>();
+
+    let sum = points
+        .iter()
+        .fold(Point::origin().coords, |sum, point| sum + point.coords);
+    Point {
+        coords: sum / Scalar::from_f64(points.len() as f64),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Transform;
+
+    use crate::{
+        algorithms::{sweep::Loft, transform::TransformObject},
+        objects::{Cycle, Face, HalfEdge, Surface},
+    };
+
+    #[test]
+    fn loft_between_offset_rectangles() {
+        let bottom = Face::build(Surface::xy_plane())
+            .polygon_from_points([[0., 0.], [2., 0.], [2., 1.], [0., 1.]])
+            .into_face();
+        let top = Face::build(Surface::xy_plane())
+            .polygon_from_points([[0., 0.], [1., 0.], [1., 1.], [0., 1.]])
+            .into_face()
+            .transform(&Transform::translation([0.5, 0.5, 1.]));
+
+        let shell = [bottom.clone(), top.clone()].loft(0.01);
+
+        // Both profiles, and two triangles per side.
+        assert_eq!(shell.faces().into_iter().count(), 2 + 4 * 2);
+        assert!(shell.is_closed());
+
+        let (_, num_flipped) = shell.fix_orientations();
+        assert_eq!(num_flipped, 0);
+
+        // Lofting in the other direction results in a valid shell too.
+        let shell = [top, bottom].loft(0.01);
+        assert!(shell.is_closed());
+
+        let (_, num_flipped) = shell.fix_orientations();
+        assert_eq!(num_flipped, 0);
+    }
+
+    #[test]
+    fn loft_with_different_vertex_counts() {
+        let bottom = Face::build(Surface::xy_plane())
+            .polygon_from_points([[0., 0.], [1., 0.], [1., 1.], [0., 1.]])
+            .into_face();
+        let top = Face::build(Surface::xy_plane())
+            .polygon_from_points([[0., 0.], [1., 0.], [0., 1.]])
+            .into_face()
+            .transform(&Transform::translation([0., 0., 1.]));
+
+        let shell = [bottom, top].loft(0.01);
+
+        // The longest edge of the triangle is split, to match the square.
+        assert_eq!(shell.faces().into_iter().count(), 2 + 4 * 2);
+        assert!(shell.is_closed());
+
+        let (_, num_flipped) = shell.fix_orientations();
+        assert_eq!(num_flipped, 0);
+    }
+
+    #[test]
+    fn loft_from_square_to_circle() {
+        let bottom = Face::build(Surface::xy_plane())
+            .polygon_from_points([[1., -1.], [1., 1.], [-1., 1.], [-1., -1.]])
+            .into_face();
+
+        let surface = Surface::xy_plane();
+        let top = Face::new(
+            surface,
+            Cycle::new(
+                surface,
+                [HalfEdge::build(surface).circle_from_radius(1.)],
+            ),
+        )
+        .transform(&Transform::translation([0., 0., 1.]));
+
+        let shell = [bottom, top].loft(0.01);
+
+        // The circle is approximated, and the square is subdivided to match,
+        // so all sides are triangles.
+        let sides = shell
+            .faces()
+            .into_iter()
+            .filter(|face| face.exterior().half_edges().count() == 3)
+            .count();
+        assert_eq!(shell.faces().into_iter().count(), 2 + sides);
+        assert!(shell.is_closed());
+
+        let (_, num_flipped) = shell.fix_orientations();
+        assert_eq!(num_flipped, 0);
+    }
+}
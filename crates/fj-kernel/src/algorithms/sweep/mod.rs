@@ -3,10 +3,24 @@
 mod curve;
 mod edge;
 mod face;
+mod helix;
+mod loft;
+mod revolve;
 mod sketch;
+mod taper;
+mod twist;
 mod vertex;
 
-use fj_math::Vector;
+use fj_math::{Point, Scalar, Vector};
+
+use crate::{
+    objects::{Cycle, Face},
+    path::SurfacePath,
+};
+
+use super::approx::{Approx, Tolerance};
+
+pub use self::taper::TaperError;
 
 /// Sweep an object along a path to create another object
 pub trait Sweep {
@@ -16,3 +30,195 @@ pub trait Sweep {
     /// Sweep the object along the given path
     fn sweep(self, path: impl Into<Vector<3>>) -> Self::Swept;
 }
+
+/// Sweep an object along a path, while rotating it around that path
+pub trait SweepWithTwist {
+    /// The object that is created by sweeping the implementing object
+    type Swept;
+
+    /// Sweep the object along the given path, while rotating it
+    ///
+    /// The object is rotated around an axis that points in the direction of
+    /// `path` and goes through the origin. `twist` is the total angle of that
+    /// rotation over the length of the sweep, in radians. It is not limited to
+    /// a single turn.
+    ///
+    /// The sides of the swept object are twisted, and are approximated using
+    /// `num_steps` segments along the path. Circular edges of the object are
+    /// approximated according to `tolerance` before it is swept.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `num_steps` is zero.
+    fn sweep_with_twist(
+        self,
+        path: impl Into<Vector<3>>,
+        twist: impl Into<Scalar>,
+        num_steps: usize,
+        tolerance: impl Into<Tolerance>,
+    ) -> Self::Swept;
+}
+
+/// Sweep an object along a path, while tapering it with a draft angle
+pub trait SweepWithTaper {
+    /// The object that is created by sweeping the implementing object
+    type Swept;
+
+    /// Sweep the object along the given path, while tapering it
+    ///
+    /// The end of the sweep is a copy of the original object, that is scaled
+    /// uniformly about the centroid of its exterior. The scale factor is
+    /// chosen such that the side closest to the centroid is inclined against
+    /// `path` by `draft_angle`, given in radians. For regular polygons, this
+    /// means all sides have the same inclination. Positive angles make the
+    /// end smaller, negative angles make it larger. Holes are scaled along
+    /// with the rest of the object.
+    ///
+    /// The sides of the tapered object are ruled surfaces, which are
+    /// approximated using triangles.
+    ///
+    /// Returns an error, if the absolute value of `draft_angle` is not smaller
+    /// than a right angle, if the object is bounded by curved edges, or if the
+    /// taper is so steep that the end would collapse to zero area (or beyond,
+    /// turning it inside out).
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `path` has zero length.
+    fn sweep_with_taper(
+        self,
+        path: impl Into<Vector<3>>,
+        draft_angle: impl Into<Scalar>,
+    ) -> Result<Self::Swept, TaperError>;
+}
+
+/// Sweep an object along a helix
+pub trait SweepHelical {
+    /// The object that is created by sweeping the implementing object
+    type Swept;
+
+    /// Sweep the object along a helix
+    ///
+    /// The helix winds around an axis that goes through `origin` and points in
+    /// the direction of `axis`, following the right-hand rule. With each turn,
+    /// the object advances by `pitch` in the direction of `axis`. A negative
+    /// `pitch` results in a left-handed helix. `turns` is not limited to whole
+    /// numbers.
+    ///
+    /// The object is approximated, according to `tolerance`, before it is
+    /// swept. The sides of the swept object are helical, and are approximated
+    /// using triangles. The number of steps along the helix is chosen such
+    /// that the approximation doesn't deviate from the helix by more than
+    /// `tolerance`, just like the approximation of a circle.
+    ///
+    /// If `pitch` is too small for the size of the object, successive turns
+    /// intersect each other. This is not detected.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `turns` is not positive.
+    fn sweep_helical(
+        self,
+        origin: impl Into<Point<3>>,
+        axis: impl Into<Vector<3>>,
+        pitch: impl Into<Scalar>,
+        turns: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Self::Swept;
+}
+
+/// Loft between two profiles to create another object
+pub trait Loft {
+    /// The object that is created by lofting between the profiles
+    type Lofted;
+
+    /// Loft between the profiles
+    ///
+    /// The profiles are connected by ruled sides. Vertices of the profiles are
+    /// paired by their position in the cycles that bound the profiles: The
+    /// first vertex of a cycle in the first profile is connected to the first
+    /// vertex of the corresponding cycle in the second profile, and so on. The
+    /// resulting sides are approximated using triangles.
+    ///
+    /// Both profiles are expected to have the same orientation, and cycles that
+    /// start at vertices that are meant to be connected. Otherwise, the sides
+    /// are twisted.
+    ///
+    /// Circular edges of the profiles are approximated according to
+    /// `tolerance`. If corresponding cycles don't have the same number of
+    /// vertices, the longest edges of the cycle with fewer vertices are split,
+    /// until the numbers match. This makes it possible to loft between
+    /// profiles of different shapes, like a square and a circle.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the profiles don't have the same number of cycles.
+    fn loft(self, tolerance: impl Into<Tolerance>) -> Self::Lofted;
+}
+
+/// Revolve an object around an axis to create another object
+pub trait Revolve {
+    /// The object that is created by revolving the implementing object
+    type Revolved;
+
+    /// Revolve the object around an axis
+    ///
+    /// The axis goes through `origin` and points in the direction of `axis`.
+    /// `angle` is the angle of the rotation in radians, following the
+    /// right-hand rule around `axis`. Angles with a magnitude of a full turn or
+    /// more result in a full revolution.
+    ///
+    /// The sides of the revolved object are curved, and are approximated using
+    /// `num_steps` segments along the rotation. Circular edges of the object
+    /// are approximated according to `tolerance` before it is revolved.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `num_steps` is zero, or if it's less than three for a full
+    /// revolution.
+    fn revolve(
+        self,
+        origin: impl Into<Point<3>>,
+        axis: impl Into<Vector<3>>,
+        angle: impl Into<Scalar>,
+        num_steps: usize,
+        tolerance: impl Into<Tolerance>,
+    ) -> Self::Revolved;
+}
+
+/// Replace the circular edges of a face with their approximation
+///
+/// Sweeps that build their sides from triangles can only connect those to
+/// straight edges. Faces that are only bounded by straight edges are returned
+/// as they are.
+fn approx_face(face: Face, tolerance: Tolerance) -> Face {
+    let is_bounded_by_circle = face
+        .all_cycles()
+        .flat_map(|cycle| cycle.half_edges())
+        .any(|half_edge| {
+            matches!(half_edge.curve().path(), SurfacePath::Circle(_))
+        });
+    if !is_bounded_by_circle {
+        return face;
+    }
+
+    let surface = *face.surface();
+    let polygon = |cycle: &Cycle| {
+        let mut points = cycle
+            .approx(tolerance)
+            .points()
+            .into_iter()
+            .map(|point| point.local_form)
+            .collect::<Vec<_>>();
+
+        // The approximation of a cycle repeats its first point at the end, to
+        // close it.
+        points.pop();
+
+        Cycle::build(surface).polygon_from_points(points)
+    };
+
+    Face::new(surface, polygon(face.exterior()))
+        .with_interiors(face.interiors().map(polygon))
+        .with_color(face.color())
+}
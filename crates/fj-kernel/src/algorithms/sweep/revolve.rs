@@ -0,0 +1,297 @@
+use fj_math::{Point, Scalar, Transform, Vector};
+
+use crate::{
+    algorithms::{
+        approx::Tolerance, reverse::Reverse, transform::TransformObject,
+    },
+    builder::triangle_from_points,
+    objects::{Face, Shell, Sketch, Solid},
+};
+
+use super::{approx_face, Revolve};
+
+impl Revolve for Face {
+    type Revolved = Shell;
+
+    fn revolve(
+        self,
+        origin: impl Into<Point<3>>,
+        axis: impl Into<Vector<3>>,
+        angle: impl Into<Scalar>,
+        num_steps: usize,
+        tolerance: impl Into<Tolerance>,
+    ) -> Self::Revolved {
+        let origin = origin.into();
+        let axis = axis.into().normalize();
+        let angle = angle.into();
+
+        let face = approx_face(self, tolerance.into());
+
+        let is_full_turn = angle.abs() >= Scalar::TAU;
+        let angle = if is_full_turn {
+            Scalar::TAU * angle.sign().to_scalar()
+        } else {
+            angle
+        };
+
+        if is_full_turn {
+            assert!(
+                num_steps >= 3,
+                "Full revolution requires at least three steps"
+            );
+        } else {
+            assert!(num_steps > 0, "Revolution requires at least one step");
+        }
+
+        let distance_from_axis =
+            |point: Point<3>| (point - origin).cross(&axis).magnitude();
+
+        let (farthest_in_surface, farthest_from_axis) = face
+            .all_cycles()
+            .flat_map(|cycle| cycle.vertices())
+            .map(|vertex| {
+                (
+                    vertex.surface_form().position(),
+                    vertex.global_form().position(),
+                )
+            })
+            .max_by_key(|&(_, point)| distance_from_axis(point))
+            .expect("Face has no vertices");
+
+        // Points that are this close to the axis are considered to lie on it.
+        // They are not moved by the rotation, which makes sure that the sides
+        // degenerate to a point there, instead of to a tiny sliver.
+        let on_axis_threshold =
+            distance_from_axis(farthest_from_axis) * Scalar::from_f64(1e-9);
+        let is_on_axis =
+            |point: Point<3>| distance_from_axis(point) <= on_axis_threshold;
+
+        let is_negative_revolve = {
+            let normal = face.surface().normal_at(farthest_in_surface);
+
+            // The direction into which the face starts to move, when the
+            // rotation begins.
+            let motion = axis.cross(&(farthest_from_axis - origin))
+                * angle.sign().to_scalar();
+
+            normal.dot(&motion) < Scalar::ZERO
+        };
+
+        // The transforms that move the original face to each step of the
+        // revolution. The first step is the original face itself.
+        let transforms = (1..=num_steps)
+            .map(|step| {
+                let fraction = Scalar::from_f64(step as f64 / num_steps as f64);
+                Transform::translation(origin.coords)
+                    * Transform::rotation(axis * angle * fraction)
+                    * Transform::translation(-origin.coords)
+            })
+            .collect::<Vec<_>>();
+        let point_at_step = |point: Point<3>, step: usize| {
+            // A full revolution ends where it started. Use the original point
+            // there, so the resulting shell is closed exactly.
+            let is_start = step == 0 || (is_full_turn && step == num_steps);
+
+            if is_start || is_on_axis(point) {
+                point
+            } else {
+                transforms[step - 1].transform_point(&point)
+            }
+        };
+
+        let mut faces = Vec::new();
+
+        // The start and end faces close off a partial revolution. A full
+        // revolution is closed without them.
+        if !is_full_turn {
+            let start_face = if is_negative_revolve {
+                face.clone()
+            } else {
+                face.clone().reverse()
+            };
+            faces.push(start_face);
+
+            let end_face = {
+                let end = face.clone().transform(&transforms[num_steps - 1]);
+                if is_negative_revolve {
+                    end.reverse()
+                } else {
+                    end
+                }
+            };
+            faces.push(end_face);
+        }
+
+        for cycle in face.all_cycles() {
+            for &half_edge in cycle.half_edges() {
+                let edge = if is_negative_revolve {
+                    half_edge.reverse()
+                } else {
+                    half_edge
+                };
+
+                let [a, b] = edge
+                    .vertices()
+                    .map(|vertex| vertex.global_form().position());
+
+                // An edge that lies on the axis doesn't sweep out any area.
+                if is_on_axis(a) && is_on_axis(b) {
+                    continue;
+                }
+
+                // The side between two steps is part of a cone, cylinder, or
+                // disk, which can't be represented exactly. Approximate it
+                // using two triangles. Where the edge touches the axis, one of
+                // them degenerates, and only the other one is kept.
+                for step in 0..num_steps {
+                    let [a0, b0, a1, b1] = [
+                        point_at_step(a, step),
+                        point_at_step(b, step),
+                        point_at_step(a, step + 1),
+                        point_at_step(b, step + 1),
+                    ];
+
+                    if b0 != b1 {
+                        faces.push(triangle_from_points(
+                            [a0, b0, b1],
+                            face.color(),
+                        ));
+                    }
+                    if a0 != a1 {
+                        faces.push(triangle_from_points(
+                            [a0, b1, a1],
+                            face.color(),
+                        ));
+                    }
+                }
+            }
+        }
+
+        Shell::new().with_faces(faces)
+    }
+}
+
+impl Revolve for Sketch {
+    type Revolved = Solid;
+
+    fn revolve(
+        self,
+        origin: impl Into<Point<3>>,
+        axis: impl Into<Vector<3>>,
+        angle: impl Into<Scalar>,
+        num_steps: usize,
+        tolerance: impl Into<Tolerance>,
+    ) -> Self::Revolved {
+        let origin = origin.into();
+        let axis = axis.into();
+        let angle = angle.into();
+        let tolerance = tolerance.into();
+
+        let mut shells = Vec::new();
+        for face in self.into_faces() {
+            let shell = face.revolve(origin, axis, angle, num_steps, tolerance);
+            shells.push(shell);
+        }
+
+        Solid::new().with_shells(shells)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        algorithms::sweep::Revolve,
+        objects::{Cycle, Face, HalfEdge, Surface},
+    };
+
+    #[test]
+    fn full_revolution() {
+        let num_steps = 8;
+
+        // A rectangle next to the z-axis, which results in a tube.
+        let shell = Face::build(Surface::xz_plane())
+            .polygon_from_points([[1., 0.], [2., 0.], [2., 1.], [1., 1.]])
+            .into_face()
+            .revolve([0., 0., 0.], [0., 0., 1.], Scalar::TAU, num_steps, 0.01);
+
+        // No start and end faces, and two triangles per edge and step.
+        assert_eq!(shell.faces().into_iter().count(), 4 * num_steps * 2);
+        assert!(shell.is_closed());
+
+        let (_, num_flipped) = shell.clone().fix_orientations();
+        assert_eq!(num_flipped, 0);
+
+        // The inside of the tube is preserved.
+        assert!(shell.faces().into_iter().all(|face| {
+            face.exterior().vertices().all(|vertex| {
+                let [x, y, _] =
+                    vertex.global_form().position().coords.components;
+                (x * x + y * y).into_f64().sqrt() > 0.9
+            })
+        }));
+    }
+
+    #[test]
+    fn partial_revolution_touching_axis() {
+        let num_steps = 4;
+
+        // A triangle with one edge on the z-axis, which results in a quarter
+        // of a cone.
+        let shell = Face::build(Surface::xz_plane())
+            .polygon_from_points([[0., 0.], [1., 0.], [0., 1.]])
+            .into_face()
+            .revolve(
+                [0., 0., 0.],
+                [0., 0., 1.],
+                Scalar::PI / 2.,
+                num_steps,
+                0.01,
+            );
+
+        // Start and end faces, and one triangle per step for the two edges
+        // that only touch the axis.
+        assert_eq!(shell.faces().into_iter().count(), 2 + 2 * num_steps);
+        assert!(shell.is_closed());
+
+        let (_, num_flipped) = shell.fix_orientations();
+        assert_eq!(num_flipped, 0);
+    }
+
+    #[test]
+    fn full_revolution_of_circle() {
+        let num_steps = 8;
+
+        // A circle next to an axis parallel to the z-axis, which results in a
+        // torus.
+        let surface = Surface::xz_plane();
+        let circle = Face::new(
+            surface,
+            Cycle::new(
+                surface,
+                [HalfEdge::build(surface).circle_from_radius(1.)],
+            ),
+        );
+        let shell = circle.revolve(
+            [3., 0., 0.],
+            [0., 0., 1.],
+            Scalar::TAU,
+            num_steps,
+            0.01,
+        );
+
+        // The circle is approximated before revolving, so all sides are
+        // triangles.
+        assert!(shell.faces().into_iter().all(|face| face
+            .exterior()
+            .half_edges()
+            .count()
+            == 3));
+        assert_eq!(shell.faces().into_iter().count() % (num_steps * 2), 0);
+        assert!(shell.is_closed());
+
+        let (_, num_flipped) = shell.fix_orientations();
+        assert_eq!(num_flipped, 0);
+    }
+}
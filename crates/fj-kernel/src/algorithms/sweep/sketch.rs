@@ -16,6 +16,6 @@ impl Sweep for Sketch {
             shells.push(shell);
         }
 
-        Solid::new().with_shells(shells)
+        Solid::new().with_shells(shells).with_sweep_path(path)
     }
 }
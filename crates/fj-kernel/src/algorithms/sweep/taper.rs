@@ -0,0 +1,228 @@
+use fj_math::{Point, Scalar, Transform, Vector};
+
+use crate::{
+    algorithms::transform::TransformObject,
+    objects::{Face, Shell, Sketch, Solid},
+    path::SurfacePath,
+};
+
+use super::{loft::loft_polygons, SweepWithTaper};
+
+impl SweepWithTaper for Face {
+    type Swept = Shell;
+
+    fn sweep_with_taper(
+        self,
+        path: impl Into<Vector<3>>,
+        draft_angle: impl Into<Scalar>,
+    ) -> Result<Self::Swept, TaperError> {
+        let path = path.into();
+        let draft_angle = draft_angle.into();
+
+        assert!(
+            path.magnitude() > Scalar::ZERO,
+            "Tapered sweep requires a path with non-zero length"
+        );
+
+        if draft_angle.abs() >= Scalar::PI / 2. {
+            return Err(TaperError::DraftAngleOutOfRange(draft_angle));
+        }
+
+        // The scale factor is derived from the vertices of the exterior, which
+        // a cycle that is bounded by a circle doesn't have enough of.
+        let is_bounded_by_circle = self
+            .all_cycles()
+            .flat_map(|cycle| cycle.half_edges())
+            .any(|half_edge| {
+                matches!(half_edge.curve().path(), SurfacePath::Circle(_))
+            });
+        if is_bounded_by_circle {
+            return Err(TaperError::CurvedProfile);
+        }
+
+        let points = self
+            .exterior()
+            .vertices()
+            .map(|vertex| vertex.global_form().position())
+            .collect::<Vec<_>>();
+        let centroid = {
+            let sum = points
+                .iter()
+                .fold(Vector::from([0., 0., 0.]), |sum, point| {
+                    sum + point.coords
+                });
+            Point {
+                coords: sum / Scalar::from_f64(points.len() as f64),
+            }
+        };
+
+        // The side that is closest to the centroid is the first to collapse,
+        // so that's the one that determines the scale factor.
+        let distance = (0..points.len())
+            .map(|i| {
+                let j = (i + 1) % points.len();
+                distance_to_segment(centroid, [points[i], points[j]])
+            })
+            .min()
+            .unwrap_or(Scalar::ZERO);
+
+        let offset =
+            path.magnitude() * Scalar::from_f64(draft_angle.into_f64().tan());
+        let scale = if offset == Scalar::ZERO {
+            Scalar::ONE
+        } else if offset < distance && distance > Scalar::ZERO {
+            Scalar::ONE - offset / distance
+        } else {
+            return Err(TaperError::EndCollapses {
+                draft_angle,
+                distance,
+            });
+        };
+
+        let transform = Transform::translation(centroid.coords + path)
+            * Transform::scale([scale, scale, scale])
+            * Transform::translation(-centroid.coords);
+
+        let end = self.clone().transform(&transform);
+        Ok(loft_polygons(self, end))
+    }
+}
+
+impl SweepWithTaper for Sketch {
+    type Swept = Solid;
+
+    fn sweep_with_taper(
+        self,
+        path: impl Into<Vector<3>>,
+        draft_angle: impl Into<Scalar>,
+    ) -> Result<Self::Swept, TaperError> {
+        let path = path.into();
+        let draft_angle = draft_angle.into();
+
+        let mut shells = Vec::new();
+        for face in self.into_faces() {
+            let shell = face.sweep_with_taper(path, draft_angle)?;
+            shells.push(shell);
+        }
+
+        Ok(Solid::new().with_shells(shells).with_sweep_path(path))
+    }
+}
+
+/// Error sweeping with a taper
+///
+/// See [`SweepWithTaper`].
+#[derive(Debug, thiserror::Error)]
+pub enum TaperError {
+    /// The draft angle is a right angle, or larger
+    #[error("Draft angle must be smaller than a right angle ({0})")]
+    DraftAngleOutOfRange(Scalar),
+
+    /// The profile has edges that aren't line segments
+    #[error("Only polygons can be swept with a taper, but profile is curved")]
+    CurvedProfile,
+
+    /// The end of the sweep would have zero area, or be turned inside out
+    #[error(
+        "Draft angle {draft_angle} collapses the end of the sweep (side at \
+        distance {distance} from centroid)"
+    )]
+    EndCollapses {
+        /// The draft angle
+        draft_angle: Scalar,
+
+        /// The distance between the centroid and the side closest to it
+        distance: Scalar,
+    },
+}
+
+fn distance_to_segment(point: Point<3>, [a, b]: [Point<3>; 2]) -> Scalar {
+    let ab = b - a;
+    let t =
+        ((point - a).dot(&ab) / ab.dot(&ab)).clamp(Scalar::ZERO, Scalar::ONE);
+    (point - (a + ab * t)).magnitude()
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        algorithms::sweep::SweepWithTaper,
+        objects::{Cycle, Face, HalfEdge, Surface},
+    };
+
+    use super::TaperError;
+
+    fn square() -> Face {
+        Face::build(Surface::xy_plane())
+            .polygon_from_points([[-1., -1.], [1., -1.], [1., 1.], [-1., 1.]])
+            .into_face()
+    }
+
+    #[test]
+    fn tapered_square() {
+        // Over a sweep of length 1, each side moves inwards by 0.5, which
+        // halves the size of the square.
+        let draft_angle = Scalar::from_f64(0.5_f64.atan());
+
+        for direction in [1., -1.] {
+            let shell = square()
+                .sweep_with_taper([0., 0., direction], draft_angle)
+                .unwrap();
+
+            // Both ends, and two triangles per side.
+            assert_eq!(shell.faces().into_iter().count(), 2 + 4 * 2);
+            assert!(shell.is_closed());
+
+            let (_, num_flipped) = shell.fix_orientations();
+            assert_eq!(num_flipped, 0);
+
+            let end = shell
+                .faces()
+                .into_iter()
+                .flat_map(|face| face.exterior().vertices())
+                .map(|vertex| vertex.global_form().position())
+                .filter(|position| position.z == Scalar::from(direction))
+                .collect::<Vec<_>>();
+            assert!(!end.is_empty());
+            for position in end {
+                for coord in [position.x, position.y] {
+                    let error = coord.abs() - Scalar::from(0.5);
+                    assert!(error.abs() < Scalar::from(1e-12));
+                }
+            }
+        }
+
+        // A negative draft angle makes the end larger.
+        let shell = square().sweep_with_taper([0., 0., 1.], -draft_angle);
+        assert!(shell.is_ok());
+    }
+
+    #[test]
+    fn collapsing_end() {
+        let result = square().sweep_with_taper(
+            [0., 0., 1.],
+            Scalar::PI / 4. + Scalar::from(0.1),
+        );
+        assert!(matches!(result, Err(TaperError::EndCollapses { .. })));
+
+        let result = square().sweep_with_taper([0., 0., 1.], Scalar::PI / 2.);
+        assert!(matches!(result, Err(TaperError::DraftAngleOutOfRange(_))));
+    }
+
+    #[test]
+    fn curved_profile() {
+        let surface = Surface::xy_plane();
+        let circle = Face::new(
+            surface,
+            Cycle::new(
+                surface,
+                [HalfEdge::build(surface).circle_from_radius(1.)],
+            ),
+        );
+
+        let result = circle.sweep_with_taper([0., 0., 1.], Scalar::from(0.1));
+        assert!(matches!(result, Err(TaperError::CurvedProfile)));
+    }
+}
@@ -0,0 +1,225 @@
+use fj_math::{Point, Scalar, Transform, Vector};
+
+use crate::{
+    algorithms::{
+        approx::Tolerance, reverse::Reverse, transform::TransformObject,
+    },
+    builder::triangle_from_points,
+    objects::{Face, Shell, Sketch, Solid},
+};
+
+use super::{approx_face, SweepWithTwist};
+
+impl SweepWithTwist for Face {
+    type Swept = Shell;
+
+    fn sweep_with_twist(
+        self,
+        path: impl Into<Vector<3>>,
+        twist: impl Into<Scalar>,
+        num_steps: usize,
+        tolerance: impl Into<Tolerance>,
+    ) -> Self::Swept {
+        let path = path.into();
+        let twist = twist.into();
+        let tolerance = tolerance.into();
+
+        assert!(num_steps > 0, "Twisted sweep requires at least one step");
+
+        // The sides are made up of triangles, which can only connect to
+        // straight edges. Replace the face with its approximation, so the
+        // bottom and top faces connect to the sides.
+        let face = approx_face(self, tolerance);
+
+        let is_negative_sweep = {
+            // The surface of the face is not necessarily a plane, so determine
+            // its normal at a point that is actually on the face.
+            let point = face
+                .exterior()
+                .vertices()
+                .next()
+                .expect("Face has no vertices")
+                .surface_form()
+                .position();
+            let normal = face.surface().normal_at(point);
+
+            normal.dot(&path) < Scalar::ZERO
+        };
+
+        // The transforms that move the original face to each step of the
+        // sweep. The first step is the original face itself.
+        let transforms = (1..=num_steps)
+            .map(|step| {
+                let fraction = Scalar::from_f64(step as f64 / num_steps as f64);
+                Transform::translation(path * fraction)
+                    * Transform::rotation(path.normalize() * twist * fraction)
+            })
+            .collect::<Vec<_>>();
+        let point_at_step = |point: Point<3>, step: usize| {
+            if step == 0 {
+                point
+            } else {
+                transforms[step - 1].transform_point(&point)
+            }
+        };
+
+        let mut faces = Vec::new();
+
+        let bottom_face = if is_negative_sweep {
+            face.clone()
+        } else {
+            face.clone().reverse()
+        };
+        faces.push(bottom_face);
+
+        let top_face = {
+            let top_face = face.clone().transform(&transforms[num_steps - 1]);
+            if is_negative_sweep {
+                top_face.reverse()
+            } else {
+                top_face
+            }
+        };
+        faces.push(top_face);
+
+        for cycle in face.all_cycles() {
+            for &half_edge in cycle.half_edges() {
+                let edge = if is_negative_sweep {
+                    half_edge.reverse()
+                } else {
+                    half_edge
+                };
+
+                // The side between two steps is twisted, which means it is not
+                // planar. Approximate it using two triangles.
+                let [a, b] = edge
+                    .vertices()
+                    .map(|vertex| vertex.global_form().position());
+                for step in 0..num_steps {
+                    let [a0, b0, a1, b1] = [
+                        point_at_step(a, step),
+                        point_at_step(b, step),
+                        point_at_step(a, step + 1),
+                        point_at_step(b, step + 1),
+                    ];
+
+                    faces
+                        .push(triangle_from_points([a0, b0, b1], face.color()));
+                    faces
+                        .push(triangle_from_points([a0, b1, a1], face.color()));
+                }
+            }
+        }
+
+        Shell::new().with_faces(faces)
+    }
+}
+
+impl SweepWithTwist for Sketch {
+    type Swept = Solid;
+
+    fn sweep_with_twist(
+        self,
+        path: impl Into<Vector<3>>,
+        twist: impl Into<Scalar>,
+        num_steps: usize,
+        tolerance: impl Into<Tolerance>,
+    ) -> Self::Swept {
+        let path = path.into();
+        let twist = twist.into();
+        let tolerance = tolerance.into();
+
+        let mut shells = Vec::new();
+        for face in self.into_faces() {
+            let shell =
+                face.sweep_with_twist(path, twist, num_steps, tolerance);
+            shells.push(shell);
+        }
+
+        Solid::new().with_shells(shells).with_sweep_path(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar, Transform};
+
+    use crate::{
+        algorithms::sweep::SweepWithTwist,
+        objects::{Cycle, Face, HalfEdge, Sketch, Surface},
+    };
+
+    #[test]
+    fn twisted_square_prism() {
+        let square = [[-1., -1.], [1., -1.], [1., 1.], [-1., 1.]];
+        let twist = Scalar::PI / 4.;
+        let num_steps = 4;
+
+        let solid = Sketch::build(Surface::xy_plane())
+            .polygon_from_points(square)
+            .sweep_with_twist([0., 0., 1.], twist, num_steps, 0.01);
+
+        let faces = solid
+            .shells()
+            .flat_map(|shell| shell.faces().into_iter().cloned())
+            .collect::<Vec<_>>();
+
+        // Top, bottom, and two triangles per side and step.
+        assert_eq!(faces.len(), 2 + 4 * num_steps * 2);
+
+        let top = faces
+            .iter()
+            .find(|face| {
+                face.exterior().vertices().count() == 4
+                    && face.exterior().vertices().all(|vertex| {
+                        vertex.global_form().position().z == Scalar::ONE
+                    })
+            })
+            .expect("Expected top face");
+
+        let rotation = Transform::rotation([0., 0., twist.into_f64()]);
+        for point in square {
+            let expected = rotation
+                .transform_point(&Point::from(point).to_xyz())
+                + [0., 0., 1.];
+
+            assert!(top.exterior().vertices().any(|vertex| {
+                let position = vertex.global_form().position();
+                (position - expected).magnitude() < Scalar::from(1e-12)
+            }));
+        }
+    }
+
+    #[test]
+    fn twisted_cylinder() {
+        let surface = Surface::xy_plane();
+        let circle = Face::new(
+            surface,
+            Cycle::new(
+                surface,
+                [HalfEdge::build(surface).circle_from_radius(1.)],
+            ),
+        );
+
+        let num_steps = 4;
+        let shell = circle.sweep_with_twist(
+            [0., 0., 1.],
+            Scalar::PI / 2.,
+            num_steps,
+            0.01,
+        );
+
+        // The circle is approximated before sweeping, so all sides are
+        // triangles.
+        let sides = shell
+            .faces()
+            .into_iter()
+            .filter(|face| face.exterior().half_edges().count() == 3)
+            .count();
+        assert_eq!(shell.faces().into_iter().count(), 2 + sides);
+        assert_eq!(sides % (num_steps * 2), 0);
+
+        let (_, num_flipped) = shell.fix_orientations();
+        assert_eq!(num_flipped, 0);
+    }
+}
@@ -1,11 +1,11 @@
-use fj_math::{Line, Point, Scalar, Vector};
+use fj_math::{Line, Point, Scalar, Vector, DEFAULT_EPSILON};
 
 use crate::{
     objects::{
         Curve, GlobalCurve, GlobalEdge, GlobalVertex, HalfEdge, Surface,
         SurfaceVertex, Vertex,
     },
-    path::SurfacePath,
+    path::{GlobalPath, SurfacePath},
 };
 
 use super::Sweep;
@@ -49,8 +49,17 @@ impl Sweep for (Vertex, Surface) {
         //
         // Let's make sure that these requirements are met.
         {
-            assert_eq!(vertex.curve().global_form().path(), surface.u());
-            assert_eq!(path, surface.v());
+            let curve_path = vertex.curve().global_form().path();
+            assert!(
+                paths_approx_eq(curve_path, surface.u()),
+                "Curve path {curve_path:?} doesn't match surface ({:?})",
+                surface.u()
+            );
+            assert!(
+                vectors_approx_eq([path], [surface.v()]),
+                "Sweep path {path:?} doesn't match surface ({:?})",
+                surface.v()
+            );
         }
 
         // With that out of the way, let's start by creating the `GlobalEdge`,
@@ -139,14 +148,41 @@ impl Sweep for GlobalVertex {
     }
 }
 
+fn paths_approx_eq(a: GlobalPath, b: GlobalPath) -> bool {
+    match (a, b) {
+        (GlobalPath::Circle(a), GlobalPath::Circle(b)) => vectors_approx_eq(
+            [a.center().coords, a.a(), a.b()],
+            [b.center().coords, b.a(), b.b()],
+        ),
+        (GlobalPath::Line(a), GlobalPath::Line(b)) => vectors_approx_eq(
+            [a.origin().coords, a.direction()],
+            [b.origin().coords, b.direction()],
+        ),
+        _ => false,
+    }
+}
+
+fn vectors_approx_eq<const N: usize>(
+    a: [Vector<3>; N],
+    b: [Vector<3>; N],
+) -> bool {
+    a.into_iter()
+        .zip(b)
+        .flat_map(|(a, b)| a.components.into_iter().zip(b.components))
+        .all(|(a, b)| a.approx_eq(b, DEFAULT_EPSILON))
+}
+
 #[cfg(test)]
 mod tests {
+    use fj_math::{Line, Point, Vector};
+
     use crate::{
         algorithms::sweep::Sweep,
         objects::{
             Curve, GlobalCurve, GlobalEdge, GlobalVertex, HalfEdge, Surface,
             Vertex,
         },
+        path::{GlobalPath, SurfacePath},
     };
 
     #[test]
@@ -162,6 +198,32 @@ mod tests {
         assert_eq!(half_edge, expected_half_edge);
     }
 
+    #[test]
+    fn vertex_surface_with_floating_point_drift() {
+        // The surface was computed in a way that introduced tiny errors, so it
+        // doesn't exactly match the curve and path it was created from.
+        let surface = Surface::new(
+            GlobalPath::Line(Line::from_origin_and_direction(
+                Point::from([0., 1e-12, 0.]),
+                Vector::from([1., 1e-12, 0.]),
+            )),
+            [0., 0., 1. + 1e-12],
+        );
+        let curve = Curve::new(
+            surface,
+            SurfacePath::line_from_points([[0., 0.], [1., 0.]]),
+            GlobalCurve::build().x_axis(),
+        );
+        let vertex = Vertex::build(curve).from_point([0.]);
+
+        let half_edge = (vertex, surface).sweep([0., 0., 1.]);
+
+        assert_eq!(
+            half_edge.curve().path(),
+            SurfacePath::line_from_points([[0., 0.], [0., 1.]])
+        );
+    }
+
     #[test]
     fn global_vertex() {
         let edge =
@@ -1,8 +1,10 @@
 //! Transforming objects
 
-use fj_math::{Transform, Vector};
+use fj_math::{Circle, Transform, Vector, DEFAULT_EPSILON};
 
 use crate::{
+    algorithms::reverse::Reverse,
+    iter::ObjectIters,
     objects::{
         Curve, Cycle, Face, Faces, GlobalCurve, GlobalVertex, HalfEdge, Shell,
         Sketch, Solid, Surface, SurfaceVertex, Vertex,
@@ -35,6 +37,77 @@ pub trait TransformObject: Sized {
     fn rotate(self, axis_angle: impl Into<Vector<3>>) -> Self {
         self.transform(&Transform::rotation(axis_angle))
     }
+
+    /// Scale the object
+    ///
+    /// The components of `factors` define the scale factors along the x, y,
+    /// and z axes. The scaling is relative to the origin.
+    ///
+    /// Returns an error, if the object contains a circle, or a surface that is
+    /// swept from a circle, that the scaling would turn into an ellipse. That
+    /// is the case, if the scaling is non-uniform within the plane of the
+    /// circle. Ellipses can't be represented.
+    fn scale(self, factors: impl Into<Vector<3>>) -> Result<Self, ScaleError>
+    where
+        Self: for<'r> ObjectIters<'r>,
+    {
+        let transform = Transform::scale(factors);
+
+        let circles = self
+            .global_curve_iter()
+            .map(|curve| curve.path())
+            .chain(self.surface_iter().map(|surface| surface.u()))
+            .filter_map(|path| match path {
+                GlobalPath::Circle(circle) => Some(circle),
+                GlobalPath::Line(_) => None,
+            });
+        for circle in circles {
+            let [a, b] = [circle.a(), circle.b()]
+                .map(|vector| transform.transform_vector(&vector));
+
+            let [a_len, b_len] = [a, b].map(|vector| vector.magnitude());
+            let is_circle = (a_len - b_len).abs() <= a_len * DEFAULT_EPSILON
+                && a.dot(&b).abs() <= a_len * b_len * DEFAULT_EPSILON;
+            if !is_circle {
+                return Err(ScaleError::CircleBecomesEllipse(circle));
+            }
+        }
+
+        Ok(self.transform(&transform))
+    }
+
+    /// Mirror the object through a plane
+    ///
+    /// Faces, and the objects that are made up of them, are reversed, so they
+    /// are still facing outward afterwards. Points on the plane stay where they
+    /// are, if the plane is aligned with the coordinate axes. Otherwise they
+    /// might move, within the limits of floating-point accuracy.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `plane` is not a plane.
+    #[must_use]
+    fn mirror(self, plane: Surface) -> Self {
+        assert!(
+            matches!(plane.u(), GlobalPath::Line(_)),
+            "Can only mirror through planes, not curved surfaces"
+        );
+
+        let origin = plane.point_from_surface_coords([0., 0.]);
+        let normal = plane.normal_at([0., 0.]);
+
+        self.transform(&Transform::reflection(origin, normal))
+    }
+}
+
+/// Error scaling an object
+///
+/// See [`TransformObject::scale`].
+#[derive(Debug, thiserror::Error)]
+pub enum ScaleError {
+    /// The scaling would turn a circle into an ellipse
+    #[error("Scaling turns circle into an ellipse, which is not supported")]
+    CircleBecomesEllipse(Circle<3>),
 }
 
 impl TransformObject for Curve {
@@ -67,9 +140,24 @@ impl TransformObject for Face {
 
         let color = self.color();
 
-        Face::new(surface, exterior)
+        let face = Face::new(surface, exterior)
             .with_interiors(interiors)
-            .with_color(color)
+            .with_color(color);
+
+        let face = match self.sweep_path() {
+            Some(path) => {
+                face.with_sweep_path(transform.transform_vector(&path))
+            }
+            None => face,
+        };
+
+        // A transform that reverses orientation turns the face into a mirror
+        // image of itself, which would then face the other way.
+        if transform.reverses_orientation() {
+            face.reverse()
+        } else {
+            face
+        }
     }
 }
 
@@ -137,8 +225,17 @@ impl TransformObject for Sketch {
 
 impl TransformObject for Solid {
     fn transform(self, transform: &Transform) -> Self {
+        let sweep_path = self.sweep_path();
+
         let faces = self.into_shells().map(|shell| shell.transform(transform));
-        Self::new().with_shells(faces)
+        let solid = Self::new().with_shells(faces);
+
+        match sweep_path {
+            Some(path) => {
+                solid.with_sweep_path(transform.transform_vector(&path))
+            }
+            None => solid,
+        }
     }
 }
 
@@ -171,3 +268,126 @@ impl TransformObject for Vertex {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::mesh::Color;
+    use fj_math::{Point, Scalar, Transform, Vector};
+
+    use crate::{
+        algorithms::sweep::Sweep,
+        iter::ObjectIters,
+        objects::{Face, HalfEdge, Surface},
+        path::GlobalPath,
+    };
+
+    use super::{ScaleError, TransformObject};
+
+    #[test]
+    fn scale_swept_edge() {
+        let half_edge = HalfEdge::build(Surface::xy_plane())
+            .line_segment_from_points([[0., 0.], [1., 0.]]);
+        let face = (half_edge, Color::default()).sweep([0., 0., 1.]);
+
+        let scaled = face.clone().scale([2., 3., 4.]).unwrap();
+
+        let mut expected = face
+            .global_vertex_iter()
+            .map(|vertex| {
+                let [x, y, z] = vertex.position().coords.components;
+                Point::from([x * 2., y * 3., z * 4.])
+            })
+            .collect::<Vec<_>>();
+        let mut positions = scaled
+            .global_vertex_iter()
+            .map(|vertex| vertex.position())
+            .collect::<Vec<_>>();
+
+        expected.sort();
+        positions.sort();
+        assert_eq!(positions, expected);
+
+        assert!(positions.contains(&Point::from([2., 0., 4.])));
+    }
+
+    #[test]
+    fn mirror_swept_face() {
+        let shell = Face::build(Surface::xy_plane())
+            .polygon_from_points([[0., 0.], [1., 0.], [0., 1.]])
+            .into_face()
+            .sweep([0., 0., 1.]);
+
+        let mirrored = shell.clone().mirror(Surface::xz_plane());
+
+        let mut expected = shell
+            .global_vertex_iter()
+            .map(|vertex| {
+                let [x, y, z] = vertex.position().coords.components;
+                Point::from([x, -y, z])
+            })
+            .collect::<Vec<_>>();
+        let mut positions = mirrored
+            .global_vertex_iter()
+            .map(|vertex| vertex.position())
+            .collect::<Vec<_>>();
+
+        expected.sort();
+        positions.sort();
+        assert_eq!(positions, expected);
+
+        // Vertices on the plane are shared with the original shell.
+        for vertex in shell.global_vertex_iter() {
+            if vertex.position().y == Scalar::ZERO {
+                assert!(mirrored.global_vertex_iter().any(|v| v == vertex));
+            }
+        }
+
+        // The faces still face outward.
+        let (_, num_flipped) = mirrored.fix_orientations();
+        assert_eq!(num_flipped, 0);
+    }
+
+    #[test]
+    fn transform_with_combined_transform() {
+        let half_edge =
+            HalfEdge::build(Surface::xy_plane()).circle_from_radius(1.);
+
+        let offset = [1., 2., 3.];
+        let axis_angle = Vector::from([1., 0., 0.]) * (Scalar::PI / 4.);
+
+        let chained = half_edge.clone().translate(offset).rotate(axis_angle);
+        let combined = half_edge.transform(
+            &(Transform::rotation(axis_angle) * Transform::translation(offset)),
+        );
+
+        let [a, b] = [&chained, &combined].map(|half_edge| {
+            match half_edge.curve().global_form().path() {
+                GlobalPath::Circle(circle) => circle,
+                GlobalPath::Line(_) => panic!("Expected circle"),
+            }
+        });
+
+        let epsilon = Scalar::from(1e-12);
+        assert!((a.center() - b.center()).magnitude() < epsilon);
+        assert!((a.a() - b.a()).magnitude() < epsilon);
+        assert!((a.b() - b.b()).magnitude() < epsilon);
+
+        let [a, b] = [&chained, &combined]
+            .map(|half_edge| half_edge.vertices()[0].global_form().position());
+        assert!((a - b).magnitude() < epsilon);
+    }
+
+    #[test]
+    fn scale_circle() {
+        let half_edge =
+            HalfEdge::build(Surface::xy_plane()).circle_from_radius(1.);
+
+        // Scaling that is uniform within the plane of the circle keeps it a
+        // circle.
+        assert!(half_edge.clone().scale([2., 2., 2.]).is_ok());
+        assert!(half_edge.clone().scale([1., 1., 3.]).is_ok());
+
+        let result = half_edge.scale([1., 2., 1.]);
+        assert!(matches!(result, Err(ScaleError::CircleBecomesEllipse(_))));
+    }
+}
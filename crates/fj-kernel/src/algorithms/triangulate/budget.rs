@@ -0,0 +1,175 @@
+//! Triangulation to a fixed triangle budget
+//!
+//! See [`approx_to_budget`].
+
+use fj_interop::mesh::Mesh;
+use fj_math::{Aabb, Point, Scalar};
+
+use crate::{
+    algorithms::approx::{InvalidTolerance, Tolerance},
+    iter::ObjectIters,
+    objects::Shell,
+    path::GlobalPath,
+};
+
+use super::Triangulate;
+
+/// Triangulate a shell, using as many triangles as the budget allows
+///
+/// Searches for the smallest tolerance, for which the triangulation of `shell`
+/// stays at or below `max_triangles`, and returns that triangulation, together
+/// with the tolerance that was used to create it.
+///
+/// The search covers tolerance values between a coarse upper bound derived
+/// from the shell (the smallest circle radius or, if there are no circles, the
+/// largest extent of its bounding box) and a thousandth of that. Within that
+/// range, it bisects the tolerance for a fixed number of iterations.
+///
+/// Returns an error, if even the coarsest triangulation exceeds the budget.
+pub fn approx_to_budget(
+    shell: &Shell,
+    max_triangles: usize,
+) -> Result<(Mesh<Point<3>>, Tolerance), BudgetError> {
+    const ITERATIONS: usize = 16;
+
+    let coarsest = coarsest_tolerance(shell)?;
+
+    let mesh = triangulate(shell, coarsest.inner())?;
+    let num_triangles = mesh.triangles().count();
+    if num_triangles > max_triangles {
+        return Err(BudgetError::BudgetTooSmall {
+            max_triangles,
+            min_triangles: num_triangles,
+        });
+    }
+
+    let finest = coarsest.inner() / 1000.;
+    let mesh_finest = triangulate(shell, finest)?;
+    if mesh_finest.triangles().count() <= max_triangles {
+        return Ok((mesh_finest, Tolerance::from_scalar(finest)?));
+    }
+
+    // Invariant: `upper` fits the budget, `lower` doesn't. Bisect in
+    // logarithmic space, as the number of triangles grows roughly with the
+    // inverse of the tolerance.
+    let mut upper = (coarsest.inner(), mesh);
+    let mut lower = finest;
+
+    for _ in 0..ITERATIONS {
+        let middle = Scalar::from_f64((upper.0 * lower).into_f64().sqrt());
+        let mesh = triangulate(shell, middle)?;
+
+        if mesh.triangles().count() <= max_triangles {
+            upper = (middle, mesh);
+        } else {
+            lower = middle;
+        }
+    }
+
+    let (tolerance, mesh) = upper;
+    Ok((mesh, Tolerance::from_scalar(tolerance)?))
+}
+
+fn coarsest_tolerance(shell: &Shell) -> Result<Tolerance, BudgetError> {
+    // A tolerance larger than a circle's radius doesn't make the
+    // approximation of that circle any coarser, so it is pointless to go
+    // beyond the smallest radius.
+    let min_radius = shell
+        .global_curve_iter()
+        .filter_map(|curve| match curve.path() {
+            GlobalPath::Circle(circle) => Some(circle.radius()),
+            GlobalPath::Line(_) => None,
+        })
+        .reduce(|a, b| if a < b { a } else { b });
+
+    let tolerance = match min_radius {
+        Some(radius) => radius,
+        None => {
+            let aabb = Aabb::<3>::from_points(
+                shell.global_vertex_iter().map(|vertex| vertex.position()),
+            );
+            aabb.size()
+                .components
+                .into_iter()
+                .fold(Scalar::ZERO, Scalar::max)
+        }
+    };
+
+    Ok(Tolerance::from_scalar(tolerance)?)
+}
+
+fn triangulate(
+    shell: &Shell,
+    tolerance: impl Into<Scalar>,
+) -> Result<Mesh<Point<3>>, BudgetError> {
+    let tolerance = Tolerance::from_scalar(tolerance)?;
+    Ok(shell.triangulate(tolerance))
+}
+
+/// Error triangulating a shell to a triangle budget
+#[derive(Debug, thiserror::Error)]
+pub enum BudgetError {
+    /// The shell is degenerate, and no valid tolerance could be derived
+    #[error("Could not derive tolerance from shell")]
+    InvalidTolerance(#[from] InvalidTolerance),
+
+    /// Even the coarsest approximation exceeds the triangle budget
+    #[error(
+        "Triangle budget too small (budget: {max_triangles}, coarsest \
+        approximation: {min_triangles})"
+    )]
+    BudgetTooSmall {
+        /// The triangle budget that was requested
+        max_triangles: usize,
+
+        /// The number of triangles in the coarsest approximation
+        min_triangles: usize,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algorithms::{sweep::Sweep, triangulate::Triangulate},
+        objects::{Cycle, Face, HalfEdge, Shell, Surface},
+    };
+
+    use super::{approx_to_budget, BudgetError};
+
+    #[test]
+    fn stays_within_budget() -> anyhow::Result<()> {
+        let surface = Surface::xy_plane();
+        let half_edge = HalfEdge::build(surface).circle_from_radius(1.);
+        let cycle = Cycle::new(surface, [half_edge]);
+        let shell = Face::new(surface, cycle).sweep([0., 0., 1.]);
+
+        let max_triangles = 200;
+        let (mesh, tolerance) = approx_to_budget(&shell, max_triangles)?;
+
+        assert!(mesh.triangles().count() <= max_triangles);
+
+        // A finer tolerance should produce a mesh with more triangles.
+        let finer = (&shell).triangulate(tolerance.inner() / 2.);
+        assert!(finer.triangles().count() >= mesh.triangles().count());
+
+        Ok(())
+    }
+
+    #[test]
+    fn budget_too_small() {
+        let shell = Shell::build().cube_from_edge_length(1.);
+        let result = approx_to_budget(&shell, 1);
+
+        assert!(matches!(
+            result,
+            Err(BudgetError::BudgetTooSmall {
+                max_triangles: 1,
+                min_triangles: 12
+            })
+        ));
+
+        // The cube is made of flat faces, so any tolerance will do.
+        let (mesh, _) = approx_to_budget(&shell, 12).unwrap();
+        assert_eq!(mesh.triangles().count(), 12);
+    }
+}
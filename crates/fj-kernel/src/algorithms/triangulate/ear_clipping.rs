@@ -0,0 +1,170 @@
+//! Triangulation of simple polygons by ear clipping
+//!
+//! See [`triangulate_simple_polygon`].
+
+use fj_math::{signed_area, Point, Scalar};
+
+/// Triangulate a simple polygon
+///
+/// Takes the vertices of a simple (i.e. non-self-intersecting) polygon, which
+/// may be concave. The polygon is implicitly closed, meaning the last vertex
+/// connects back to the first one. The vertices may be given in either
+/// orientation.
+///
+/// Returns the triangles of the polygon. All triangles are wound
+/// counter-clockwise, regardless of the orientation of the input. Vertices
+/// that lie on a straight line between their neighbors don't form triangles of
+/// their own.
+///
+/// # Panics
+///
+/// Panics, if the polygon is self-intersecting.
+pub fn triangulate_simple_polygon(points: &[Point<2>]) -> Vec<[Point<2>; 3]> {
+    let mut triangles = Vec::new();
+
+    if points.len() < 3 {
+        return triangles;
+    }
+
+    // The algorithm below assumes a counter-clockwise polygon. Indices are
+    // removed from this list as ears are clipped off.
+    let mut remaining = (0..points.len()).collect::<Vec<_>>();
+    if signed_area(points) < Scalar::ZERO {
+        remaining.reverse();
+    }
+
+    while remaining.len() > 3 {
+        let corner_at = |i: usize| {
+            let n = remaining.len();
+            let prev = points[remaining[(i + n - 1) % n]];
+            let curr = points[remaining[i]];
+            let next = points[remaining[(i + 1) % n]];
+            [prev, curr, next]
+        };
+
+        let ear = (0..remaining.len()).find(|&i| {
+            let triangle = corner_at(i);
+            if !is_convex(triangle) {
+                return false;
+            }
+
+            // A convex corner is an ear, if no other vertex of the polygon
+            // lies within the triangle it forms. Otherwise, clipping it off
+            // would cut through the polygon's boundary.
+            remaining
+                .iter()
+                .map(|&index| points[index])
+                .filter(|point| !triangle.contains(point))
+                .all(|point| !triangle_contains(triangle, point))
+        });
+
+        match ear {
+            Some(i) => {
+                triangles.push(corner_at(i));
+                remaining.remove(i);
+            }
+            None => {
+                // There are no ears left. This can only happen, if the
+                // remaining vertices include some that are collinear with
+                // their neighbors. Those can be removed without changing the
+                // area of the polygon.
+                let collinear = (0..remaining.len())
+                    .find(|&i| {
+                        let [a, b, c] = corner_at(i);
+                        (b - a).cross(&(c - b)) == Scalar::ZERO
+                    })
+                    .expect("Can't triangulate self-intersecting polygon");
+                remaining.remove(collinear);
+            }
+        }
+    }
+
+    let last = [
+        points[remaining[0]],
+        points[remaining[1]],
+        points[remaining[2]],
+    ];
+    if is_convex(last) {
+        triangles.push(last);
+    }
+
+    triangles
+}
+
+fn is_convex([a, b, c]: [Point<2>; 3]) -> bool {
+    (b - a).cross(&(c - b)) > Scalar::ZERO
+}
+
+fn triangle_contains([a, b, c]: [Point<2>; 3], point: Point<2>) -> bool {
+    // The triangle is counter-clockwise, so the point is contained, if it's
+    // not on the right side of any edge. Points on the boundary count as
+    // contained.
+    [(a, b), (b, c), (c, a)].into_iter().all(|(start, end)| {
+        (end - start).cross(&(point - start)) >= Scalar::ZERO
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use super::triangulate_simple_polygon;
+
+    #[test]
+    fn l_shape() {
+        let points =
+            [[0., 0.], [2., 0.], [2., 1.], [1., 1.], [1., 2.], [0., 2.]]
+                .map(Point::from);
+
+        let triangles = triangulate_simple_polygon(&points);
+        assert_eq!(triangles.len(), 4);
+        assert_eq!(area(&triangles), Scalar::from(3.));
+
+        // The orientation of the input doesn't matter.
+        let mut reversed = points;
+        reversed.reverse();
+        let triangles = triangulate_simple_polygon(&reversed);
+        assert_eq!(triangles.len(), 4);
+        assert_eq!(area(&triangles), Scalar::from(3.));
+    }
+
+    #[test]
+    fn comb() {
+        // A comb with three teeth, each of which is 1 wide and 2 high, with
+        // gaps of width 1 in between. The spine is 5 wide and 1 high.
+        let points = [
+            [0., 0.],
+            [5., 0.],
+            [5., 3.],
+            [4., 3.],
+            [4., 1.],
+            [3., 1.],
+            [3., 3.],
+            [2., 3.],
+            [2., 1.],
+            [1., 1.],
+            [1., 3.],
+            [0., 3.],
+        ]
+        .map(Point::from);
+
+        let triangles = triangulate_simple_polygon(&points);
+        assert_eq!(triangles.len(), points.len() - 2);
+        assert_eq!(area(&triangles), Scalar::from(11.));
+    }
+
+    fn area(triangles: &[[Point<2>; 3]]) -> Scalar {
+        let mut area = Scalar::ZERO;
+
+        for &[a, b, c] in triangles {
+            let signed_area = (b - a).cross(&(c - a)) / 2.;
+
+            // All triangles are wound counter-clockwise.
+            assert!(signed_area > Scalar::ZERO);
+
+            area += signed_area;
+        }
+
+        area
+    }
+}
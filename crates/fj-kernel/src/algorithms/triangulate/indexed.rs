@@ -0,0 +1,110 @@
+//! Indexed triangles of a single face
+//!
+//! See [`IndexedTriangles`].
+
+use std::collections::BTreeMap;
+
+use fj_interop::mesh::{Color, Index};
+use fj_math::Point;
+
+use crate::algorithms::approx::face::FaceApprox;
+
+use super::triangulate_face;
+
+/// The triangulation of a single face, as indexed triangles
+///
+/// Created by [`FaceApprox::to_indexed_triangles`]. Unlike [`Mesh`], which
+/// covers a whole shape, this only contains the vertices of a single face.
+/// This makes it suitable for building per-face buffers, which can later be
+/// merged.
+///
+/// [`Mesh`]: fj_interop::mesh::Mesh
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IndexedTriangles {
+    /// The vertices of the face
+    ///
+    /// Each vertex is only present once.
+    pub vertices: Vec<Point<3>>,
+
+    /// The triangles of the face, as indices into `vertices`
+    pub triangles: Vec<[Index; 3]>,
+
+    /// The color of the face
+    pub color: Color,
+}
+
+impl FaceApprox {
+    /// Triangulate the face approximation into indexed triangles
+    pub fn to_indexed_triangles(&self) -> IndexedTriangles {
+        let mut vertices = Vec::new();
+        let mut indices_by_vertex = BTreeMap::new();
+
+        let triangles = triangulate_face(self)
+            .into_iter()
+            .map(|triangle| {
+                triangle.map(|point| {
+                    *indices_by_vertex.entry(point.point_global).or_insert_with(
+                        || {
+                            let index = vertices.len() as Index;
+                            vertices.push(point.point_global);
+                            index
+                        },
+                    )
+                })
+            })
+            .collect();
+
+        IndexedTriangles {
+            vertices,
+            triangles,
+            color: self.color,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Point;
+
+    use crate::{
+        algorithms::approx::{Approx, Tolerance},
+        objects::{Face, Surface},
+    };
+
+    #[test]
+    fn deduplicates_vertices() -> anyhow::Result<()> {
+        let a = [0., 0.];
+        let b = [2., 0.];
+        let c = [2., 2.];
+        let d = [0., 1.];
+
+        let surface = Surface::xy_plane();
+        let face = Face::build(surface).polygon_from_points([a, b, c, d]);
+
+        let tolerance = Tolerance::from_scalar(1.)?;
+        let triangles = (&face).approx(tolerance).to_indexed_triangles();
+
+        assert_eq!(triangles.vertices.len(), 4);
+        assert_eq!(triangles.triangles.len(), 2);
+        assert_eq!(triangles.color, face.color());
+
+        let triangles = triangles
+            .triangles
+            .iter()
+            .map(|triangle| {
+                triangle.map(|index| triangles.vertices[index as usize])
+            })
+            .collect::<Vec<_>>();
+
+        let [a, b, c, d] =
+            [a, b, c, d].map(|point| Point::from(point).to_xyz());
+        assert!(triangles.iter().any(|&t| same_triangle(t, [a, b, d])));
+        assert!(triangles.iter().any(|&t| same_triangle(t, [b, c, d])));
+
+        Ok(())
+    }
+
+    fn same_triangle(a: [Point<3>; 3], b: [Point<3>; 3]) -> bool {
+        (0..3).any(|i| a[i..].iter().chain(&a[..i]).eq(b.iter()))
+    }
+}
@@ -0,0 +1,247 @@
+//! Triangulation with a maximum edge length
+//!
+//! See [`triangulate_with_max_edge_length`].
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use fj_interop::mesh::Mesh;
+use fj_math::{Point, Scalar};
+
+use crate::{
+    algorithms::approx::{curve::CurveCache, Approx, Tolerance},
+    objects::Face,
+};
+
+use super::triangulate_face;
+
+/// Triangulate faces, so that no triangle edge exceeds a maximum length
+///
+/// First approximates the faces using `tolerance`, which makes sure that
+/// curved regions are accurate. Then refines the triangulation of each face,
+/// by splitting edges that are longer than `max_edge_length` at their middle,
+/// until no such edges are left. This also refines planar faces, which a
+/// tolerance-based triangulation would leave as large triangles.
+///
+/// Edges on the boundary of a face are split at the middle of their straight
+/// segment, so faces that share an edge are split in the same way, and the
+/// resulting mesh stays watertight. Edges in the interior of a face are split
+/// at the point on the surface that corresponds to the middle of the edge.
+///
+/// # Panics
+///
+/// Panics, if `max_edge_length` is not positive.
+pub fn triangulate_with_max_edge_length<'r>(
+    faces: impl IntoIterator<Item = &'r Face>,
+    tolerance: impl Into<Tolerance>,
+    max_edge_length: impl Into<Scalar>,
+) -> Mesh<Point<3>> {
+    // Every split halves an edge, so this is enough for any reasonable ratio
+    // of face size to edge length.
+    const MAX_ITERATIONS: usize = 64;
+
+    let tolerance = tolerance.into();
+    let max_edge_length = max_edge_length.into();
+
+    assert!(
+        max_edge_length > Scalar::ZERO,
+        "Maximum edge length must be positive"
+    );
+
+    let mut cache = CurveCache::new();
+    let mut mesh = Mesh::new();
+
+    for face in faces {
+        let approx = face.approx_with_cache(tolerance, &mut cache);
+        let mut refinement = Refinement::default();
+
+        for triangle in triangulate_face(&approx) {
+            let triangle = triangle.map(|point| {
+                refinement.point(point.point_surface, point.point_global)
+            });
+            refinement.triangles.push(triangle);
+        }
+
+        let cycles = [&approx.exterior].into_iter().chain(&approx.interiors);
+        for cycle in cycles {
+            for segment in cycle.points().windows(2) {
+                let [a, b] = [&segment[0], &segment[1]].map(|point| {
+                    refinement.point(point.local_form, point.global_form)
+                });
+                refinement.boundary.insert(edge_key(a, b));
+            }
+        }
+
+        for _ in 0..MAX_ITERATIONS {
+            let is_done = refinement
+                .split_long_edges(max_edge_length, |point| {
+                    face.surface().point_from_surface_coords(point)
+                });
+            if is_done {
+                break;
+            }
+        }
+
+        for triangle in refinement.triangles {
+            let points = triangle.map(|i| refinement.points[i].1);
+            mesh.push_triangle(points, face.color());
+        }
+    }
+
+    mesh
+}
+
+#[derive(Default)]
+struct Refinement {
+    points: Vec<(Point<2>, Point<3>)>,
+    indices: BTreeMap<Point<2>, usize>,
+    triangles: Vec<[usize; 3]>,
+    boundary: BTreeSet<[usize; 2]>,
+}
+
+impl Refinement {
+    fn point(
+        &mut self,
+        point_surface: Point<2>,
+        point_global: Point<3>,
+    ) -> usize {
+        let points = &mut self.points;
+        *self.indices.entry(point_surface).or_insert_with(|| {
+            points.push((point_surface, point_global));
+            points.len() - 1
+        })
+    }
+
+    /// Split all edges that are too long; return `true`, if there were none
+    fn split_long_edges(
+        &mut self,
+        max_edge_length: Scalar,
+        point_from_surface_coords: impl Fn(Point<2>) -> Point<3>,
+    ) -> bool {
+        let mut midpoints = BTreeMap::new();
+
+        let triangles = self.triangles.clone();
+        for triangle in &triangles {
+            for i in 0..3 {
+                let [a, b] = [triangle[i], triangle[(i + 1) % 3]];
+                let key = edge_key(a, b);
+
+                if midpoints.contains_key(&key) {
+                    continue;
+                }
+
+                let [(a_surface, a_global), (b_surface, b_global)] =
+                    [self.points[key[0]], self.points[key[1]]];
+                if (b_global - a_global).magnitude() <= max_edge_length {
+                    continue;
+                }
+
+                let midpoint_surface = Point {
+                    coords: (a_surface.coords + b_surface.coords) / 2.,
+                };
+                let midpoint_global = if self.boundary.contains(&key) {
+                    Point {
+                        coords: (a_global.coords + b_global.coords) / 2.,
+                    }
+                } else {
+                    point_from_surface_coords(midpoint_surface)
+                };
+
+                let m = self.point(midpoint_surface, midpoint_global);
+                midpoints.insert(key, m);
+
+                if self.boundary.remove(&key) {
+                    self.boundary.insert(edge_key(key[0], m));
+                    self.boundary.insert(edge_key(m, key[1]));
+                }
+            }
+        }
+
+        if midpoints.is_empty() {
+            return true;
+        }
+
+        self.triangles.clear();
+        for triangle in triangles {
+            let splits = [0, 1, 2].map(|i| {
+                let key = edge_key(triangle[i], triangle[(i + 1) % 3]);
+                midpoints.get(&key).copied()
+            });
+            self.triangles.extend(split_triangle(triangle, splits));
+        }
+
+        false
+    }
+}
+
+/// Split a triangle at the midpoints of its edges
+///
+/// `splits[i]` is the midpoint of the edge from `triangle[i]` to
+/// `triangle[i + 1]`, if that edge is split. Preserves the orientation of the
+/// triangle.
+fn split_triangle(
+    triangle: [usize; 3],
+    splits: [Option<usize>; 3],
+) -> Vec<[usize; 3]> {
+    // Rotate the triangle, so the split edges come first. This doesn't change
+    // its orientation.
+    let num_splits = splits.iter().filter(|split| split.is_some()).count();
+    let rotation = (0..3)
+        .find(|&r| (0..num_splits).all(|i| splits[(r + i) % 3].is_some()))
+        .unwrap_or(0);
+    let [a, b, c] = [0, 1, 2].map(|i| triangle[(rotation + i) % 3]);
+    let [m0, m1, m2] = [0, 1, 2].map(|i| splits[(rotation + i) % 3]);
+
+    match (m0, m1, m2) {
+        (Some(m0), Some(m1), Some(m2)) => {
+            vec![[a, m0, m2], [m0, b, m1], [m2, m1, c], [m0, m1, m2]]
+        }
+        (Some(m0), Some(m1), None) => {
+            vec![[m0, b, m1], [a, m0, m1], [a, m1, c]]
+        }
+        (Some(m0), None, None) => vec![[a, m0, c], [m0, b, c]],
+        _ => vec![[a, b, c]],
+    }
+}
+
+fn edge_key(a: usize, b: usize) -> [usize; 2] {
+    if a < b {
+        [a, b]
+    } else {
+        [b, a]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::objects::{Face, Surface};
+
+    use super::triangulate_with_max_edge_length;
+
+    #[test]
+    fn flat_plate() {
+        let face = Face::build(Surface::xy_plane())
+            .polygon_from_points([[0., 0.], [2., 0.], [2., 1.], [0., 1.]])
+            .into_face();
+
+        let max_edge_length = Scalar::from(0.3);
+        let mesh =
+            triangulate_with_max_edge_length([&face], 0.1, max_edge_length);
+
+        assert!(mesh.triangles().count() > 2);
+
+        let mut area = Scalar::ZERO;
+        for triangle in mesh.triangles() {
+            let [a, b, c] = triangle.inner.points();
+
+            for [p, q] in [[a, b], [b, c], [c, a]] {
+                assert!((q - p).magnitude() <= max_edge_length);
+            }
+
+            area += (b - a).cross(&(c - a)).magnitude() / 2.;
+        }
+
+        assert!((area - Scalar::from(2.)).abs() < Scalar::from(1e-12));
+    }
+}
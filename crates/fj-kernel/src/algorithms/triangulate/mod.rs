@@ -1,6 +1,11 @@
 //! Shape triangulation
 
+mod budget;
 mod delaunay;
+mod ear_clipping;
+mod indexed;
+mod max_edge;
+mod planar;
 mod polygon;
 
 use fj_interop::mesh::Mesh;
@@ -8,6 +13,14 @@ use fj_math::Point;
 
 use self::{delaunay::TriangulationPoint, polygon::Polygon};
 
+pub use self::{
+    budget::{approx_to_budget, BudgetError},
+    ear_clipping::triangulate_simple_polygon,
+    indexed::IndexedTriangles,
+    max_edge::triangulate_with_max_edge_length,
+    planar::triangulate_2d,
+};
+
 use super::approx::{face::FaceApprox, Approx, Tolerance};
 
 /// Triangulate a shape
@@ -55,39 +68,43 @@ impl Triangulate for FaceApprox {
         _: impl Into<Tolerance>,
         mesh: &mut Mesh<Point<3>>,
     ) {
-        let points: Vec<_> = self
-            .points()
-            .into_iter()
-            .map(|point| TriangulationPoint {
-                point_surface: point.local_form,
-                point_global: point.global_form,
-            })
-            .collect();
-        let face_as_polygon = Polygon::new()
-            .with_exterior(
-                self.exterior
-                    .points()
-                    .into_iter()
-                    .map(|point| point.local_form),
-            )
-            .with_interiors(self.interiors.into_iter().map(|interior| {
-                interior.points().into_iter().map(|point| point.local_form)
-            }));
-
-        let mut triangles =
-            delaunay::triangulate(points, self.coord_handedness);
-        triangles.retain(|triangle| {
-            face_as_polygon
-                .contains_triangle(triangle.map(|point| point.point_surface))
-        });
-
-        for triangle in triangles {
+        for triangle in triangulate_face(&self) {
             let points = triangle.map(|point| point.point_global);
             mesh.push_triangle(points, self.color);
         }
     }
 }
 
+fn triangulate_face(approx: &FaceApprox) -> Vec<[TriangulationPoint; 3]> {
+    let points: Vec<_> = approx
+        .points()
+        .into_iter()
+        .map(|point| TriangulationPoint {
+            point_surface: point.local_form,
+            point_global: point.global_form,
+        })
+        .collect();
+    let face_as_polygon = Polygon::new()
+        .with_exterior(
+            approx
+                .exterior
+                .points()
+                .into_iter()
+                .map(|point| point.local_form),
+        )
+        .with_interiors(approx.interiors.iter().map(|interior| {
+            interior.points().into_iter().map(|point| point.local_form)
+        }));
+
+    let mut triangles = delaunay::triangulate(points, approx.coord_handedness);
+    triangles.retain(|triangle| {
+        face_as_polygon
+            .contains_triangle(triangle.map(|point| point.point_surface))
+    });
+
+    triangles
+}
+
 #[cfg(test)]
 mod tests {
     use fj_interop::mesh::Mesh;
@@ -0,0 +1,171 @@
+//! Triangulation of planar straight-line graphs
+//!
+//! See [`triangulate_2d`].
+
+use fj_math::{Point, Scalar};
+use spade::{
+    ConstrainedDelaunayTriangulation, HasPosition, Point2, Triangulation as _,
+};
+
+/// Triangulate a planar straight-line graph
+///
+/// Takes a list of points and a list of segments, each of which refers to two
+/// of those points by their index. The segments must form closed loops. Any
+/// number of loops is supported, as long as they don't intersect each other.
+///
+/// The region that is triangulated is determined by the even-odd rule: A
+/// triangle is part of the result, if a ray from its center crosses the
+/// segments an odd number of times. This means nested loops form holes, and
+/// the orientation of the loops doesn't matter.
+///
+/// Returns the triangles as indices into `points`. All triangles are wound
+/// counter-clockwise.
+///
+/// # Panics
+///
+/// Panics, if a segment refers to a point that doesn't exist, or if segments
+/// intersect each other.
+pub fn triangulate_2d(
+    points: &[Point<2>],
+    segments: &[[usize; 2]],
+) -> Vec<[usize; 3]> {
+    let mut triangulation =
+        ConstrainedDelaunayTriangulation::<IndexedPoint>::new();
+
+    let handles = points
+        .iter()
+        .enumerate()
+        .map(|(index, point)| {
+            triangulation
+                .insert(IndexedPoint {
+                    point: *point,
+                    index,
+                })
+                .expect("Inserted invalid point into triangulation")
+        })
+        .collect::<Vec<_>>();
+
+    for &[a, b] in segments {
+        let [a, b] = [handles[a], handles[b]];
+
+        assert!(
+            triangulation.can_add_constraint(a, b),
+            "Segments of planar graph intersect"
+        );
+        triangulation.add_constraint(a, b);
+    }
+
+    let mut triangles = Vec::new();
+    for face in triangulation.inner_faces() {
+        let [a, b, c] = face.vertices().map(|vertex| *vertex.data());
+
+        let center = Point {
+            coords: (a.point.coords + b.point.coords + c.point.coords) / 3.,
+        };
+        if !is_inside(center, points, segments) {
+            continue;
+        }
+
+        let is_ccw =
+            (b.point - a.point).cross(&(c.point - a.point)) > Scalar::ZERO;
+        let triangle = if is_ccw {
+            [a.index, b.index, c.index]
+        } else {
+            [a.index, c.index, b.index]
+        };
+
+        triangles.push(triangle);
+    }
+
+    triangles
+}
+
+fn is_inside(
+    point: Point<2>,
+    points: &[Point<2>],
+    segments: &[[usize; 2]],
+) -> bool {
+    let mut num_crossings = 0;
+
+    // Cast a ray from the point into the positive u-direction, and count the
+    // segments it crosses.
+    for &[a, b] in segments {
+        let [a, b] = [points[a], points[b]];
+
+        // Count segments that touch the ray with their lower end, but not
+        // those that touch it with their upper end. That way, a ray that
+        // passes through a vertex between two segments is counted once.
+        if (a.v > point.v) == (b.v > point.v) {
+            continue;
+        }
+
+        let u = a.u + (point.v - a.v) / (b.v - a.v) * (b.u - a.u);
+        if u > point.u {
+            num_crossings += 1;
+        }
+    }
+
+    num_crossings % 2 == 1
+}
+
+#[derive(Clone, Copy, Debug)]
+struct IndexedPoint {
+    point: Point<2>,
+    index: usize,
+}
+
+impl HasPosition for IndexedPoint {
+    type Scalar = f64;
+
+    fn position(&self) -> Point2<Self::Scalar> {
+        Point2::new(self.point.u.into_f64(), self.point.v.into_f64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar, Triangle};
+
+    use super::triangulate_2d;
+
+    #[test]
+    fn square_with_hole() {
+        let points = [
+            [0., 0.],
+            [4., 0.],
+            [4., 4.],
+            [0., 4.],
+            [1., 1.],
+            [3., 1.],
+            [3., 3.],
+            [1., 3.],
+        ]
+        .map(Point::from);
+        let segments = [
+            [0, 1],
+            [1, 2],
+            [2, 3],
+            [3, 0],
+            [4, 5],
+            [5, 6],
+            [6, 7],
+            [7, 4],
+        ];
+
+        let triangles = triangulate_2d(&points, &segments);
+        assert_eq!(triangles.len(), 8);
+
+        let mut area = Scalar::ZERO;
+        for [a, b, c] in triangles {
+            let triangle =
+                Triangle::from_points([points[a], points[b], points[c]])
+                    .expect("Expected valid triangle");
+            let [a, b, c] = triangle.points();
+            area += (b - a).cross(&(c - a)) / 2.;
+        }
+
+        // All triangles are wound counter-clockwise, and the hole is not
+        // covered.
+        assert_eq!(area, Scalar::from(12.));
+    }
+}
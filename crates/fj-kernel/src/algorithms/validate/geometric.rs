@@ -0,0 +1,89 @@
+use std::fmt;
+
+use fj_math::{Point, Scalar};
+
+use crate::objects::Cycle;
+
+pub fn validate_cycle(
+    cycle: &Cycle,
+    max_distance: impl Into<Scalar>,
+) -> Result<(), GeometricIssues> {
+    let max_distance = max_distance.into();
+
+    // `Cycle` makes sure that the surface forms of connecting vertices are
+    // identical. That doesn't mean the curves of the half-edges actually meet
+    // there, so compute the end points from the curves instead.
+    let half_edges = cycle.half_edges().collect::<Vec<_>>();
+
+    for (i, half_edge) in half_edges.iter().enumerate() {
+        let next = half_edges[(i + 1) % half_edges.len()];
+
+        let [_, end] = half_edge.vertices();
+        let [start, _] = next.vertices();
+
+        let end = half_edge
+            .curve()
+            .path()
+            .point_from_path_coords(end.position());
+        let start =
+            next.curve().path().point_from_path_coords(start.position());
+
+        let distance = (end - start).magnitude();
+
+        if distance > max_distance {
+            Err(CycleNotClosed {
+                index: i,
+                end,
+                start,
+                distance,
+                cycle: cycle.clone(),
+            })?
+        }
+    }
+
+    Ok(())
+}
+
+/// Issues in geometric validation
+#[derive(Debug, thiserror::Error)]
+pub enum GeometricIssues {
+    /// The half-edges of a cycle don't form a closed loop
+    #[error("Half-edges of cycle don't form a closed loop")]
+    CycleNotClosed(#[from] CycleNotClosed),
+}
+
+/// The half-edges of a cycle don't form a closed loop
+///
+/// Describes the first place where a half-edge doesn't end where the next one
+/// starts. Used in [`GeometricIssues`].
+#[derive(Debug, thiserror::Error)]
+pub struct CycleNotClosed {
+    /// The index of the half-edge whose end doesn't connect to the next one
+    ///
+    /// The index of the next half-edge is `index + 1`, wrapping around to `0`
+    /// for the last half-edge of the cycle.
+    pub index: usize,
+
+    /// The end of the half-edge, in surface coordinates
+    pub end: Point<2>,
+
+    /// The start of the next half-edge, in surface coordinates
+    pub start: Point<2>,
+
+    /// The distance between `end` and `start`
+    pub distance: Scalar,
+
+    /// The cycle that isn't closed
+    pub cycle: Cycle,
+}
+
+impl fmt::Display for CycleNotClosed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "half-edge {} ends at {:?}, but next half-edge starts at {:?} \
+            (distance: {:?})",
+            self.index, self.end, self.start, self.distance,
+        )
+    }
+}
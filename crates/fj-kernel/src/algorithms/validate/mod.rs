@@ -15,11 +15,15 @@
 //! implemented, as of this writing.
 
 mod coherence;
+mod geometric;
 mod uniqueness;
 
 pub use self::{
     coherence::{CoherenceIssues, VertexCoherenceMismatch},
-    uniqueness::UniquenessIssues,
+    geometric::{CycleNotClosed, GeometricIssues},
+    uniqueness::{
+        find_coincident_vertices, CoincidentVertices, UniquenessIssues,
+    },
 };
 
 use std::{collections::HashSet, ops::Deref};
@@ -79,6 +83,12 @@ where
         for vertex in self.vertex_iter() {
             coherence::validate_vertex(vertex, config.identical_max_distance)?;
         }
+        for cycle in self.cycle_iter() {
+            // Curves are approximated by floating-point numbers, so where two
+            // of them meet can't be computed exactly. Use the distance below
+            // which vertices are considered identical.
+            geometric::validate_cycle(cycle, config.distinct_min_distance)?;
+        }
 
         Ok(Validated(self))
     }
@@ -147,7 +157,7 @@ pub enum ValidationError {
 
     /// Geometric validation failed
     #[error("Geometric validation failed")]
-    Geometric,
+    Geometric(#[from] GeometricIssues),
 
     /// Uniqueness validation failed
     #[error("Uniqueness validation failed")]
@@ -159,10 +169,16 @@ mod tests {
     use fj_math::{Line, Point, Scalar};
 
     use crate::{
-        algorithms::validate::{Validate, ValidationConfig, ValidationError},
+        algorithms::{
+            transform::TransformObject,
+            validate::{
+                find_coincident_vertices, Validate, ValidationConfig,
+                ValidationError,
+            },
+        },
         objects::{
-            Curve, GlobalCurve, GlobalVertex, HalfEdge, Surface, SurfaceVertex,
-            Vertex,
+            Curve, Cycle, GlobalCurve, GlobalVertex, HalfEdge, Shell, Surface,
+            SurfaceVertex, Vertex,
         },
         path::{GlobalPath, SurfacePath},
     };
@@ -240,6 +256,51 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn geometric_cycle() {
+        let surface = Surface::xy_plane();
+
+        let cycle = Cycle::build(surface).polygon_from_points([
+            [0., 0.],
+            [1., 0.],
+            [1., 1.],
+            [0., 1.],
+        ]);
+        assert!(cycle.clone().validate().is_ok());
+
+        // Replace the first half-edge with one that is shifted along the
+        // y-axis. Its vertices still refer to the same surface vertices as the
+        // neighboring half-edges, but its curve no longer meets theirs.
+        let mut half_edges = cycle.half_edges().cloned().collect::<Vec<_>>();
+        let shifted = {
+            let curve =
+                Curve::build(surface).line_from_points([[0., 0.5], [1., 0.5]]);
+            let [a, b] = half_edges[0].vertices();
+
+            let vertices = [(a, 0.), (b, 1.)].map(|(vertex, position)| {
+                let global_form = GlobalVertex::from_position(
+                    curve
+                        .global_form()
+                        .path()
+                        .point_from_path_coords([position]),
+                );
+                Vertex::new(
+                    [position],
+                    curve,
+                    *vertex.surface_form(),
+                    global_form,
+                )
+            });
+
+            HalfEdge::from_curve_and_vertices(curve, vertices)
+        };
+        half_edges[0] = shifted;
+        let cycle = Cycle::new(surface, half_edges);
+
+        let result = cycle.validate();
+        assert!(matches!(result, Err(ValidationError::Geometric(_))));
+    }
+
     #[test]
     fn uniqueness_vertex() -> anyhow::Result<()> {
         let mut shape = Vec::new();
@@ -267,4 +328,41 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn uniqueness_coincident_vertices() {
+        let cube = Shell::build().cube_from_edge_length(1.);
+        assert_eq!(find_coincident_vertices(&cube, 1e-3), []);
+
+        // Move the top face up a tiny bit. Its vertices are now separate from
+        // those of the side faces, but too close to them to be intended.
+        let faces = cube.into_faces().into_iter().map(|face| {
+            let is_top = face
+                .exterior()
+                .vertices()
+                .all(|vertex| vertex.global_form().position().z > Scalar::ZERO);
+
+            if is_top {
+                face.translate([0., 0., 1e-4])
+            } else {
+                face
+            }
+        });
+        let shell = Shell::new().with_faces(faces);
+
+        // The offset is too large to be caught by regular validation.
+        assert!(shell.clone().validate().is_ok());
+
+        let coincident = find_coincident_vertices(&shell, 1e-3);
+        assert_eq!(coincident.len(), 4);
+        for pair in coincident {
+            let [a, b] = pair.vertices.map(|vertex| vertex.position());
+            assert_eq!(a.x, b.x);
+            assert_eq!(a.y, b.y);
+            assert!(
+                (pair.distance - Scalar::from(1e-4)).abs()
+                    < Scalar::from(1e-12)
+            );
+        }
+    }
 }
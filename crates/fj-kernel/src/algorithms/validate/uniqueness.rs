@@ -2,7 +2,10 @@ use std::{collections::HashSet, fmt};
 
 use fj_math::Scalar;
 
-use crate::objects::GlobalVertex;
+use crate::{
+    iter::ObjectIters,
+    objects::{GlobalVertex, Shell},
+};
 
 pub fn validate_vertex(
     vertex: &GlobalVertex,
@@ -21,6 +24,72 @@ pub fn validate_vertex(
     Ok(())
 }
 
+/// Find distinct global vertices of a shell that are close to each other
+///
+/// Returns all pairs of [`GlobalVertex`]es of `shell` that are separate
+/// objects, but closer to each other than `max_distance`. Those are often two
+/// vertices that should have been merged, but weren't.
+///
+/// This is not part of [`Validate`], and needs to be called explicitly. Its
+/// findings are only meant as warnings. Coincident vertices are not always a
+/// problem (for example, sweeping a circle produces vertices whose global forms
+/// are coincident), and a `max_distance` that is much larger than
+/// [`ValidationConfig::distinct_min_distance`] will report vertices that are
+/// legitimately close to each other.
+///
+/// [`Validate`]: super::Validate
+/// [`ValidationConfig::distinct_min_distance`]: super::ValidationConfig::distinct_min_distance
+pub fn find_coincident_vertices(
+    shell: &Shell,
+    max_distance: impl Into<Scalar>,
+) -> Vec<CoincidentVertices> {
+    let max_distance = max_distance.into();
+
+    let vertices = shell.global_vertex_iter().collect::<Vec<_>>();
+    let mut coincident = Vec::new();
+
+    for (i, a) in vertices.iter().enumerate() {
+        for b in &vertices[i + 1..] {
+            let distance = a.distance_to(b);
+
+            if distance < max_distance {
+                coincident.push(CoincidentVertices {
+                    vertices: [**a, **b],
+                    distance,
+                });
+            }
+        }
+    }
+
+    coincident
+}
+
+/// Two distinct global vertices that are close to each other
+///
+/// Returned by [`find_coincident_vertices`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct CoincidentVertices {
+    /// The coincident vertices
+    pub vertices: [GlobalVertex; 2],
+
+    /// The distance between the vertices
+    pub distance: Scalar,
+}
+
+impl fmt::Display for CoincidentVertices {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let [a, b] = self.vertices;
+        write!(
+            f,
+            "Distinct vertices at {:?} and {:?} are coincident (distance: \
+            {:?})",
+            a.position(),
+            b.position(),
+            self.distance,
+        )
+    }
+}
+
 /// Uniqueness issues found during validation
 ///
 /// Used by [`ValidationError`].
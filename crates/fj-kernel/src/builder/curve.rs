@@ -1,4 +1,4 @@
-use fj_math::{Line, Point, Scalar, Vector};
+use fj_math::{Circle, Line, Point, Scalar, Vector};
 
 use crate::{
     objects::{Curve, GlobalCurve, Surface},
@@ -35,13 +35,34 @@ impl CurveBuilder {
     }
 
     /// Build a circle from the given radius
+    ///
+    /// The circle is centered at the origin of the surface. See
+    /// [`Self::circle_from_center_and_radius`].
     pub fn circle_from_radius(&self, radius: impl Into<Scalar>) -> Curve {
-        let radius = radius.into();
+        self.circle_from_center_and_radius(Point::origin(), radius)
+    }
 
-        let path = SurfacePath::circle_from_radius(radius);
-        let global_form = GlobalCurveBuilder.circle_from_radius(radius);
+    /// Build a circle from the given center and radius
+    ///
+    /// Circle coordinates are measured counter-clockwise from the u-axis of
+    /// the surface, like for a circle built with [`Self::circle_from_radius`].
+    pub fn circle_from_center_and_radius(
+        &self,
+        center: impl Into<Point<2>>,
+        radius: impl Into<Scalar>,
+    ) -> Curve {
+        let local = Circle::from_center_and_radius(center, radius);
+        let global = Circle::new(
+            self.surface.point_from_surface_coords(local.center()),
+            self.surface.vector_from_surface_coords(local.a()),
+            self.surface.vector_from_surface_coords(local.b()),
+        );
 
-        Curve::new(self.surface, path, global_form)
+        Curve::new(
+            self.surface,
+            SurfacePath::Circle(local),
+            GlobalCurve::from_path(GlobalPath::Circle(global)),
+        )
     }
 
     /// Build a line from the given points
@@ -1,4 +1,4 @@
-use fj_math::Point;
+use fj_math::{signed_area, Point, Scalar};
 
 use crate::objects::{Cycle, HalfEdge, Surface};
 
@@ -42,4 +42,22 @@ impl CycleBuilder {
 
         Cycle::new(self.surface, half_edges)
     }
+
+    /// Create a counter-clockwise polygon from a list of points
+    ///
+    /// Reverses the points, if they are wound clockwise. Returns the cycle, and
+    /// whether the points have been reversed.
+    pub fn ccw_polygon_from_points(
+        &self,
+        points: impl IntoIterator<Item = impl Into<Point<2>>>,
+    ) -> (Cycle, bool) {
+        let mut points: Vec<_> = points.into_iter().map(Into::into).collect();
+
+        let is_reversed = signed_area(&points) < Scalar::ZERO;
+        if is_reversed {
+            points.reverse();
+        }
+
+        (self.polygon_from_points(points), is_reversed)
+    }
 }
@@ -1,9 +1,9 @@
-use fj_math::{Line, Point, Scalar};
+use fj_math::{Circle, Line, Point, Scalar};
 
 use crate::{
     objects::{
-        Curve, GlobalCurve, GlobalVertex, HalfEdge, Surface, SurfaceVertex,
-        Vertex,
+        Curve, GlobalCurve, GlobalEdge, GlobalVertex, HalfEdge, Surface,
+        SurfaceVertex, Vertex,
     },
     path::{GlobalPath, SurfacePath},
 };
@@ -22,8 +22,21 @@ impl HalfEdgeBuilder {
     }
 
     /// Build a circle from the given radius
+    ///
+    /// The circle is centered at the origin of the surface. See
+    /// [`Self::circle_from_center_and_radius`].
     pub fn circle_from_radius(&self, radius: impl Into<Scalar>) -> HalfEdge {
-        let curve = Curve::build(self.surface).circle_from_radius(radius);
+        self.circle_from_center_and_radius(Point::origin(), radius)
+    }
+
+    /// Build a circle from the given center and radius
+    pub fn circle_from_center_and_radius(
+        &self,
+        center: impl Into<Point<2>>,
+        radius: impl Into<Scalar>,
+    ) -> HalfEdge {
+        let curve = Curve::build(self.surface)
+            .circle_from_center_and_radius(center, radius);
 
         let vertices = {
             let [a_curve, b_curve] =
@@ -57,6 +70,59 @@ impl HalfEdgeBuilder {
         HalfEdge::from_curve_and_vertices(curve, vertices)
     }
 
+    /// Build a circular arc from its center, radius, and angles
+    ///
+    /// The arc starts at `start_angle` and ends at `end_angle`, both given in
+    /// radians and measured counter-clockwise from the u-axis of the surface.
+    /// If `end_angle` is smaller than `start_angle`, the arc runs clockwise.
+    ///
+    /// The global positions of the vertices are computed from their positions
+    /// in surface coordinates, like [`Self::line_segment_from_points`] does.
+    /// This means an arc and a line segment that start or end at the same
+    /// surface position share that vertex exactly.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the angles are equal, or if they span more than a full
+    /// circle. Use [`Self::circle_from_radius`] to build a full circle.
+    pub fn arc_from_center_and_radius(
+        &self,
+        center: impl Into<Point<2>>,
+        radius: impl Into<Scalar>,
+        start_angle: impl Into<Scalar>,
+        end_angle: impl Into<Scalar>,
+    ) -> HalfEdge {
+        let center = center.into();
+        let radius = radius.into();
+        let angles = [start_angle.into(), end_angle.into()];
+
+        let [start, end] = angles;
+        let span = (end - start).abs();
+        assert!(
+            span > Scalar::ZERO && span < Scalar::TAU,
+            "Arc must span more than nothing and less than a full circle"
+        );
+
+        let circle = Circle::from_center_and_radius(center, radius);
+        let curve = Curve::build(self.surface)
+            .circle_from_center_and_radius(center, radius);
+
+        let vertices = angles.map(|angle| {
+            let point_curve = Point::from([angle]);
+            let point_surface = circle.point_from_circle_coords(point_curve);
+
+            let global_form = GlobalVertex::from_position(
+                self.surface.point_from_surface_coords(point_surface),
+            );
+            let surface_form =
+                SurfaceVertex::new(point_surface, self.surface, global_form);
+
+            Vertex::new(point_curve, curve, surface_form, global_form)
+        });
+
+        HalfEdge::from_curve_and_vertices(curve, vertices)
+    }
+
     /// Build a line segment from two points
     pub fn line_segment_from_points(
         &self,
@@ -111,3 +177,227 @@ impl HalfEdgeBuilder {
         HalfEdge::from_curve_and_vertices(curve, vertices)
     }
 }
+
+/// API for building a [`GlobalEdge`]
+///
+/// Also see [`GlobalEdge::build`].
+pub struct GlobalEdgeBuilder;
+
+impl GlobalEdgeBuilder {
+    /// Build a line segment from two points
+    pub fn line_from_points(
+        &self,
+        points: [impl Into<Point<3>>; 2],
+    ) -> GlobalEdge {
+        let points = points.map(Into::into);
+
+        let curve = GlobalCurve::build().line_from_points(points);
+        let vertices = points.map(GlobalVertex::from_position);
+
+        GlobalEdge::new(curve, vertices)
+    }
+
+    /// Build a circular arc that starts at `a`, passes `mid`, and ends at `b`
+    ///
+    /// The arc is defined by a circle, which starts at `a` (circle coordinate
+    /// `0`), and runs through `mid` towards `b`. The position of the vertex at
+    /// `b` is computed from the circle, which means it might deviate very
+    /// slightly from the provided point, due to floating point accuracy.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the three points are collinear, as there is no circle that
+    /// runs through them in that case.
+    pub fn arc_from_points(
+        &self,
+        a: impl Into<Point<3>>,
+        mid: impl Into<Point<3>>,
+        b: impl Into<Point<3>>,
+    ) -> GlobalEdge {
+        let [a, mid, b] = [a.into(), mid.into(), b.into()];
+
+        let u = mid - a;
+        let v = b - a;
+        let normal = u.cross(&v);
+
+        assert_ne!(
+            normal.magnitude(),
+            Scalar::ZERO,
+            "Can't build arc from collinear points"
+        );
+
+        // The circumcenter of the triangle `a`, `mid`, `b`.
+        let center = a
+            + (v.cross(&normal) * u.dot(&u) + normal.cross(&u) * v.dot(&v))
+                / (normal.dot(&normal) * 2.);
+
+        // The normal points into the direction, from which the points appear
+        // in counter-clockwise order. Choosing the circle's `b` vector
+        // accordingly, means that the circle runs from `a` through `mid`.
+        let circle_a = a - center;
+        let circle_b = normal.normalize().cross(&circle_a);
+        let circle = Circle::new(center, circle_a, circle_b);
+
+        let end = {
+            let b = b - center;
+            let angle = Scalar::atan2(b.dot(&circle_b), b.dot(&circle_a));
+
+            if angle > Scalar::ZERO {
+                angle
+            } else {
+                angle + Scalar::TAU
+            }
+        };
+
+        let vertices = [Scalar::ZERO, end].map(|coord| {
+            let position = circle.point_from_circle_coords([coord]);
+            GlobalVertex::from_position(position)
+        });
+        let curve = GlobalCurve::from_path(GlobalPath::Circle(circle));
+
+        GlobalEdge::new(curve, vertices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        objects::{GlobalEdge, HalfEdge, Surface},
+        path::{GlobalPath, SurfacePath},
+    };
+
+    #[test]
+    fn arc_from_center_and_radius() {
+        let surface = Surface::xz_plane();
+        let half_edge = HalfEdge::build(surface).arc_from_center_and_radius(
+            [1., 1.],
+            2.,
+            0.,
+            Scalar::PI / 2.,
+        );
+
+        match half_edge.curve().path() {
+            SurfacePath::Circle(circle) => {
+                assert_eq!(circle.center(), Point::from([1., 1.]));
+                assert_eq!(circle.radius(), Scalar::from(2.));
+            }
+            SurfacePath::Line(_) => panic!("Expected circle"),
+        }
+
+        let [a, b] = half_edge
+            .vertices()
+            .map(|vertex| vertex.surface_form().position());
+        assert_eq!(a, Point::from([3., 1.]));
+        assert!((b - Point::from([1., 3.])).magnitude() < Scalar::from(1e-12));
+
+        // The global form matches the surface form.
+        for vertex in half_edge.vertices() {
+            assert_eq!(
+                vertex.global_form().position(),
+                surface.point_from_surface_coords(
+                    vertex.surface_form().position()
+                )
+            );
+            assert!(
+                (half_edge
+                    .curve()
+                    .global_form()
+                    .path()
+                    .point_from_path_coords(vertex.position())
+                    - vertex.global_form().position())
+                .magnitude()
+                    < Scalar::from(1e-12)
+            );
+        }
+    }
+
+    #[test]
+    fn circle_from_center_and_radius() {
+        let surface = Surface::xz_plane();
+        let half_edge = HalfEdge::build(surface)
+            .circle_from_center_and_radius([1., 2.], 3.);
+
+        match half_edge.curve().path() {
+            SurfacePath::Circle(circle) => {
+                assert_eq!(circle.center(), Point::from([1., 2.]));
+                assert_eq!(circle.radius(), Scalar::from(3.));
+            }
+            SurfacePath::Line(_) => panic!("Expected circle"),
+        }
+        match half_edge.curve().global_form().path() {
+            GlobalPath::Circle(circle) => {
+                assert_eq!(circle.center(), Point::from([1., 0., 2.]));
+                assert_eq!(circle.radius(), Scalar::from(3.));
+            }
+            GlobalPath::Line(_) => panic!("Expected circle"),
+        }
+
+        // The circle starts in the direction of the u-axis of the surface, and
+        // both vertices are at that start.
+        for vertex in half_edge.vertices() {
+            let distance = (vertex.surface_form().position()
+                - Point::from([4., 2.]))
+            .magnitude();
+            assert!(distance < Scalar::from(1e-12));
+            assert_eq!(
+                vertex.global_form().position(),
+                Point::from([4., 0., 2.])
+            );
+        }
+    }
+
+    #[test]
+    fn line_from_points() {
+        let edge =
+            GlobalEdge::build().line_from_points([[1., 2., 3.], [4., 5., 6.]]);
+
+        let [a, b] = edge.vertices().map(|vertex| vertex.position());
+        assert_eq!(a, Point::from([1., 2., 3.]));
+        assert_eq!(b, Point::from([4., 5., 6.]));
+
+        let path = edge.curve().path();
+        assert_eq!(path.point_from_path_coords([0.]), a);
+        assert_eq!(path.point_from_path_coords([1.]), b);
+    }
+
+    #[test]
+    fn arc_from_points() {
+        let edge = GlobalEdge::build().arc_from_points(
+            [1., 0., 1.],
+            [0., 1., 1.],
+            [-1., 0., 1.],
+        );
+
+        let circle = match edge.curve().path() {
+            GlobalPath::Circle(circle) => circle,
+            GlobalPath::Line(_) => panic!("Expected circle"),
+        };
+        assert_eq!(circle.center(), Point::from([0., 0., 1.]));
+        assert_eq!(circle.radius(), Scalar::ONE);
+
+        let [a, b] = edge.vertices().map(|vertex| vertex.position());
+        assert_eq!(a, Point::from([1., 0., 1.]));
+        assert!(
+            (b - Point::from([-1., 0., 1.])).magnitude() < Scalar::from(1e-12)
+        );
+
+        // The arc runs from `a` through `mid`, which is a quarter turn in.
+        let mid = circle.point_from_circle_coords([Scalar::PI / 2.]);
+        assert!(
+            (mid - Point::from([0., 1., 1.])).magnitude() < Scalar::from(1e-12)
+        );
+
+        // The same points in reverse order result in the opposite direction.
+        let edge = GlobalEdge::build().arc_from_points(
+            [-1., 0., 1.],
+            [0., 1., 1.],
+            [1., 0., 1.],
+        );
+        let [_, b] = edge.vertices().map(|vertex| vertex.position());
+        assert!(
+            (b - Point::from([1., 0., 1.])).magnitude() < Scalar::from(1e-12)
+        );
+    }
+}
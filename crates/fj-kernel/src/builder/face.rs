@@ -1,8 +1,15 @@
 use std::ops::Deref;
 
-use fj_math::Point;
+use fj_interop::mesh::Color;
+use fj_math::{signed_area, Line, Point, Scalar};
 
-use crate::objects::{Cycle, Face, Surface};
+use crate::{
+    objects::{
+        Curve, Cycle, Face, GlobalCurve, GlobalVertex, HalfEdge, Surface,
+        SurfaceVertex, Vertex,
+    },
+    path::{GlobalPath, SurfacePath},
+};
 
 /// API for building a [`Face`]
 pub struct FaceBuilder {
@@ -18,14 +25,20 @@ impl FaceBuilder {
     }
 
     /// Construct a polygon from a list of points
+    ///
+    /// The exterior of the polygon is always wound counter-clockwise, so the
+    /// face points in the direction of its surface's normal. If the points
+    /// are wound clockwise, they are reversed. Use
+    /// [`FacePolygon::is_reversed`] to find out whether that happened.
     pub fn polygon_from_points(
         &self,
         points: impl IntoIterator<Item = impl Into<Point<2>>>,
     ) -> FacePolygon {
-        let cycle = Cycle::build(self.surface).polygon_from_points(points);
+        let (cycle, is_reversed) =
+            Cycle::build(self.surface).ccw_polygon_from_points(points);
         let face = Face::new(self.surface, cycle);
 
-        FacePolygon { face }
+        FacePolygon { face, is_reversed }
     }
 }
 
@@ -33,15 +46,25 @@ impl FaceBuilder {
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct FacePolygon {
     face: Face,
+    is_reversed: bool,
 }
 
 impl FacePolygon {
     /// Add a hole to the polygon
+    ///
+    /// Holes are always wound clockwise, opposite to the exterior. If the
+    /// points are wound counter-clockwise, they are reversed.
     pub fn with_hole(
         mut self,
         points: impl IntoIterator<Item = impl Into<Point<2>>>,
     ) -> Self {
         let surface = *self.face.surface();
+
+        let mut points: Vec<_> = points.into_iter().map(Into::into).collect();
+        if signed_area(&points) > Scalar::ZERO {
+            points.reverse();
+        }
+
         self.face = self.face.with_interiors([
             Cycle::build(surface).polygon_from_points(points)
         ]);
@@ -49,6 +72,13 @@ impl FacePolygon {
         self
     }
 
+    /// Indicate whether the points of the exterior have been reversed
+    ///
+    /// See [`FaceBuilder::polygon_from_points`].
+    pub fn is_reversed(&self) -> bool {
+        self.is_reversed
+    }
+
     /// Consume the `Polygon` and return the [`Face`] it wraps
     pub fn into_face(self) -> Face {
         self.face
@@ -68,3 +98,102 @@ impl Deref for FacePolygon {
         &self.face
     }
 }
+
+/// Create a triangular face from the given points
+///
+/// The global vertices of the face are created directly from the points, which
+/// makes sure they are identical to those of neighboring faces that were
+/// created from the same points.
+pub(crate) fn triangle_from_points(
+    points: [Point<3>; 3],
+    color: Color,
+) -> Face {
+    let surface = Surface::plane_from_points(points);
+
+    let points_surface = [[0., 0.], [1., 0.], [0., 1.]].map(Point::from);
+    let vertices = [0, 1, 2].map(|i| {
+        let global_form = GlobalVertex::from_position(points[i]);
+        SurfaceVertex::new(points_surface[i], surface, global_form)
+    });
+
+    let half_edges = [[0, 1], [1, 2], [2, 0]].map(|[i, j]| {
+        let curve = Curve::new(
+            surface,
+            SurfacePath::Line(Line::from_points([
+                points_surface[i],
+                points_surface[j],
+            ])),
+            GlobalCurve::from_path(GlobalPath::Line(Line::from_points([
+                points[i], points[j],
+            ]))),
+        );
+
+        let [a, b] = [(0., vertices[i]), (1., vertices[j])].map(
+            |(position, surface_form)| {
+                Vertex::new(
+                    [position],
+                    curve,
+                    surface_form,
+                    *surface_form.global_form(),
+                )
+            },
+        );
+
+        HalfEdge::from_curve_and_vertices(curve, [a, b])
+    });
+
+    Face::new(surface, Cycle::new(surface, half_edges)).with_color(color)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Winding};
+
+    use crate::objects::{Face, Surface};
+
+    #[test]
+    fn polygon_is_counter_clockwise() {
+        let surface = Surface::xy_plane();
+        let points = [[0., 0.], [1., 0.], [0., 1.]];
+
+        let ccw = Face::build(surface).polygon_from_points(points);
+        let cw =
+            Face::build(surface).polygon_from_points(points.into_iter().rev());
+
+        assert!(!ccw.is_reversed());
+        assert!(cw.is_reversed());
+
+        for face in [ccw.into_face(), cw.into_face()] {
+            assert_eq!(face.exterior().winding(), Winding::Ccw);
+
+            let mut vertices = face
+                .exterior()
+                .vertices()
+                .map(|vertex| vertex.surface_form().position())
+                .collect::<Vec<_>>();
+            vertices.sort();
+            assert_eq!(
+                vertices,
+                [[0., 0.], [0., 1.], [1., 0.]].map(Point::from)
+            );
+        }
+    }
+
+    #[test]
+    fn hole_is_clockwise() {
+        let surface = Surface::xy_plane();
+        let exterior = [[0., 0.], [3., 0.], [3., 3.], [0., 3.]];
+        let hole = [[1., 1.], [2., 1.], [2., 2.], [1., 2.]];
+
+        for hole in [hole, [hole[3], hole[2], hole[1], hole[0]]] {
+            let face = Face::build(surface)
+                .polygon_from_points(exterior)
+                .with_hole(hole)
+                .into_face();
+
+            for interior in face.interiors() {
+                assert_eq!(interior.winding(), Winding::Cw);
+            }
+        }
+    }
+}
@@ -12,10 +12,12 @@ mod vertex;
 pub use self::{
     curve::{CurveBuilder, GlobalCurveBuilder},
     cycle::CycleBuilder,
-    edge::HalfEdgeBuilder,
+    edge::{GlobalEdgeBuilder, HalfEdgeBuilder},
     face::{FaceBuilder, FacePolygon},
     shell::ShellBuilder,
     sketch::SketchBuilder,
-    solid::SolidBuilder,
+    solid::{BoxBuilder, BoxSide, SolidBuilder},
     vertex::VertexBuilder,
 };
+
+pub(crate) use self::face::triangle_from_points;
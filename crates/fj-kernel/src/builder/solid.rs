@@ -1,6 +1,8 @@
-use fj_math::Scalar;
+use std::collections::BTreeSet;
 
-use crate::objects::{Shell, Solid};
+use fj_math::{Point, Scalar, Vector};
+
+use crate::objects::{Face, Shell, Solid, Surface};
 
 /// API for building a [`Solid`]
 pub struct SolidBuilder;
@@ -14,4 +16,416 @@ impl SolidBuilder {
         let shell = Shell::build().cube_from_edge_length(edge_length);
         Solid::new().with_shells([shell])
     }
+
+    /// Create a box from its size along the x, y, and z axes
+    ///
+    /// Returns a [`BoxBuilder`], which can be used to make the box hollow, and
+    /// to open some of its sides.
+    pub fn box_from_size(&self, size: [impl Into<Scalar>; 3]) -> BoxBuilder {
+        BoxBuilder {
+            size: size.map(Into::into),
+            wall_thickness: Scalar::ZERO,
+            open_sides: BTreeSet::new(),
+        }
+    }
+}
+
+/// API for building a box-shaped [`Solid`]
+///
+/// Also see [`SolidBuilder::box_from_size`].
+pub struct BoxBuilder {
+    size: [Scalar; 3],
+    wall_thickness: Scalar,
+    open_sides: BTreeSet<BoxSide>,
+}
+
+impl BoxBuilder {
+    /// Make the box hollow, with walls of the given thickness
+    ///
+    /// A wall thickness of zero (the default) results in a solid box.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `thickness` is negative.
+    pub fn with_wall_thickness(mut self, thickness: impl Into<Scalar>) -> Self {
+        let thickness = thickness.into();
+        assert!(
+            thickness >= Scalar::ZERO,
+            "Wall thickness must not be negative"
+        );
+
+        self.wall_thickness = thickness;
+        self
+    }
+
+    /// Open the given sides of the box
+    ///
+    /// If the box is hollow, an open side leaves a rim the width of the wall
+    /// thickness. If it is solid, the open side is just left out, resulting in
+    /// a shell that is not closed.
+    pub fn with_open_sides(
+        mut self,
+        sides: impl IntoIterator<Item = BoxSide>,
+    ) -> Self {
+        self.open_sides.extend(sides);
+        self
+    }
+
+    /// Build the box
+    ///
+    /// The box is centered on the origin. If it is hollow and none of its
+    /// sides are open, the solid has two shells: The outside of the box, and
+    /// the inside of its cavity. Otherwise, it has a single shell.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the box is hollow, and its walls are so thick that no space
+    /// is left for the cavity.
+    pub fn build(&self) -> Solid {
+        let outer_min = self.size.map(|size| -size / 2.);
+        let outer_max = self.size.map(|size| size / 2.);
+
+        let is_hollow = self.wall_thickness > Scalar::ZERO;
+
+        let mut cavity_min = outer_min;
+        let mut cavity_max = outer_max;
+        for side in BoxSide::ALL {
+            if self.open_sides.contains(&side) {
+                continue;
+            }
+
+            let axis = side.axis();
+            if side.is_positive() {
+                cavity_max[axis] -= self.wall_thickness;
+            } else {
+                cavity_min[axis] += self.wall_thickness;
+            }
+        }
+
+        if is_hollow {
+            assert!(
+                cavity_min
+                    .iter()
+                    .zip(&cavity_max)
+                    .all(|(min, max)| min < max),
+                "Walls of box are too thick, leaving no space for the cavity"
+            );
+        }
+
+        let is_closed = self.open_sides.is_empty();
+
+        let mut faces = Vec::new();
+        let mut cavity_faces = Vec::new();
+
+        for side in BoxSide::ALL {
+            let axis = side.axis();
+            let [b, c] = side.plane_axes();
+
+            let outer_offset = if side.is_positive() {
+                outer_max[axis]
+            } else {
+                outer_min[axis]
+            };
+            let outer =
+                [[outer_min[b], outer_min[c]], [outer_max[b], outer_max[c]]];
+            let cavity = [
+                [cavity_min[b], cavity_min[c]],
+                [cavity_max[b], cavity_max[c]],
+            ];
+
+            if !self.open_sides.contains(&side) {
+                faces.push(rectangle(side, outer_offset, outer, None));
+
+                if is_hollow {
+                    let cavity_offset = if side.is_positive() {
+                        cavity_max[axis]
+                    } else {
+                        cavity_min[axis]
+                    };
+
+                    // The wall of the cavity faces the opposite way. If the
+                    // box is closed, the cavity forms a separate shell.
+                    let shell_faces = if is_closed {
+                        &mut cavity_faces
+                    } else {
+                        &mut faces
+                    };
+                    shell_faces.push(rectangle(
+                        side.opposite(),
+                        cavity_offset,
+                        cavity,
+                        None,
+                    ));
+                }
+
+                continue;
+            }
+
+            if !is_hollow {
+                continue;
+            }
+
+            faces.extend(rim(side, outer_offset, outer, cavity));
+        }
+
+        let shells = [faces, cavity_faces]
+            .into_iter()
+            .filter(|faces| !faces.is_empty())
+            .map(|faces| Shell::new().with_faces(faces));
+
+        Solid::new().with_shells(shells)
+    }
+}
+
+/// A side of a box
+///
+/// See [`BoxBuilder`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum BoxSide {
+    /// The side facing the negative x-direction
+    Left,
+
+    /// The side facing the positive x-direction
+    Right,
+
+    /// The side facing the negative y-direction
+    Front,
+
+    /// The side facing the positive y-direction
+    Back,
+
+    /// The side facing the negative z-direction
+    Bottom,
+
+    /// The side facing the positive z-direction
+    Top,
+}
+
+impl BoxSide {
+    const ALL: [Self; 6] = [
+        Self::Left,
+        Self::Right,
+        Self::Front,
+        Self::Back,
+        Self::Bottom,
+        Self::Top,
+    ];
+
+    fn axis(&self) -> usize {
+        match self {
+            Self::Left | Self::Right => 0,
+            Self::Front | Self::Back => 1,
+            Self::Bottom | Self::Top => 2,
+        }
+    }
+
+    fn is_positive(&self) -> bool {
+        matches!(self, Self::Right | Self::Back | Self::Top)
+    }
+
+    fn opposite(&self) -> Self {
+        match self {
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+            Self::Front => Self::Back,
+            Self::Back => Self::Front,
+            Self::Bottom => Self::Top,
+            Self::Top => Self::Bottom,
+        }
+    }
+
+    /// The other two axes, in an order that forms a right-handed coordinate
+    /// system together with the side's axis
+    fn plane_axes(&self) -> [usize; 2] {
+        let axis = self.axis();
+        [(axis + 1) % 3, (axis + 2) % 3]
+    }
+}
+
+/// Create the rim that an open side of a hollow box leaves
+fn rim(
+    side: BoxSide,
+    offset: Scalar,
+    outer: [[Scalar; 2]; 2],
+    cavity: [[Scalar; 2]; 2],
+) -> Vec<Face> {
+    let [outer_min, outer_max] = outer;
+    let [cavity_min, cavity_max] = cavity;
+
+    let touches_outer = cavity_min[0] == outer_min[0]
+        || cavity_min[1] == outer_min[1]
+        || cavity_max[0] == outer_max[0]
+        || cavity_max[1] == outer_max[1];
+
+    if !touches_outer {
+        return vec![rectangle(side, offset, outer, Some(cavity))];
+    }
+
+    // The cavity extends to the edge of the side, because an adjacent side is
+    // open too. Cover the area around the cavity with up to four rectangles.
+    let bands = [
+        [outer_min, [outer_max[0], cavity_min[1]]],
+        [[outer_min[0], cavity_max[1]], outer_max],
+        [
+            [outer_min[0], cavity_min[1]],
+            [cavity_min[0], cavity_max[1]],
+        ],
+        [
+            [cavity_max[0], cavity_min[1]],
+            [outer_max[0], cavity_max[1]],
+        ],
+    ];
+
+    bands
+        .into_iter()
+        .filter(|[min, max]| min[0] < max[0] && min[1] < max[1])
+        .map(|band| rectangle(side, offset, band, None))
+        .collect()
+}
+
+/// Create a rectangular face, facing outward from the given side
+///
+/// The rectangle and hole are given in the coordinates of
+/// [`BoxSide::plane_axes`].
+fn rectangle(
+    side: BoxSide,
+    offset: Scalar,
+    rectangle: [[Scalar; 2]; 2],
+    hole: Option<[[Scalar; 2]; 2]>,
+) -> Face {
+    let axis = side.axis();
+    let [b, c] = side.plane_axes();
+
+    let unit = |axis: usize| {
+        let mut components = [Scalar::ZERO; 3];
+        components[axis] = Scalar::ONE;
+        Vector::from(components)
+    };
+
+    // Choose the surface coordinate system, such that the exterior is wound
+    // counter-clockwise, if the face's normal points outward.
+    let (u, v, swap) = if side.is_positive() {
+        (unit(b), unit(c), false)
+    } else {
+        (unit(c), unit(b), true)
+    };
+
+    let mut origin = [Scalar::ZERO; 3];
+    origin[axis] = offset;
+    let origin = Point::from(origin);
+
+    let surface = Surface::plane_from_points([origin, origin + u, origin + v]);
+
+    let points = |[min, max]: [[Scalar; 2]; 2]| {
+        [
+            [min[0], min[1]],
+            [max[0], min[1]],
+            [max[0], max[1]],
+            [min[0], max[1]],
+        ]
+        .map(|[p_b, p_c]| if swap { [p_c, p_b] } else { [p_b, p_c] })
+        .map(Point::from)
+    };
+
+    // Swapping the coordinates changes the winding, so correct for that.
+    let exterior = {
+        let mut points = points(rectangle);
+        if swap {
+            points.reverse();
+        }
+        points
+    };
+
+    let face = Face::build(surface).polygon_from_points(exterior);
+
+    match hole {
+        Some(hole) => {
+            // Holes are wound in the opposite direction.
+            let mut points = points(hole);
+            if !swap {
+                points.reverse();
+            }
+            face.with_hole(points).into_face()
+        }
+        None => face.into_face(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{iter::ObjectIters, objects::Solid};
+
+    use super::BoxSide;
+
+    #[test]
+    fn box_from_size() {
+        let solid = Solid::build().box_from_size([4., 4., 2.]).build();
+        assert_eq!(solid.shells().count(), 1);
+        assert_eq!(solid.face_iter().count(), 6);
+
+        let sheet = Solid::build()
+            .box_from_size([4., 4., 2.])
+            .with_open_sides([BoxSide::Top])
+            .build();
+        assert_eq!(sheet.face_iter().count(), 5);
+
+        let tray = Solid::build()
+            .box_from_size([4., 4., 2.])
+            .with_wall_thickness(0.5)
+            .with_open_sides([BoxSide::Top])
+            .build();
+        assert_eq!(tray.shells().count(), 1);
+        assert_eq!(tray.face_iter().count(), 11);
+
+        // 12 edges on the outside of the box, 12 on the inside of the cavity.
+        let angles = tray.shells().next().unwrap().dihedral_angles();
+        assert_eq!(angles.len(), 24);
+
+        let rim = tray
+            .face_iter()
+            .find(|face| face.interiors().count() == 1)
+            .expect("Expected tray to have a rim");
+        let hole = rim.interiors().next().unwrap();
+        for vertex in hole.vertices() {
+            let position = vertex.global_form().position();
+
+            // The inside of the wall is 0.5 away from the outside.
+            assert_eq!(position.x.abs(), Scalar::from(1.5));
+            assert_eq!(position.y.abs(), Scalar::from(1.5));
+            assert_eq!(position.z, Scalar::ONE);
+        }
+    }
+
+    #[test]
+    fn closed_hollow_box() {
+        let hollow = Solid::build()
+            .box_from_size([4., 4., 2.])
+            .with_wall_thickness(0.5)
+            .build();
+
+        // The outside of the box and the cavity are separate shells.
+        assert_eq!(hollow.shells().count(), 2);
+        for shell in hollow.shells() {
+            assert_eq!(shell.faces().into_iter().count(), 6);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn negative_wall_thickness() {
+        let _ = Solid::build()
+            .box_from_size([4., 4., 2.])
+            .with_wall_thickness(-0.5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn wall_thickness_too_large() {
+        let _ = Solid::build()
+            .box_from_size([4., 4., 2.])
+            .with_wall_thickness(1.)
+            .build();
+    }
 }
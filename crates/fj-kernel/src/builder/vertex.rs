@@ -1,6 +1,6 @@
 use fj_math::Point;
 
-use crate::objects::{Curve, GlobalVertex, SurfaceVertex, Vertex};
+use crate::objects::{Curve, Vertex};
 
 /// API for building a [`Vertex`]
 pub struct VertexBuilder {
@@ -17,21 +17,6 @@ impl VertexBuilder {
 
     /// Build a vertex from a curve position
     pub fn from_point(&self, point: impl Into<Point<1>>) -> Vertex {
-        let point = point.into();
-        let &surface = self.curve.surface();
-
-        let global_form = GlobalVertex::from_position(
-            self.curve
-                .global_form()
-                .path()
-                .point_from_path_coords(point),
-        );
-        let surface_form = SurfaceVertex::new(
-            self.curve.path().point_from_path_coords(point),
-            surface,
-            global_form,
-        );
-
-        Vertex::new([0.], self.curve, surface_form, global_form)
+        Vertex::on_curve(self.curve, point)
     }
 }
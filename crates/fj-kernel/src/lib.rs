@@ -91,4 +91,5 @@ pub mod algorithms;
 pub mod builder;
 pub mod iter;
 pub mod objects;
+pub mod patch;
 pub mod path;
@@ -1,4 +1,7 @@
+use fj_math::{Circle, Line, Point, Scalar, Vector};
+
 use crate::{
+    algorithms::approx::Tolerance,
     builder::{CurveBuilder, GlobalCurveBuilder},
     path::{GlobalPath, SurfacePath},
 };
@@ -32,6 +35,97 @@ impl Curve {
         }
     }
 
+    /// Construct a `Curve` from a global curve that lies in a surface
+    ///
+    /// Computes the path of the curve in surface coordinates, by projecting
+    /// the global curve into the surface. This is supported, if the global
+    /// curve is a translation of the u-curve of the surface along its
+    /// v-direction (as is the case for the edges of swept faces), or if the
+    /// surface is a plane.
+    ///
+    /// Returns an error, if the global curve doesn't lie in the surface (within
+    /// the given tolerance), or if its projection into the surface can't be
+    /// represented by a [`SurfacePath`].
+    pub fn from_global_on_surface(
+        global: GlobalCurve,
+        surface: Surface,
+        tolerance: impl Into<Tolerance>,
+    ) -> Result<Self, CurveFromGlobalError> {
+        let tolerance = tolerance.into().inner();
+
+        if let Some(v) = offset_along_v(&global, &surface, tolerance) {
+            let path = SurfacePath::Line(Line::from_origin_and_direction(
+                Point::from([Scalar::ZERO, v]),
+                Vector::unit_u(),
+            ));
+            return Ok(Self::new(surface, path, global));
+        }
+
+        let plane = match surface.u() {
+            GlobalPath::Line(line) => PlaneCoords {
+                origin: line.origin(),
+                u: line.direction(),
+                v: surface.v(),
+            },
+            GlobalPath::Circle(_) => {
+                return Err(CurveFromGlobalError::NotInSurface {
+                    global,
+                    surface,
+                })
+            }
+        };
+        let not_in_surface =
+            || CurveFromGlobalError::NotInSurface { global, surface };
+
+        let path = match global.path() {
+            GlobalPath::Line(line) => {
+                let origin = plane
+                    .point_to_plane_coords(line.origin(), tolerance)
+                    .ok_or_else(not_in_surface)?;
+                let direction = plane
+                    .vector_to_plane_coords(line.direction(), tolerance)
+                    .ok_or_else(not_in_surface)?;
+
+                SurfacePath::Line(Line::from_origin_and_direction(
+                    origin, direction,
+                ))
+            }
+            GlobalPath::Circle(circle) => {
+                let center = plane
+                    .point_to_plane_coords(circle.center(), tolerance)
+                    .ok_or_else(not_in_surface)?;
+                let a = plane
+                    .vector_to_plane_coords(circle.a(), tolerance)
+                    .ok_or_else(not_in_surface)?;
+                let b = plane
+                    .vector_to_plane_coords(circle.b(), tolerance)
+                    .ok_or_else(not_in_surface)?;
+
+                // `Circle` requires `a` and `b` to be of exactly equal length,
+                // which the projection won't necessarily provide, due to
+                // numerical inaccuracy. Derive `b` from `a` instead, and make
+                // sure that the result is close enough to the projection.
+                // Otherwise, the surface coordinates are skewed, and the
+                // circle became an ellipse.
+                let b_from_a = if a.cross(&b) > Scalar::ZERO {
+                    Vector::from([-a.v, a.u])
+                } else {
+                    Vector::from([a.v, -a.u])
+                };
+                if (b_from_a - b).magnitude() > tolerance {
+                    return Err(CurveFromGlobalError::NotRepresentable {
+                        global,
+                        surface,
+                    });
+                }
+
+                SurfacePath::Circle(Circle::new(center, a, b_from_a))
+            }
+        };
+
+        Ok(Self::new(surface, path, global))
+    }
+
     /// Access the path that defines this curve
     pub fn path(&self) -> SurfacePath {
         self.path
@@ -70,3 +164,180 @@ impl GlobalCurve {
         self.path
     }
 }
+
+/// Error constructing a [`Curve`] from a [`GlobalCurve`]
+///
+/// See [`Curve::from_global_on_surface`].
+#[derive(Debug, thiserror::Error)]
+pub enum CurveFromGlobalError {
+    /// The global curve does not lie in the surface
+    #[error("Global curve does not lie in surface")]
+    NotInSurface {
+        /// The global curve
+        global: GlobalCurve,
+
+        /// The surface
+        surface: Surface,
+    },
+
+    /// The global curve can't be represented in the surface's coordinates
+    #[error("Global curve can't be represented in surface coordinates")]
+    NotRepresentable {
+        /// The global curve
+        global: GlobalCurve,
+
+        /// The surface
+        surface: Surface,
+    },
+}
+
+/// Determine whether a global curve is the u-curve of a surface, translated
+/// along the surface's v-direction
+///
+/// Returns the v-coordinate of the translated curve, if that is the case.
+fn offset_along_v(
+    global: &GlobalCurve,
+    surface: &Surface,
+    tolerance: Scalar,
+) -> Option<Scalar> {
+    let (offset, same_shape) = match (surface.u(), global.path()) {
+        (GlobalPath::Line(u), GlobalPath::Line(line)) => (
+            line.origin() - u.origin(),
+            (line.direction() - u.direction()).magnitude() <= tolerance,
+        ),
+        (GlobalPath::Circle(u), GlobalPath::Circle(circle)) => (
+            circle.center() - u.center(),
+            (circle.a() - u.a()).magnitude() <= tolerance
+                && (circle.b() - u.b()).magnitude() <= tolerance,
+        ),
+        _ => return None,
+    };
+
+    if !same_shape {
+        return None;
+    }
+
+    let v = surface.v();
+    let offset_v = offset.dot(&v) / v.dot(&v);
+
+    if (offset - v * offset_v).magnitude() > tolerance {
+        return None;
+    }
+
+    Some(offset_v)
+}
+
+/// The coordinate system of a plane
+struct PlaneCoords {
+    origin: Point<3>,
+    u: Vector<3>,
+    v: Vector<3>,
+}
+
+impl PlaneCoords {
+    fn point_to_plane_coords(
+        &self,
+        point: Point<3>,
+        tolerance: Scalar,
+    ) -> Option<Point<2>> {
+        let coords =
+            self.vector_to_plane_coords(point - self.origin, tolerance)?;
+        Some(Point { coords })
+    }
+
+    fn vector_to_plane_coords(
+        &self,
+        vector: Vector<3>,
+        tolerance: Scalar,
+    ) -> Option<Vector<2>> {
+        // Solve `vector = s * u + t * v` in the least-squares sense. The
+        // coordinate axes of the plane don't need to be orthogonal.
+        let uu = self.u.dot(&self.u);
+        let uv = self.u.dot(&self.v);
+        let vv = self.v.dot(&self.v);
+        let wu = vector.dot(&self.u);
+        let wv = vector.dot(&self.v);
+
+        let det = uu * vv - uv * uv;
+        let s = (wu * vv - wv * uv) / det;
+        let t = (wv * uu - wu * uv) / det;
+
+        let projected = self.u * s + self.v * t;
+        if (vector - projected).magnitude() > tolerance {
+            return None;
+        }
+
+        Some(Vector::from([s, t]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Circle, Line, Point, Vector};
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        algorithms::transform::TransformObject,
+        objects::{GlobalCurve, Surface},
+        path::{GlobalPath, SurfacePath},
+    };
+
+    use super::{Curve, CurveFromGlobalError};
+
+    #[test]
+    fn from_global_on_surface_plane() -> anyhow::Result<()> {
+        let surface = Surface::xz_plane();
+
+        let line =
+            GlobalCurve::build().line_from_points([[1., 0., 1.], [2., 0., 3.]]);
+        let curve = Curve::from_global_on_surface(line, surface, 1e-12)?;
+        assert_eq!(
+            curve.path(),
+            SurfacePath::Line(Line::from_points([[1., 1.], [2., 3.]]))
+        );
+
+        let circle = GlobalCurve::from_path(GlobalPath::Circle(Circle::new(
+            [1., 0., 1.],
+            [0., 0., 2.],
+            [2., 0., 0.],
+        )));
+        let curve = Curve::from_global_on_surface(circle, surface, 1e-12)?;
+        assert_eq!(
+            curve.path(),
+            SurfacePath::Circle(Circle::new([1., 1.], [0., 2.], [2., 0.]))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_global_on_surface_swept() -> anyhow::Result<()> {
+        let circle = GlobalCurve::build().circle_from_radius(1.);
+        let surface = Surface::new(circle.path(), [0., 0., 2.]);
+
+        let top = circle.translate([0., 0., 1.]);
+        let curve = Curve::from_global_on_surface(top, surface, 1e-12)?;
+
+        assert_eq!(
+            curve.path(),
+            SurfacePath::Line(Line::from_origin_and_direction(
+                Point::from([0., 0.5]),
+                Vector::unit_u(),
+            ))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_global_on_surface_not_in_surface() {
+        let surface = Surface::xy_plane();
+        let line =
+            GlobalCurve::build().line_from_points([[0., 0., 0.], [0., 0., 1.]]);
+
+        assert!(matches!(
+            Curve::from_global_on_surface(line, surface, 1e-12),
+            Err(CurveFromGlobalError::NotInSurface { .. })
+        ));
+    }
+}
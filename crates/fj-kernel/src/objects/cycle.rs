@@ -3,7 +3,7 @@ use pretty_assertions::assert_eq;
 
 use crate::{builder::CycleBuilder, path::SurfacePath};
 
-use super::{HalfEdge, Surface};
+use super::{HalfEdge, Surface, Vertex};
 
 /// A cycle of connected half-edges
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
@@ -90,6 +90,19 @@ impl Cycle {
         self.half_edges.iter()
     }
 
+    /// Access the vertices of the cycle, in order
+    ///
+    /// Yields the first vertex of each half-edge. Since the end of each
+    /// half-edge connects to the beginning of the next one, this covers every
+    /// vertex of the cycle exactly once. To access the edges that connect
+    /// these vertices, use [`Cycle::half_edges`].
+    pub fn vertices(&self) -> impl Iterator<Item = &Vertex> + '_ {
+        self.half_edges.iter().map(|half_edge| {
+            let [first, _] = half_edge.vertices();
+            first
+        })
+    }
+
     /// Indicate the cycle's winding, assuming a right-handed coordinate system
     pub fn winding(&self) -> Winding {
         // The cycle could be made up of one or two circles. If that is the
@@ -155,3 +168,24 @@ impl Cycle {
         self.half_edges.into_iter()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Point;
+
+    use crate::objects::{Cycle, Surface};
+
+    #[test]
+    fn vertices() {
+        let points = [[0., 0.], [1., 0.], [1., 1.], [0., 1.]];
+        let cycle =
+            Cycle::build(Surface::xy_plane()).polygon_from_points(points);
+
+        let vertices = cycle
+            .vertices()
+            .map(|vertex| vertex.surface_form().position())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vertices, points.map(Point::from));
+    }
+}
@@ -1,6 +1,12 @@
 use std::fmt;
 
-use crate::builder::HalfEdgeBuilder;
+use fj_math::{Point, Scalar};
+
+use crate::{
+    algorithms::approx::Tolerance,
+    builder::{GlobalEdgeBuilder, HalfEdgeBuilder},
+    path::GlobalPath,
+};
 
 use super::{Curve, GlobalCurve, GlobalVertex, Surface, Vertex};
 
@@ -111,6 +117,54 @@ impl HalfEdge {
     pub fn global_form(&self) -> &GlobalEdge {
         &self.global_form
     }
+
+    /// Compute the curve coordinate of a point on the half-edge
+    ///
+    /// This is the inverse of converting a curve coordinate of the half-edge
+    /// into a global point. Returns `None`, if the point is further than
+    /// `tolerance` from the curve, or if it lies outside of the range that is
+    /// bounded by the half-edge's vertices.
+    pub fn parameter_of(
+        &self,
+        point: impl Into<Point<3>>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Option<Scalar> {
+        let point = point.into();
+        let tolerance = tolerance.into();
+
+        let path = self.curve().global_form().path();
+        let coord = path.point_to_path_coords(point).t;
+
+        let distance =
+            (path.point_from_path_coords([coord]) - point).magnitude();
+        if distance > tolerance.inner() {
+            return None;
+        }
+
+        let [a, b] = self.vertices().map(|vertex| vertex.position().t);
+        let [min, max] = if a < b { [a, b] } else { [b, a] };
+
+        // Circle coordinates wrap around, so the vertices might be outside of
+        // the range that `point_to_path_coords` returns.
+        let candidates = match path {
+            GlobalPath::Circle(_) => {
+                vec![coord - Scalar::TAU, coord, coord + Scalar::TAU]
+            }
+            GlobalPath::Line(_) => vec![coord],
+        };
+
+        if let Some(t) = candidates.into_iter().find(|&t| t >= min && t <= max)
+        {
+            return Some(t);
+        }
+
+        // Rounding errors might place points at the vertices just outside of
+        // the range.
+        [min, max].into_iter().find(|&t| {
+            let vertex = path.point_from_path_coords([t]);
+            (vertex - point).magnitude() <= tolerance.inner()
+        })
+    }
 }
 
 impl fmt::Display for HalfEdge {
@@ -131,6 +185,11 @@ pub struct GlobalEdge {
 }
 
 impl GlobalEdge {
+    /// Build a global edge using [`GlobalEdgeBuilder`]
+    pub fn build() -> GlobalEdgeBuilder {
+        GlobalEdgeBuilder
+    }
+
     /// Create a new instance
     pub fn new(curve: GlobalCurve, vertices: [GlobalVertex; 2]) -> Self {
         Self { curve, vertices }
@@ -153,4 +212,79 @@ impl GlobalEdge {
     pub fn vertices(&self) -> &[GlobalVertex; 2] {
         &self.vertices
     }
+
+    /// Normalize the edge, for use as an identifier
+    ///
+    /// Half-edges that refer to the same edge from opposite directions, have
+    /// global forms with their vertices in opposite order. Half-edges that are
+    /// constructed independently of each other, might additionally refer to
+    /// different lines that are defined by the same two vertices.
+    ///
+    /// The normalized edge has its vertices sorted and, if its curve is a
+    /// line, that line recomputed from the vertices. Normalized edges that
+    /// refer to the same edge in the model are equal.
+    ///
+    /// Please note that the curve of the normalized edge might not be the same
+    /// curve that any half-edge refers to. The normalized edge is meant as an
+    /// identifier only.
+    #[must_use]
+    pub fn normalize(mut self) -> Self {
+        self.vertices.sort();
+
+        if let GlobalPath::Line(_) = self.curve.path() {
+            let [a, b] = self.vertices.map(|vertex| vertex.position());
+
+            if a != b {
+                self.curve = GlobalCurve::build().line_from_points([a, b]);
+            }
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::objects::{HalfEdge, Surface};
+
+    #[test]
+    fn parameter_of() {
+        let tolerance = 0.001;
+
+        let line = HalfEdge::build(Surface::xy_plane())
+            .line_segment_from_points([[1., 0.], [3., 0.]]);
+
+        assert_eq!(
+            line.parameter_of([1.5, 0., 0.], tolerance),
+            Some(Scalar::from(0.25))
+        );
+        assert_eq!(
+            line.parameter_of([3., 0., 0.], tolerance),
+            Some(Scalar::ONE)
+        );
+        assert_eq!(line.parameter_of([4., 0., 0.], tolerance), None);
+        assert_eq!(line.parameter_of([2., 1., 0.], tolerance), None);
+
+        let circle =
+            HalfEdge::build(Surface::xy_plane()).circle_from_radius(2.);
+
+        let t = circle
+            .parameter_of([0., 2., 0.], tolerance)
+            .expect("Point is on circle");
+        assert!((t - Scalar::PI / 2.).abs() < Scalar::from(1e-12));
+
+        let point = circle
+            .curve()
+            .global_form()
+            .path()
+            .point_from_path_coords([t]);
+        assert!(
+            (point - Point::from([0., 2., 0.])).magnitude()
+                < Scalar::from(1e-12)
+        );
+
+        assert_eq!(circle.parameter_of([0., 3., 0.], tolerance), None);
+    }
 }
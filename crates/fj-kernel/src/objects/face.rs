@@ -1,13 +1,36 @@
-use std::collections::{btree_set, BTreeSet};
+use std::{
+    cmp::Ordering,
+    collections::{btree_set, BTreeSet},
+    hash::{Hash, Hasher},
+};
 
 use fj_interop::mesh::Color;
-use fj_math::Winding;
+use fj_math::{Circle, Line, Point, Scalar, Vector, Winding, DEFAULT_EPSILON};
 
-use crate::builder::FaceBuilder;
+use crate::{
+    builder::FaceBuilder,
+    path::{GlobalPath, SurfacePath},
+};
 
-use super::{Cycle, Surface};
+use super::{
+    Curve, Cycle, GlobalCurve, GlobalEdge, GlobalVertex, HalfEdge, Sketch,
+    Surface, SurfaceVertex, Vertex,
+};
 
 /// A collection of faces
+///
+/// # Iteration Order
+///
+/// Faces are iterated in a deterministic order, which is defined by the `Ord`
+/// implementation of [`Face`]. That implementation compares the faces'
+/// geometry (their surface, then their cycles, then their color), and nothing
+/// else. Specifically, the path along which a face was swept is not considered.
+/// This means the order doesn't depend on the order in which faces were added,
+/// and two collections that contain equal faces are always iterated in the
+/// same order, in the same process or across runs.
+///
+/// Adding a face can change the position of the faces that come after it, as
+/// this order is not stable under modification.
 #[derive(Clone, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct Faces {
     inner: BTreeSet<Face>,
@@ -56,12 +79,17 @@ impl<'a> IntoIterator for &'a Faces {
 }
 
 /// A face of a shape
-#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+///
+/// Faces are compared by their geometry and color. The path along which a face
+/// was swept (see [`Face::sweep_path`]) records where it came from, and is not
+/// considered when comparing or hashing faces.
+#[derive(Clone, Debug)]
 pub struct Face {
     surface: Surface,
     exterior: Cycle,
     interiors: Vec<Cycle>,
     color: Color,
+    sweep_path: Option<Vector<3>>,
 }
 
 impl Face {
@@ -80,6 +108,7 @@ impl Face {
             exterior,
             interiors: Vec::new(),
             color: Color::default(),
+            sweep_path: None,
         }
     }
 
@@ -115,6 +144,14 @@ impl Face {
         self
     }
 
+    /// Record the path along which the face was swept
+    ///
+    /// Consumes the face and returns the updated instance.
+    pub fn with_sweep_path(mut self, path: impl Into<Vector<3>>) -> Self {
+        self.sweep_path = Some(path.into());
+        self
+    }
+
     /// Access this face's surface
     pub fn surface(&self) -> &Surface {
         &self.surface
@@ -142,6 +179,13 @@ impl Face {
         self.color
     }
 
+    /// Access the path along which the face was swept
+    ///
+    /// Returns `None`, if the face wasn't created by sweeping an edge.
+    pub fn sweep_path(&self) -> Option<Vector<3>> {
+        self.sweep_path
+    }
+
     /// Determine handed-ness of the face's front-side coordinate system
     ///
     /// A face is defined on a surface, which has a coordinate system. Since
@@ -158,6 +202,237 @@ impl Face {
             Winding::Cw => Handedness::LeftHanded,
         }
     }
+
+    /// Compute the normal of the face's front side at the given point
+    ///
+    /// The point is given in the coordinates of the face's surface. The
+    /// returned normal is normalized.
+    pub fn normal_at(&self, point: impl Into<Point<2>>) -> Vector<3> {
+        let normal = self.surface().normal_at(point);
+
+        match self.coord_handedness() {
+            Handedness::RightHanded => normal,
+            Handedness::LeftHanded => -normal,
+        }
+    }
+
+    /// Determine whether the face contains a point
+    ///
+    /// The point is given in the coordinates of the face's surface. Points
+    /// that are in a hole are not contained in the face. Points on the
+    /// boundary of the face, including the boundaries of its holes, are.
+    ///
+    /// Casts a ray from the point into the positive u-direction, and counts
+    /// the crossings with the boundary of the face. Edges on lines and
+    /// circles are both handled exactly, without approximating them first.
+    pub fn contains_point(&self, point: impl Into<Point<2>>) -> bool {
+        let point = point.into();
+
+        let mut num_crossings = 0;
+
+        for half_edge in self.all_cycles().flat_map(|cycle| cycle.half_edges())
+        {
+            if is_on_half_edge(half_edge, point) {
+                return true;
+            }
+
+            num_crossings += count_crossings(half_edge, point);
+        }
+
+        num_crossings % 2 == 1
+    }
+
+    /// Compute the flat pattern of the face
+    ///
+    /// Unrolls the face into the xy-plane, without stretching it. Planar faces
+    /// are just moved into the xy-plane. Faces on the surface of a right
+    /// cylinder are unrolled into the rectangle that the cylinder is made
+    /// from. The returned sketch consists of a single face, with the same
+    /// color as this one.
+    ///
+    /// Returns `None`, if the face can't be developed. All surfaces that can
+    /// currently be represented are developable, but the following cases are
+    /// not supported:
+    ///
+    /// - Faces on oblique cylinders, whose sweep path is not perpendicular to
+    ///   the circle they were swept from.
+    /// - Circular edges on cylindrical faces, or circular edges that would
+    ///   become elliptical, because the surface coordinate system is skewed.
+    ///
+    /// # Implementation Note
+    ///
+    /// Surfaces can't represent cones yet, so there is no support for
+    /// developing cones into circular sectors.
+    pub fn develop(&self) -> Option<Sketch> {
+        let surface = self.surface();
+        let v = surface.v();
+
+        // The development is a linear map from surface coordinates into the
+        // xy-plane. These are the images of the unit vectors of the surface
+        // coordinate system.
+        let (map, is_curved) = match surface.u() {
+            GlobalPath::Line(line) => {
+                let u = line.direction();
+
+                let x = u.normalize();
+                let y = (v - x * v.dot(&x)).normalize();
+
+                let map = [u, v].map(|w| Vector::from([w.dot(&x), w.dot(&y)]));
+                (map, false)
+            }
+            GlobalPath::Circle(circle) => {
+                let normal = circle.a().cross(&circle.b()).normalize();
+                if v.cross(&normal).magnitude()
+                    > v.magnitude() * Scalar::from(1e-12)
+                {
+                    return None;
+                }
+
+                let map = [
+                    Vector::from([circle.radius(), Scalar::ZERO]),
+                    Vector::from([Scalar::ZERO, v.magnitude()]),
+                ];
+                (map, true)
+            }
+        };
+        let map_vector = |w: Vector<2>| map[0] * w.u + map[1] * w.v;
+        let map_point = |p: Point<2>| Point {
+            coords: map_vector(p.coords),
+        };
+
+        let target = Surface::xy_plane();
+
+        let develop_cycle = |cycle: &Cycle| -> Option<Cycle> {
+            let mut half_edges = Vec::new();
+
+            for half_edge in cycle.half_edges() {
+                let (path, global_path) = match half_edge.curve().path() {
+                    SurfacePath::Line(line) => {
+                        let origin = map_point(line.origin());
+                        let direction = map_vector(line.direction());
+
+                        (
+                            SurfacePath::Line(Line::from_origin_and_direction(
+                                origin, direction,
+                            )),
+                            GlobalPath::Line(Line::from_origin_and_direction(
+                                origin.to_xyz(),
+                                direction.to_xyz(),
+                            )),
+                        )
+                    }
+                    SurfacePath::Circle(circle) => {
+                        if is_curved {
+                            return None;
+                        }
+
+                        let center = map_point(circle.center());
+                        let a = map_vector(circle.a());
+                        let b = map_vector(circle.b());
+
+                        let epsilon = a.magnitude() * Scalar::from(1e-12);
+                        if (a.magnitude() - b.magnitude()).abs() > epsilon
+                            || a.dot(&b).abs() > epsilon * a.magnitude()
+                        {
+                            return None;
+                        }
+
+                        // Construct `b` from `a`, to make sure the circle is
+                        // valid, despite any numerical inaccuracy.
+                        let b_perp = Vector::from([-a.v, a.u]);
+                        let b = if b_perp.dot(&b) > Scalar::ZERO {
+                            b_perp
+                        } else {
+                            -b_perp
+                        };
+
+                        (
+                            SurfacePath::Circle(Circle::new(center, a, b)),
+                            GlobalPath::Circle(Circle::new(
+                                center.to_xyz(),
+                                a.to_xyz(),
+                                b.to_xyz(),
+                            )),
+                        )
+                    }
+                };
+
+                let global_curve = GlobalCurve::from_path(global_path);
+                let curve = Curve::new(target, path, global_curve);
+
+                let vertices = half_edge.vertices().map(|vertex| {
+                    let position = map_point(vertex.surface_form().position());
+                    let global_form =
+                        GlobalVertex::from_position(position.to_xyz());
+                    let surface_form =
+                        SurfaceVertex::new(position, target, global_form);
+
+                    Vertex::new(
+                        vertex.position(),
+                        curve,
+                        surface_form,
+                        global_form,
+                    )
+                });
+
+                let global_edge = GlobalEdge::new(
+                    global_curve,
+                    vertices.map(|vertex| *vertex.global_form()),
+                );
+                half_edges.push(HalfEdge::new(curve, vertices, global_edge));
+            }
+
+            Some(Cycle::new(target, half_edges))
+        };
+
+        let exterior = develop_cycle(self.exterior())?;
+        let interiors = self
+            .interiors()
+            .map(develop_cycle)
+            .collect::<Option<Vec<_>>>()?;
+
+        let face = Face::new(target, exterior)
+            .with_interiors(interiors)
+            .with_color(self.color());
+
+        Some(Sketch::new().with_faces([face]))
+    }
+}
+
+impl Eq for Face {}
+
+impl PartialEq for Face {
+    fn eq(&self, other: &Self) -> bool {
+        self.surface == other.surface
+            && self.exterior == other.exterior
+            && self.interiors == other.interiors
+            && self.color == other.color
+    }
+}
+
+impl Hash for Face {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.surface.hash(state);
+        self.exterior.hash(state);
+        self.interiors.hash(state);
+        self.color.hash(state);
+    }
+}
+
+impl Ord for Face {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.surface
+            .cmp(&other.surface)
+            .then_with(|| self.exterior.cmp(&other.exterior))
+            .then_with(|| self.interiors.cmp(&other.interiors))
+            .then_with(|| self.color.cmp(&other.color))
+    }
+}
+
+impl PartialOrd for Face {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
 /// The handedness of a face's coordinate system
@@ -171,3 +446,205 @@ pub enum Handedness {
     /// The face's coordinate system is right-handed
     RightHanded,
 }
+
+/// Determine whether a point is on a half-edge, within `DEFAULT_EPSILON`
+fn is_on_half_edge(half_edge: &HalfEdge, point: Point<2>) -> bool {
+    let [start, end] = half_edge.vertices().map(|vertex| vertex.position());
+    let [lo, hi] = if start < end {
+        [start.t, end.t]
+    } else {
+        [end.t, start.t]
+    };
+
+    match half_edge.curve().path() {
+        SurfacePath::Line(line) => {
+            let t = line.point_to_line_coords(point).t.clamp(lo, hi);
+            let closest = line.point_from_line_coords([t]);
+
+            (closest - point).magnitude() <= DEFAULT_EPSILON
+        }
+        SurfacePath::Circle(circle) => {
+            let distance = (point - circle.center()).magnitude();
+            if !distance.approx_eq(circle.radius(), DEFAULT_EPSILON) {
+                return false;
+            }
+
+            let t = circle.point_to_circle_coords(point).t;
+            let t = move_into_range(t, lo);
+
+            t <= hi
+        }
+    }
+}
+
+/// Count how often a ray from the point into the positive u-direction crosses
+/// a half-edge
+fn count_crossings(half_edge: &HalfEdge, point: Point<2>) -> usize {
+    let [start, end] = half_edge.vertices().map(|vertex| vertex.position());
+
+    match half_edge.curve().path() {
+        SurfacePath::Line(line) => {
+            let [a, b] = [start, end]
+                .map(|position| line.point_from_line_coords(position));
+
+            // Count segments that touch the ray with their lower end, but not
+            // those that touch it with their upper end. That way, a ray that
+            // passes through a vertex between two segments is counted once.
+            if (a.v > point.v) == (b.v > point.v) {
+                return 0;
+            }
+
+            let u = a.u + (point.v - a.v) / (b.v - a.v) * (b.u - a.u);
+            usize::from(u > point.u)
+        }
+        SurfacePath::Circle(circle) => {
+            let [lo, hi] = if start < end {
+                [start.t, end.t]
+            } else {
+                [end.t, start.t]
+            };
+
+            // Points on the circle have the v-coordinate
+            // `center.v + a.v * cos(t) + b.v * sin(t)`, which can be written as
+            // `center.v + r * cos(t - phi)`.
+            let [a, b] = [circle.a(), circle.b()];
+            let r = Scalar::from_f64((a.v * a.v + b.v * b.v).into_f64().sqrt());
+            if r == Scalar::ZERO {
+                return 0;
+            }
+            let phi = Scalar::atan2(b.v, a.v);
+
+            let k = (point.v - circle.center().v) / r;
+            if k.abs() >= Scalar::ONE {
+                // The ray misses the circle, or just touches it.
+                return 0;
+            }
+            let delta = k.acos();
+
+            [phi - delta, phi + delta]
+                .into_iter()
+                .map(|t| move_into_range(t, lo))
+                .filter(|&t| t < hi)
+                .filter(|&t| circle.point_from_circle_coords([t]).u > point.u)
+                .count()
+        }
+    }
+}
+
+/// Move an angle into the range `[lo, lo + TAU)`
+fn move_into_range(t: Scalar, lo: Scalar) -> Scalar {
+    lo + Scalar::from_f64(
+        (t - lo).into_f64().rem_euclid(Scalar::TAU.into_f64()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar, Vector};
+
+    use crate::{
+        algorithms::{sweep::Sweep, transform::TransformObject},
+        objects::{Cycle, Face, Faces, HalfEdge, Surface},
+        path::GlobalPath,
+    };
+
+    #[test]
+    fn sweep_path_is_not_part_of_identity() {
+        let face = Face::build(Surface::xy_plane())
+            .polygon_from_points([[0., 0.], [1., 0.], [0., 1.]])
+            .into_face();
+        let swept = face.clone().with_sweep_path([0., 0., 1.]);
+
+        assert_eq!(face, swept);
+
+        let mut faces = Faces::new();
+        faces.extend([face, swept]);
+        assert_eq!(faces.into_iter().count(), 1);
+    }
+
+    #[test]
+    fn develop_planar_face() {
+        let face = Face::build(Surface::xz_plane())
+            .polygon_from_points([[0., 0.], [2., 0.], [2., 1.], [0., 1.]])
+            .into_face()
+            .rotate([0., 0., 1.]);
+
+        let sketch = face.develop().unwrap();
+        let aabb = sketch.bounding_box().unwrap();
+
+        let size = aabb.max - aabb.min;
+        assert!((size - Vector::from([2., 1.])).magnitude() < epsilon());
+    }
+
+    #[test]
+    fn develop_cylinder() {
+        let surface = Surface::xy_plane();
+        let half_edge = HalfEdge::build(surface).circle_from_radius(1.);
+        let cycle = Cycle::new(surface, [half_edge]);
+        let cylinder = Face::new(surface, cycle.clone()).sweep([0., 0., 3.]);
+
+        let side = cylinder
+            .faces()
+            .into_iter()
+            .find(|face| matches!(face.surface().u(), GlobalPath::Circle(_)))
+            .unwrap();
+        let sketch = side.develop().unwrap();
+        let aabb = sketch.bounding_box().unwrap();
+
+        let size = aabb.max - aabb.min;
+        assert!(
+            (size - Vector::from([Scalar::TAU, Scalar::from(3.)])).magnitude()
+                < epsilon()
+        );
+
+        // An oblique cylinder can't be developed.
+        let oblique = Face::new(surface, cycle).sweep([1., 0., 3.]);
+        let side = oblique
+            .faces()
+            .into_iter()
+            .find(|face| matches!(face.surface().u(), GlobalPath::Circle(_)))
+            .unwrap();
+        assert_eq!(side.develop(), None);
+    }
+
+    fn epsilon() -> Scalar {
+        Scalar::from(1e-12)
+    }
+
+    #[test]
+    fn contains_point() {
+        let face = Face::build(Surface::xy_plane())
+            .polygon_from_points([[0., 0.], [4., 0.], [4., 3.], [0., 3.]])
+            .with_hole([[1., 1.], [1., 2.], [2., 2.], [2., 1.]])
+            .into_face();
+
+        // In the solid region, in the hole, and outside of the face.
+        assert!(face.contains_point([3., 1.5]));
+        assert!(!face.contains_point([1.5, 1.5]));
+        assert!(!face.contains_point([5., 1.5]));
+
+        // Points on the boundary, including that of the hole, are inside.
+        for point in [[4., 1.5], [2., 0.], [0., 0.], [1., 1.5], [2., 2.]] {
+            assert!(face.contains_point(Point::from(point)));
+        }
+    }
+
+    #[test]
+    fn contains_point_circle() {
+        let surface = Surface::xy_plane();
+        let cycle = Cycle::new(
+            surface,
+            [HalfEdge::build(surface).circle_from_radius(1.)],
+        );
+        let face = Face::new(surface, cycle);
+
+        assert!(face.contains_point([0.5, 0.]));
+        assert!(face.contains_point([0., -0.9]));
+        assert!(!face.contains_point([2., 0.]));
+        assert!(!face.contains_point([0.8, 0.8]));
+
+        // Points on the circle are inside.
+        assert!(face.contains_point([1., 0.]));
+        assert!(face.contains_point([0., 1.]));
+    }
+}
@@ -15,13 +15,13 @@ mod surface;
 mod vertex;
 
 pub use self::{
-    curve::{Curve, GlobalCurve},
+    curve::{Curve, CurveFromGlobalError, GlobalCurve},
     cycle::Cycle,
     edge::{GlobalEdge, HalfEdge},
     face::{Face, Faces, Handedness},
-    shell::Shell,
+    shell::{ReplaceFaceError, Shell},
     sketch::Sketch,
-    solid::Solid,
+    solid::{BoundingCylinder, Convexity, Solid},
     surface::Surface,
     vertex::{GlobalVertex, SurfaceVertex, Vertex},
 };
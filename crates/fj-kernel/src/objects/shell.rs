@@ -1,13 +1,33 @@
-use crate::builder::ShellBuilder;
+use std::collections::{BTreeMap, BTreeSet};
 
-use super::{face::Faces, Face};
+use fj_math::{Aabb, Point, Scalar, Vector};
+
+use crate::{
+    algorithms::{
+        approx::{Approx, Tolerance},
+        reverse::Reverse,
+        triangulate::Triangulate,
+    },
+    builder::ShellBuilder,
+    iter::ObjectIters,
+    path::GlobalPath,
+};
+
+use super::{
+    face::Faces, Curve, Cycle, Face, GlobalEdge, GlobalVertex, HalfEdge,
+    Surface, SurfaceVertex, Vertex,
+};
 
 /// A 3-dimensional closed shell
 ///
 /// # Implementation Note
 ///
 /// The faces that make up a shell should be closed ("watertight"). This is not
-/// currently validated.
+/// validated on construction, but can be checked using [`Shell::is_closed`].
+///
+/// Open shells, like the single face created by sweeping an open profile, are
+/// still useful for approximation and export. Just don't expect them to form
+/// a valid solid.
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct Shell {
     faces: Faces,
@@ -39,10 +59,33 @@ impl Shell {
     }
 
     /// Access the shell's faces
+    ///
+    /// The faces are iterated in a deterministic order that only depends on
+    /// their geometry. See [`Faces`] for details.
     pub fn faces(&self) -> &Faces {
         &self.faces
     }
 
+    /// Check whether the shell is closed
+    ///
+    /// A shell is closed ("watertight"), if every edge is shared by exactly two
+    /// faces. Edges are compared by their [normalized](GlobalEdge::normalize)
+    /// global form.
+    pub fn is_closed(&self) -> bool {
+        let mut num_half_edges: BTreeMap<GlobalEdge, usize> = BTreeMap::new();
+        for face in self.faces() {
+            for cycle in face.all_cycles() {
+                for half_edge in cycle.half_edges() {
+                    *num_half_edges
+                        .entry(half_edge.global_form().normalize())
+                        .or_default() += 1;
+                }
+            }
+        }
+
+        num_half_edges.values().all(|&num| num == 2)
+    }
+
     /// Convert the shell into a list of faces
     pub fn into_faces(self) -> Faces {
         self.faces
@@ -52,6 +95,316 @@ impl Shell {
     pub fn find_face(&self, face: &Face) -> Option<Face> {
         self.faces().find(face)
     }
+
+    /// Replace a face of the shell with another one
+    ///
+    /// Every edge that `old` shares with another face of the shell must also
+    /// be part of the boundary of `new`, so the replacement connects to the
+    /// rest of the shell in the same way. Edges are compared by their
+    /// [normalized](GlobalEdge::normalize) global form, which means `new` may
+    /// use them in either direction. Edges of `old` that aren't shared with
+    /// other faces don't need to be kept, and `new` may add edges of its own.
+    ///
+    /// Returns an error, if `old` is not part of the shell, or if `new` is
+    /// missing an edge that `old` shares with other faces.
+    pub fn replace_face(
+        &self,
+        old: &Face,
+        new: impl Into<Face>,
+    ) -> Result<Self, ReplaceFaceError> {
+        let new = new.into();
+
+        if self.find_face(old).is_none() {
+            return Err(ReplaceFaceError::FaceNotFound);
+        }
+
+        let edges_of = |face: &Face| {
+            face.all_cycles()
+                .flat_map(|cycle| cycle.half_edges())
+                .map(|half_edge| half_edge.global_form().normalize())
+                .collect::<BTreeSet<_>>()
+        };
+
+        let edges_of_others = self
+            .faces()
+            .into_iter()
+            .filter(|face| *face != old)
+            .flat_map(edges_of)
+            .collect::<BTreeSet<_>>();
+        let edges_of_new = edges_of(&new);
+
+        for edge in edges_of(old) {
+            if edges_of_others.contains(&edge) && !edges_of_new.contains(&edge)
+            {
+                return Err(ReplaceFaceError::SharedEdgeMissing(edge));
+            }
+        }
+
+        let faces = self
+            .faces()
+            .into_iter()
+            .filter(|face| *face != old)
+            .cloned()
+            .chain([new]);
+
+        Ok(Self::new().with_faces(faces))
+    }
+
+    /// Make the orientation of the shell's faces consistent
+    ///
+    /// Two neighboring faces are consistently oriented, if they refer to the
+    /// edge between them from opposite directions. Starting from one face in
+    /// each connected group of faces, the orientation is propagated to its
+    /// neighbors, and any face that isn't consistent with its neighbor is
+    /// reversed.
+    ///
+    /// Afterwards, each group is checked to be facing outward, by computing its
+    /// signed volume. Groups with a negative volume are inside-out, and all of
+    /// their faces are reversed.
+    ///
+    /// Returns the repaired shell, together with the number of faces that were
+    /// reversed. Edges that aren't shared by exactly two faces, don't
+    /// propagate orientation. Open shells can't be checked for facing outward.
+    pub fn fix_orientations(self) -> (Self, usize) {
+        let faces = self.faces.into_iter().collect::<Vec<_>>();
+
+        let mut neighbors: BTreeMap<GlobalEdge, Vec<(usize, &HalfEdge)>> =
+            BTreeMap::new();
+        for (i, face) in faces.iter().enumerate() {
+            for cycle in face.all_cycles() {
+                for half_edge in cycle.half_edges() {
+                    neighbors
+                        .entry(half_edge.global_form().normalize())
+                        .or_default()
+                        .push((i, half_edge));
+                }
+            }
+        }
+
+        let mut edges_by_face = vec![Vec::new(); faces.len()];
+        for sides in neighbors.values() {
+            if let &[(a, half_edge_a), (b, half_edge_b)] = sides.as_slice() {
+                let is_opposite = direction_at_half_edge(half_edge_a)
+                    .dot(&direction_at_half_edge(half_edge_b))
+                    < Scalar::ZERO;
+
+                edges_by_face[a].push((b, is_opposite));
+                edges_by_face[b].push((a, is_opposite));
+            }
+        }
+
+        // Propagate the orientation through each group of connected faces.
+        let mut flip: Vec<Option<bool>> = vec![None; faces.len()];
+        let mut groups = Vec::new();
+        for seed in 0..faces.len() {
+            if flip[seed].is_some() {
+                continue;
+            }
+
+            flip[seed] = Some(false);
+            let mut group = vec![seed];
+            let mut queue = vec![seed];
+
+            while let Some(i) = queue.pop() {
+                let flip_i = flip[i].unwrap_or(false);
+
+                for &(j, is_opposite) in &edges_by_face[i] {
+                    if flip[j].is_none() {
+                        // Consistent neighbors refer to their shared edge
+                        // from opposite directions.
+                        flip[j] =
+                            Some(if is_opposite { flip_i } else { !flip_i });
+                        group.push(j);
+                        queue.push(j);
+                    }
+                }
+            }
+
+            groups.push(group);
+        }
+
+        let mut flip = flip
+            .into_iter()
+            .map(|flip| flip.unwrap_or(false))
+            .collect::<Vec<_>>();
+
+        // Make sure each group faces outward.
+        for group in groups {
+            let volume = group
+                .iter()
+                .map(|&i| {
+                    let volume = signed_volume(&faces[i]);
+                    if flip[i] {
+                        -volume
+                    } else {
+                        volume
+                    }
+                })
+                .fold(Scalar::ZERO, |a, b| a + b);
+
+            if volume < Scalar::ZERO {
+                for i in group {
+                    flip[i] = !flip[i];
+                }
+            }
+        }
+
+        let num_flipped = flip.iter().filter(|&&flip| flip).count();
+        let faces = faces.into_iter().zip(flip).map(|(face, flip)| {
+            if flip {
+                face.reverse()
+            } else {
+                face
+            }
+        });
+
+        (Self::new().with_faces(faces), num_flipped)
+    }
+
+    /// Find the vertex of the shell that is nearest to the given point
+    ///
+    /// Returns the vertex, together with its distance to `point`, or `None`,
+    /// if the shell has no vertices. If multiple vertices have the same
+    /// distance, the smallest one (according to its `Ord` implementation) is
+    /// returned.
+    ///
+    /// # Implementation Note
+    ///
+    /// This checks every vertex of the shell, which is fine for interactive
+    /// use on shells of moderate size. There is no spatial index that could
+    /// speed this up yet.
+    pub fn nearest_vertex(
+        &self,
+        point: impl Into<Point<3>>,
+    ) -> Option<(GlobalVertex, Scalar)> {
+        let point = GlobalVertex::from_position(point);
+
+        self.global_vertex_iter()
+            .map(|vertex| (*vertex, vertex.distance_to(&point)))
+            .min_by(|(vertex_a, distance_a), (vertex_b, distance_b)| {
+                distance_a.cmp(distance_b).then(vertex_a.cmp(vertex_b))
+            })
+    }
+
+    /// Compute the dihedral angles at the edges of the shell
+    ///
+    /// Returns the interior angle between the two faces that meet at each
+    /// edge, in radians. Flat edges have an angle of `PI`, convex edges less
+    /// than that, concave edges more. The angles are computed from the normals
+    /// of the faces' surfaces at the middle of each edge.
+    ///
+    /// The edges are identified by their [normalized](GlobalEdge::normalize)
+    /// global form. Edges that aren't shared by exactly two faces (as in
+    /// open shells) are skipped.
+    ///
+    /// Expects the faces of the shell to be consistently oriented, with their
+    /// front sides facing outward.
+    pub fn dihedral_angles(&self) -> BTreeMap<GlobalEdge, Scalar> {
+        let mut edges: BTreeMap<GlobalEdge, Vec<(&Face, &HalfEdge)>> =
+            BTreeMap::new();
+
+        for face in self.faces() {
+            for cycle in face.all_cycles() {
+                for half_edge in cycle.half_edges() {
+                    edges
+                        .entry(half_edge.global_form().normalize())
+                        .or_default()
+                        .push((face, half_edge));
+                }
+            }
+        }
+
+        edges
+            .into_iter()
+            .filter_map(|(edge, sides)| {
+                if let [a, b] = sides.as_slice() {
+                    Some((edge, dihedral_angle(*a, *b)))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Merge adjacent faces that lie in the same plane
+    ///
+    /// Two faces are merged, if they share an edge, both are planar, they face
+    /// the same direction, and the vertices of one are within `tolerance` of
+    /// the plane of the other. The edges between merged faces are removed, and
+    /// the remaining edges form the boundary of the merged face.
+    ///
+    /// The merged face is defined in the surface of one of the original faces,
+    /// and takes over its color. The global forms of its edges and vertices
+    /// are kept, which means it still connects to its neighbors.
+    ///
+    /// Faces whose combined boundary can't be reconstructed unambiguously
+    /// (for example, because they only touch at a vertex), are left as they
+    /// are.
+    ///
+    /// Expects the faces of the shell to be consistently oriented.
+    pub fn merge_coplanar(self, tolerance: impl Into<Tolerance>) -> Self {
+        let tolerance = tolerance.into();
+        let faces = self.faces.into_iter().collect::<Vec<_>>();
+
+        let mut faces_by_edge: BTreeMap<GlobalEdge, Vec<usize>> =
+            BTreeMap::new();
+        for (i, face) in faces.iter().enumerate() {
+            for cycle in face.all_cycles() {
+                for half_edge in cycle.half_edges() {
+                    faces_by_edge
+                        .entry(half_edge.global_form().normalize())
+                        .or_default()
+                        .push(i);
+                }
+            }
+        }
+
+        // Union-find structure, to group faces that are connected through
+        // edges between coplanar faces.
+        let mut parents = (0..faces.len()).collect::<Vec<_>>();
+        for indices in faces_by_edge.values() {
+            if let &[a, b] = indices.as_slice() {
+                if are_coplanar(&faces[a], &faces[b], tolerance) {
+                    let a = find_root(&parents, a);
+                    let b = find_root(&parents, b);
+                    parents[a.max(b)] = a.min(b);
+                }
+            }
+        }
+
+        let mut groups: BTreeMap<usize, Vec<&Face>> = BTreeMap::new();
+        for (i, face) in faces.iter().enumerate() {
+            groups.entry(find_root(&parents, i)).or_default().push(face);
+        }
+
+        let mut merged = Vec::new();
+        for group in groups.into_values() {
+            if group.len() > 1 {
+                if let Some(face) = merge_faces(&group, tolerance) {
+                    merged.push(face);
+                    continue;
+                }
+            }
+
+            merged.extend(group.into_iter().cloned());
+        }
+
+        Self::new().with_faces(merged)
+    }
+}
+
+/// Error replacing a face of a [`Shell`]
+///
+/// See [`Shell::replace_face`].
+#[derive(Debug, thiserror::Error)]
+pub enum ReplaceFaceError {
+    /// The face that should be replaced is not part of the shell
+    #[error("Face to replace is not part of the shell")]
+    FaceNotFound,
+
+    /// The replacement face is missing an edge that connects to the shell
+    #[error("Replacement face is missing shared edge: {0:?}")]
+    SharedEdgeMissing(GlobalEdge),
 }
 
 impl Default for Shell {
@@ -59,3 +412,382 @@ impl Default for Shell {
         Self::new()
     }
 }
+
+fn dihedral_angle(
+    (face_a, half_edge_a): (&Face, &HalfEdge),
+    (face_b, half_edge_b): (&Face, &HalfEdge),
+) -> Scalar {
+    let normal_a = normal_at_half_edge(face_a, half_edge_a);
+    let normal_b = normal_at_half_edge(face_b, half_edge_b);
+
+    let cos = normal_a.dot(&normal_b);
+    let cos = if cos > Scalar::ONE {
+        Scalar::ONE
+    } else if cos < -Scalar::ONE {
+        -Scalar::ONE
+    } else {
+        cos
+    };
+    let angle_between_normals = cos.acos();
+
+    // Looking at the front side of a face, the face is to the left of its
+    // half-edges. If, from there, the normal of the other face is turned
+    // towards the front side, the edge is convex.
+    let direction = direction_at_half_edge(half_edge_a);
+    if normal_a.cross(&normal_b).dot(&direction) >= Scalar::ZERO {
+        Scalar::PI - angle_between_normals
+    } else {
+        Scalar::PI + angle_between_normals
+    }
+}
+
+fn normal_at_half_edge(face: &Face, half_edge: &HalfEdge) -> Vector<3> {
+    let point_surface = half_edge
+        .curve()
+        .path()
+        .point_from_path_coords(middle_of_half_edge(half_edge));
+    face.normal_at(point_surface)
+}
+
+fn direction_at_half_edge(half_edge: &HalfEdge) -> Vector<3> {
+    let tangent = half_edge
+        .curve()
+        .global_form()
+        .path()
+        .tangent_at(middle_of_half_edge(half_edge));
+
+    let [a, b] = half_edge.vertices().map(|vertex| vertex.position());
+    if a < b {
+        tangent
+    } else {
+        -tangent
+    }
+}
+
+fn middle_of_half_edge(half_edge: &HalfEdge) -> Point<1> {
+    let [a, b] = half_edge.vertices().map(|vertex| vertex.position());
+    Point::from([(a.t + b.t) / 2.])
+}
+
+/// Compute the signed volume of the cone from the origin to a face
+///
+/// Summed up over all faces of a closed shell, this results in the volume of
+/// the shell, which is negative, if the shell is inside-out.
+fn signed_volume(face: &Face) -> Scalar {
+    let aabb = Aabb::<3>::from_points(
+        face.all_cycles()
+            .flat_map(|cycle| cycle.vertices())
+            .map(|vertex| vertex.global_form().position()),
+    );
+
+    // A coarse approximation is good enough to determine the sign of the
+    // volume.
+    let tolerance = match Tolerance::from_scalar(aabb.size().magnitude() / 100.)
+    {
+        Ok(tolerance) => tolerance,
+        Err(_) => return Scalar::ZERO,
+    };
+
+    face.approx(tolerance)
+        .triangulate(tolerance)
+        .triangles()
+        .map(|triangle| {
+            let [a, b, c] = triangle.inner.points().map(|point| point.coords);
+            a.dot(&b.cross(&c)) / 6.
+        })
+        .fold(Scalar::ZERO, |a, b| a + b)
+}
+
+fn find_root(parents: &[usize], mut i: usize) -> usize {
+    while parents[i] != i {
+        i = parents[i];
+    }
+    i
+}
+
+fn are_coplanar(a: &Face, b: &Face, tolerance: Tolerance) -> bool {
+    let origin = match (a.surface().u(), b.surface().u()) {
+        (GlobalPath::Line(line), GlobalPath::Line(_)) => line.origin(),
+        _ => return false,
+    };
+
+    // Both surfaces are planes, so the normals are the same everywhere.
+    let normal_a = a.normal_at(Point::origin());
+    let normal_b = b.normal_at(Point::origin());
+    if normal_a.dot(&normal_b) <= Scalar::ZERO {
+        return false;
+    }
+
+    b.all_cycles()
+        .flat_map(|cycle| cycle.vertices())
+        .all(|vertex| {
+            let distance = (vertex.global_form().position() - origin)
+                .dot(&normal_a)
+                .abs();
+            distance <= tolerance.inner()
+        })
+}
+
+fn merge_faces(faces: &[&Face], tolerance: Tolerance) -> Option<Face> {
+    let first = faces.first()?;
+    let surface = *first.surface();
+
+    let half_edges = faces
+        .iter()
+        .flat_map(|face| face.all_cycles())
+        .flat_map(|cycle| cycle.half_edges());
+
+    // Edges between the merged faces are referred to by two of their
+    // half-edges. Only those referred to once are part of the boundary.
+    let mut half_edges_by_edge: BTreeMap<GlobalEdge, Vec<&HalfEdge>> =
+        BTreeMap::new();
+    for half_edge in half_edges {
+        half_edges_by_edge
+            .entry(half_edge.global_form().normalize())
+            .or_default()
+            .push(half_edge);
+    }
+
+    let mut boundary: BTreeMap<GlobalVertex, &HalfEdge> = BTreeMap::new();
+    for half_edges in half_edges_by_edge.into_values() {
+        if let [half_edge] = half_edges.as_slice() {
+            let [start, _] = half_edge.vertices();
+
+            // If multiple boundary half-edges start at the same vertex, there
+            // is no unambiguous way to assemble them into cycles.
+            if boundary.insert(*start.global_form(), *half_edge).is_some() {
+                return None;
+            }
+        }
+    }
+
+    let mut surface_positions = BTreeMap::new();
+    let mut cycles = Vec::new();
+
+    while let Some(&start) = boundary.keys().next() {
+        let mut half_edges = Vec::new();
+        let mut current = start;
+
+        loop {
+            let half_edge = boundary.remove(&current)?;
+            half_edges.push(transfer_half_edge(
+                half_edge,
+                surface,
+                tolerance,
+                &mut surface_positions,
+            )?);
+
+            let [_, end] = half_edge.vertices();
+            current = *end.global_form();
+
+            if current == start {
+                break;
+            }
+        }
+
+        cycles.push(Cycle::new(surface, half_edges));
+    }
+
+    // The surface of the merged face is the surface of `first`, so the exterior
+    // of the merged face has the same winding as the exterior of `first`. All
+    // other cycles are holes.
+    let winding = first.exterior().winding();
+    let (mut exteriors, interiors): (Vec<_>, Vec<_>) = cycles
+        .into_iter()
+        .partition(|cycle| cycle.winding() == winding);
+
+    let exterior = exteriors.pop()?;
+    if !exteriors.is_empty() {
+        return None;
+    }
+
+    Some(
+        Face::new(surface, exterior)
+            .with_interiors(interiors)
+            .with_color(first.color()),
+    )
+}
+
+fn transfer_half_edge(
+    half_edge: &HalfEdge,
+    surface: Surface,
+    tolerance: Tolerance,
+    surface_positions: &mut BTreeMap<GlobalVertex, Point<2>>,
+) -> Option<HalfEdge> {
+    let curve = Curve::from_global_on_surface(
+        *half_edge.curve().global_form(),
+        surface,
+        tolerance,
+    )
+    .ok()?;
+
+    let vertices = half_edge.vertices().map(|vertex| {
+        let global_form = *vertex.global_form();
+
+        // The half-edges of a cycle must connect exactly, so vertices that
+        // are shared between half-edges need the same surface position.
+        let position =
+            *surface_positions.entry(global_form).or_insert_with(|| {
+                curve.path().point_from_path_coords(vertex.position())
+            });
+
+        let surface_form = SurfaceVertex::new(position, surface, global_form);
+        Vertex::new(vertex.position(), curve, surface_form, global_form)
+    });
+
+    Some(HalfEdge::new(curve, vertices, *half_edge.global_form()))
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        algorithms::{
+            reverse::Reverse, sweep::Sweep, transform::TransformObject,
+        },
+        objects::{Face, Shell, Surface},
+    };
+
+    use super::ReplaceFaceError;
+
+    #[test]
+    fn replace_face() {
+        let cube = Shell::build().cube_from_edge_length(1.);
+        let face = cube.faces().into_iter().next().unwrap().clone();
+
+        // The reversed face has the same edges, so it still connects to the
+        // rest of the cube.
+        let replaced = cube.replace_face(&face, face.clone().reverse());
+        assert!(replaced.is_ok());
+
+        let moved = face.clone().translate([0., 0., 2.]);
+        assert!(matches!(
+            cube.replace_face(&face, moved.clone()),
+            Err(ReplaceFaceError::SharedEdgeMissing(_))
+        ));
+        assert!(matches!(
+            cube.replace_face(&moved, face),
+            Err(ReplaceFaceError::FaceNotFound)
+        ));
+    }
+
+    #[test]
+    fn is_closed() {
+        let cube = Shell::build().cube_from_edge_length(1.);
+        assert!(cube.is_closed());
+
+        // Removing a face opens up the cube.
+        let faces = cube.faces().into_iter().skip(1).cloned();
+        assert!(!Shell::new().with_faces(faces).is_closed());
+    }
+
+    #[test]
+    fn fix_orientations() {
+        let face = Face::build(Surface::xy_plane())
+            .polygon_from_points([[0., 0.], [1., 0.], [1., 1.], [0., 1.]])
+            .into_face();
+        let cube = face.sweep([0., 0., 1.]);
+
+        let (fixed, num_flipped) = cube.clone().fix_orientations();
+        assert_eq!(num_flipped, 0);
+        assert_eq!(fixed, cube);
+
+        let inside_out = Shell::new().with_faces(
+            cube.faces().into_iter().map(|face| face.clone().reverse()),
+        );
+        let (fixed, num_flipped) = inside_out.fix_orientations();
+        assert_eq!(num_flipped, 6);
+        assert_eq!(fixed, cube);
+
+        // The faces of this cube are not consistently oriented.
+        let cube = Shell::build().cube_from_edge_length(1.);
+        let (fixed, _) = cube.fix_orientations();
+        let (_, num_flipped) = fixed.fix_orientations();
+        assert_eq!(num_flipped, 0);
+    }
+
+    #[test]
+    fn nearest_vertex() {
+        let cube = Shell::build().cube_from_edge_length(2.);
+
+        let (vertex, distance) = cube.nearest_vertex([1.5, 1., 1.]).unwrap();
+        assert_eq!(vertex.position(), Point::from([1., 1., 1.]));
+        assert_eq!(distance, Scalar::from(0.5));
+
+        assert_eq!(Shell::new().nearest_vertex([0., 0., 0.]), None);
+    }
+
+    #[test]
+    fn faces_order_is_independent_of_insertion_order() {
+        let faces = Shell::build()
+            .cube_from_edge_length(1.)
+            .into_faces()
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        let a = Shell::new().with_faces(faces.iter().cloned());
+        let b = Shell::new().with_faces(faces.iter().rev().cloned());
+
+        assert!(a.faces().into_iter().eq(b.faces()));
+    }
+
+    #[test]
+    fn dihedral_angles_of_cube() {
+        let face = Face::build(Surface::xy_plane())
+            .polygon_from_points([[0., 0.], [1., 0.], [1., 1.], [0., 1.]])
+            .into_face();
+        let cube = face.sweep([0., 0., 1.]);
+
+        let angles = cube.dihedral_angles();
+        assert_eq!(angles.len(), 12);
+
+        for angle in angles.values() {
+            assert!((*angle - Scalar::PI / 2.).abs() < Scalar::from(1e-12));
+        }
+    }
+
+    #[test]
+    fn merge_coplanar() {
+        // Two triangles that form a square in the xy-plane. They are defined
+        // in different surfaces, which still describe the same plane.
+        let a = Face::build(Surface::xy_plane())
+            .polygon_from_points([[0., 0.], [1., 0.], [1., 1.]])
+            .into_face();
+        let b = Face::build(Surface::plane_from_points([
+            [0., 0., 0.],
+            [1., 1., 0.],
+            [0., 1., 0.],
+        ]))
+        .polygon_from_points([[0., 0.], [1., 0.], [0., 1.]])
+        .into_face();
+
+        // A triangle in the xz-plane, that shares an edge with the square, but
+        // must not be merged with it.
+        let c = Face::build(Surface::xz_plane())
+            .polygon_from_points([[0., 0.], [0., 1.], [1., 0.]])
+            .into_face();
+
+        let shell = Shell::new().with_faces([a, b, c.clone()]);
+        let merged = shell.merge_coplanar(1e-9);
+
+        assert_eq!(merged.faces().into_iter().count(), 2);
+        assert!(merged.find_face(&c).is_some());
+
+        let square =
+            merged.faces().into_iter().find(|&face| face != &c).unwrap();
+        assert_eq!(square.interiors().count(), 0);
+
+        let mut vertices = square
+            .exterior()
+            .vertices()
+            .map(|vertex| vertex.global_form().position())
+            .collect::<Vec<_>>();
+        vertices.sort();
+        assert_eq!(
+            vertices,
+            [[0., 0., 0.], [0., 1., 0.], [1., 0., 0.], [1., 1., 0.]]
+                .map(Point::from)
+        );
+    }
+}
@@ -1,6 +1,17 @@
-use crate::builder::SketchBuilder;
+use fj_math::{
+    signed_area, Aabb, Circle, Line, Point, Scalar, Vector, Winding,
+};
 
-use super::{face::Faces, Face, Surface};
+use crate::{
+    algorithms::{reverse::Reverse, transform::TransformObject},
+    builder::SketchBuilder,
+    path::SurfacePath,
+};
+
+use super::{
+    face::Faces, Curve, Cycle, Face, GlobalEdge, HalfEdge, Surface,
+    SurfaceVertex, Vertex,
+};
 
 /// A 2-dimensional shape
 ///
@@ -47,6 +58,111 @@ impl Sketch {
     pub fn into_faces(self) -> Faces {
         self.faces
     }
+
+    /// Normalize the sketch
+    ///
+    /// Brings the sketch into a canonical form, so that sketches that describe
+    /// the same shape compare equal:
+    ///
+    /// - Exterior cycles are wound counter-clockwise, interior cycles
+    ///   clockwise.
+    /// - Polygonal cycles start at their smallest vertex, and collinear
+    ///   adjacent edges are merged into a single edge.
+    /// - The interior cycles of each face are sorted.
+    #[must_use]
+    pub fn normalize(self) -> Self {
+        let faces = self.faces.into_iter().map(|face| {
+            let surface = *face.surface();
+
+            let exterior =
+                normalize_cycle(face.exterior().clone(), Winding::Ccw);
+            let mut interiors = face
+                .interiors()
+                .map(|cycle| normalize_cycle(cycle.clone(), Winding::Cw))
+                .collect::<Vec<_>>();
+            interiors.sort();
+
+            Face::new(surface, exterior)
+                .with_interiors(interiors)
+                .with_color(face.color())
+        });
+
+        Self::new().with_faces(faces)
+    }
+
+    /// Compute the bounding box of the sketch, in surface coordinates
+    ///
+    /// Takes the full extent of circular edges into account, not just their
+    /// vertices.
+    ///
+    /// Returns `None`, if the sketch has no faces.
+    pub fn bounding_box(&self) -> Option<Aabb<2>> {
+        let mut points = Vec::new();
+
+        for face in &self.faces {
+            for half_edge in face.exterior().half_edges() {
+                let path = half_edge.curve().path();
+                let positions =
+                    half_edge.vertices().map(|vertex| vertex.position().t);
+
+                for t in positions {
+                    points.push(path.point_from_path_coords([t]));
+                }
+
+                if let SurfacePath::Circle(circle) = path {
+                    let [a, b] = positions;
+                    let (start, end) = if a < b { (a, b) } else { (b, a) };
+
+                    for t in circle_extrema(&circle) {
+                        // Bring the angle into the range of the edge.
+                        let t = t
+                            + ((start - t) / Scalar::TAU).ceil() * Scalar::TAU;
+
+                        if t <= end {
+                            points.push(circle.point_from_circle_coords([t]));
+                        }
+                    }
+                }
+            }
+        }
+
+        if points.is_empty() {
+            return None;
+        }
+
+        Some(Aabb::<2>::from_points(points))
+    }
+
+    /// Compute the center of the sketch, in surface coordinates
+    ///
+    /// This is the center of the sketch's [bounding box]. Returns `None`, if
+    /// the sketch has no faces.
+    ///
+    /// [bounding box]: Self::bounding_box
+    pub fn center(&self) -> Option<Point<2>> {
+        self.bounding_box().map(|aabb| aabb.center())
+    }
+
+    /// Move the sketch within its surface, so it is centered on the origin
+    ///
+    /// After this operation, [`Sketch::center`] returns the origin of the
+    /// surface. The surface itself stays the same. Expects the sketch to be
+    /// defined in a plane.
+    #[must_use]
+    pub fn centered(self) -> Self {
+        let center = match self.center() {
+            Some(center) => center,
+            None => return self,
+        };
+        let offset = -center.coords;
+
+        let faces = self
+            .faces
+            .into_iter()
+            .map(|face| translate_face(&face, offset));
+
+        Self::new().with_faces(faces)
+    }
 }
 
 impl Default for Sketch {
@@ -54,3 +170,185 @@ impl Default for Sketch {
         Self::new()
     }
 }
+
+fn normalize_cycle(cycle: Cycle, winding: Winding) -> Cycle {
+    let is_polygon = cycle.half_edges().all(|half_edge| {
+        matches!(half_edge.curve().path(), SurfacePath::Line(_))
+    });
+
+    if !is_polygon {
+        // Cycles that contain circles can't be re-built from their vertices.
+        // All we can do is make sure they are wound correctly.
+        return if cycle.winding() == winding {
+            cycle
+        } else {
+            cycle.reverse()
+        };
+    }
+
+    let mut points = cycle
+        .vertices()
+        .map(|vertex| vertex.surface_form().position())
+        .collect::<Vec<_>>();
+
+    // Merge collinear edges, by removing the vertex between them.
+    let mut i = 0;
+    while i < points.len() && points.len() > 3 {
+        let prev = points[(i + points.len() - 1) % points.len()];
+        let next = points[(i + 1) % points.len()];
+        let a = points[i] - prev;
+        let b = next - points[i];
+
+        let is_collinear = a.cross(&b).abs()
+            <= Scalar::from(f64::EPSILON) * a.magnitude() * b.magnitude()
+            && a.dot(&b) > Scalar::ZERO;
+
+        if is_collinear {
+            points.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+
+    if signed_area(&points) > Scalar::ZERO {
+        if winding == Winding::Cw {
+            points.reverse();
+        }
+    } else if winding == Winding::Ccw {
+        points.reverse();
+    }
+
+    let start = points
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, point)| **point)
+        .map(|(i, _)| i)
+        .unwrap_or(0);
+    points.rotate_left(start);
+
+    Cycle::build(*cycle.surface()).polygon_from_points(points)
+}
+
+/// Compute the circle coordinates of the extreme points of a circle
+///
+/// Returns the angles at which the circle reaches its minimum and maximum along
+/// each axis.
+fn circle_extrema(circle: &Circle<2>) -> [Scalar; 4] {
+    let [a, b] = [circle.a(), circle.b()];
+
+    // Along each axis, the circle is `a * cos(t) + b * sin(t)`, which has its
+    // extrema where the derivative `-a * sin(t) + b * cos(t)` is zero.
+    let t_u = b.u.atan2(a.u);
+    let t_v = b.v.atan2(a.v);
+
+    [t_u, t_u + Scalar::PI, t_v, t_v + Scalar::PI]
+}
+
+/// Translate a face within its surface
+fn translate_face(face: &Face, offset: Vector<2>) -> Face {
+    let surface = *face.surface();
+    let offset_global = surface.vector_from_surface_coords(offset);
+
+    let translate_cycle = |cycle: &Cycle| {
+        let half_edges = cycle.half_edges().map(|half_edge| {
+            let path = match half_edge.curve().path() {
+                SurfacePath::Circle(circle) => {
+                    SurfacePath::Circle(Circle::new(
+                        circle.center() + offset,
+                        circle.a(),
+                        circle.b(),
+                    ))
+                }
+                SurfacePath::Line(line) => {
+                    SurfacePath::Line(Line::from_origin_and_direction(
+                        line.origin() + offset,
+                        line.direction(),
+                    ))
+                }
+            };
+            let global_curve =
+                half_edge.curve().global_form().translate(offset_global);
+            let curve = Curve::new(surface, path, global_curve);
+
+            let vertices = half_edge.vertices().map(|vertex| {
+                let global_form = vertex.global_form().translate(offset_global);
+                let surface_form = SurfaceVertex::new(
+                    vertex.surface_form().position() + offset,
+                    surface,
+                    global_form,
+                );
+
+                Vertex::new(vertex.position(), curve, surface_form, global_form)
+            });
+
+            let global_edge = GlobalEdge::new(
+                global_curve,
+                vertices.map(|vertex| *vertex.global_form()),
+            );
+
+            HalfEdge::new(curve, vertices, global_edge)
+        });
+
+        Cycle::new(surface, half_edges)
+    };
+
+    Face::new(surface, translate_cycle(face.exterior()))
+        .with_interiors(face.interiors().map(translate_cycle))
+        .with_color(face.color())
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use fj_math::{Point, Scalar};
+
+    use crate::objects::{Cycle, Face, HalfEdge, Sketch, Surface};
+
+    #[test]
+    fn normalize() {
+        let surface = Surface::xy_plane();
+
+        let a = Sketch::build(surface).polygon_from_points([
+            [0., 0.],
+            [1., 0.],
+            [1., 1.],
+            [0., 1.],
+        ]);
+        let b = Sketch::build(surface).polygon_from_points([
+            [1., 1.],
+            [1., 0.],
+            [0., 0.],
+            [0., 0.5],
+            [0., 1.],
+        ]);
+
+        assert_ne!(a, b);
+        assert_eq!(a.normalize(), b.normalize());
+    }
+
+    #[test]
+    fn bounding_box_and_centered() {
+        let surface = Surface::xy_plane();
+
+        let circle = {
+            let half_edge = HalfEdge::build(surface).circle_from_radius(1.);
+            let cycle = Cycle::new(surface, [half_edge]);
+            Face::new(surface, cycle)
+        };
+        let square = Face::build(surface)
+            .polygon_from_points([[2., 0.], [4., 0.], [4., 2.], [2., 2.]])
+            .into_face();
+        let sketch = Sketch::new().with_faces([circle, square]);
+
+        let aabb = sketch.bounding_box().unwrap();
+        assert_eq!(aabb.min, Point::from([-1., -1.]));
+        assert_eq!(aabb.max, Point::from([4., 2.]));
+        assert_eq!(sketch.center(), Some(Point::from([1.5, 0.5])));
+
+        let center = sketch.centered().center().unwrap();
+        assert!(center.coords.magnitude() < Scalar::from(1e-12));
+
+        assert_eq!(Sketch::new().bounding_box(), None);
+    }
+}
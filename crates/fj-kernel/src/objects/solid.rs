@@ -1,8 +1,25 @@
-use std::collections::BTreeSet;
+use std::{
+    cmp::Ordering,
+    collections::BTreeSet,
+    hash::{Hash, Hasher},
+};
 
-use crate::builder::SolidBuilder;
+use fj_math::{Point, Scalar, Vector};
 
-use super::{Face, Shell};
+use crate::{
+    algorithms::{
+        approx::Tolerance,
+        intersect::{
+            ray_exact::{Ray, RayHit},
+            Intersect,
+        },
+        triangulate::Triangulate,
+    },
+    builder::SolidBuilder,
+    path::GlobalPath,
+};
+
+use super::{Face, GlobalEdge, Shell};
 
 /// A 3-dimensional shape
 ///
@@ -10,9 +27,13 @@ use super::{Face, Shell};
 ///
 /// The shells that form the boundaries of the solid must not intersect. This is
 /// not currently validated.
-#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+///
+/// Solids are compared by their shells. Like that of [`Face`], the sweep path
+/// of a solid is not considered when comparing or hashing solids.
+#[derive(Clone, Debug)]
 pub struct Solid {
     shells: BTreeSet<Shell>,
+    sweep_path: Option<Vector<3>>,
 }
 
 impl Solid {
@@ -25,6 +46,7 @@ impl Solid {
     pub fn new() -> Self {
         Self {
             shells: BTreeSet::new(),
+            sweep_path: None,
         }
     }
 
@@ -40,6 +62,14 @@ impl Solid {
         self
     }
 
+    /// Record the path along which the solid was swept
+    ///
+    /// Consumes the solid and returns the updated instance.
+    pub fn with_sweep_path(mut self, path: impl Into<Vector<3>>) -> Self {
+        self.sweep_path = Some(path.into());
+        self
+    }
+
     /// Access the solid's shells
     pub fn shells(&self) -> impl Iterator<Item = &Shell> {
         self.shells.iter()
@@ -50,6 +80,13 @@ impl Solid {
         self.shells.into_iter()
     }
 
+    /// Access the path along which the solid was swept
+    ///
+    /// Returns `None`, if the solid wasn't created by sweeping a sketch.
+    pub fn sweep_path(&self) -> Option<Vector<3>> {
+        self.sweep_path
+    }
+
     /// Find the given face in this solid
     pub fn find_face(&self, face: &Face) -> Option<Face> {
         for shell in self.shells() {
@@ -60,6 +97,293 @@ impl Solid {
 
         None
     }
+
+    /// Select all faces of the solid that match a predicate
+    ///
+    /// Faces are returned in a deterministic order, which only depends on
+    /// their geometry (see [`Faces`]). Since faces are identified by their
+    /// geometry, a selection will also match the corresponding faces of a
+    /// rebuilt solid, as long as those faces didn't change.
+    ///
+    /// [`Faces`]: super::Faces
+    pub fn select_faces(&self, predicate: impl Fn(&Face) -> bool) -> Vec<Face> {
+        let mut faces = self
+            .shells()
+            .flat_map(|shell| shell.faces().into_iter())
+            .filter(|face| predicate(face))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        // Each shell's faces are already sorted, but the solid might consist
+        // of multiple shells.
+        faces.sort();
+
+        faces
+    }
+
+    /// Determine whether the solid is convex
+    ///
+    /// See [`Solid::convexity`], for details on the check, and for a variant of
+    /// this method that provides information on why a solid is not convex.
+    pub fn is_convex(&self) -> bool {
+        self.convexity().is_convex()
+    }
+
+    /// Check the convexity of the solid
+    ///
+    /// A solid is considered convex, if it is bounded by a single shell, none
+    /// of its edges are concave (see [`Shell::dihedral_angles`]), and none of
+    /// its curved faces bulge inward.
+    pub fn convexity(&self) -> Convexity {
+        // A tiny bit of slack, so flat edges don't count as concave, due to
+        // numerical inaccuracy.
+        let max_angle = Scalar::PI + Scalar::from(1e-9);
+
+        let mut convexity = Convexity {
+            num_shells: self.shells.len(),
+            concave_edges: Vec::new(),
+            concave_faces: Vec::new(),
+        };
+
+        for shell in self.shells() {
+            for (edge, angle) in shell.dihedral_angles() {
+                if angle > max_angle {
+                    convexity.concave_edges.push(edge);
+                }
+            }
+
+            for face in shell.faces() {
+                if is_concave_face(face) {
+                    convexity.concave_faces.push(face.clone());
+                }
+            }
+        }
+
+        convexity
+    }
+
+    /// Cast a ray against the exact surfaces of the solid's faces
+    ///
+    /// Returns the nearest hit, or `None`, if the ray doesn't hit the solid.
+    /// Unlike casting a ray against a triangle mesh of the solid, the result
+    /// doesn't depend on the tolerance of an approximation.
+    ///
+    /// Only faces in planar and cylindrical surfaces are supported.
+    pub fn raycast_exact(&self, ray: &Ray) -> Option<RayHit> {
+        self.shells()
+            .flat_map(|shell| shell.faces())
+            .filter_map(|face| (ray, face).intersect())
+            .min_by_key(|hit| hit.distance)
+    }
+
+    /// Compute the inertia tensor of the solid, relative to its centroid
+    ///
+    /// The solid is assumed to have a uniform `density`. Its boundary is
+    /// triangulated using `tolerance`, and the mass moments of inertia are
+    /// integrated over the tetrahedra that connect each triangle to the
+    /// origin. The returned tensor is symmetric, and its off-diagonal elements
+    /// are the negated products of inertia.
+    ///
+    /// The integrals are signed, so the triangles of the boundary must be
+    /// consistently oriented. If all of them point inward, the result is
+    /// still correct, but mixed orientations result in garbage.
+    pub fn inertia_tensor(
+        &self,
+        density: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+    ) -> [[Scalar; 3]; 3] {
+        let density = density.into();
+        let mesh = self.triangulate(tolerance);
+
+        let mut volume = Scalar::ZERO;
+        let mut first = [Scalar::ZERO; 3];
+        let mut second = [[Scalar::ZERO; 3]; 3];
+
+        for triangle in mesh.triangles() {
+            let [a, b, c] = triangle.inner.points().map(|point| point.coords);
+            let det = a.dot(&b.cross(&c));
+            let [a, b, c] = [a, b, c].map(|vector| vector.components);
+
+            volume += det / 6.;
+
+            for (i, first) in first.iter_mut().enumerate() {
+                *first += det / 24. * (a[i] + b[i] + c[i]);
+            }
+
+            // Integral of `x_i * x_j` over the tetrahedron.
+            for (i, row) in second.iter_mut().enumerate() {
+                for (j, second) in row.iter_mut().enumerate() {
+                    *second += det / 120.
+                        * (a[i] * a[j] * 2.
+                            + b[i] * b[j] * 2.
+                            + c[i] * c[j] * 2.
+                            + a[i] * b[j]
+                            + a[j] * b[i]
+                            + a[i] * c[j]
+                            + a[j] * c[i]
+                            + b[i] * c[j]
+                            + b[j] * c[i]);
+                }
+            }
+        }
+
+        // All integrals are inverted, if the boundary points inward.
+        let sign = if volume < Scalar::ZERO {
+            -Scalar::ONE
+        } else {
+            Scalar::ONE
+        };
+        let mass = volume * sign * density;
+        if mass == Scalar::ZERO {
+            return [[Scalar::ZERO; 3]; 3];
+        }
+
+        let centroid = first.map(|m| m * sign * density / mass);
+
+        // The second moments, relative to the centroid.
+        let moment = |i: usize, j: usize| {
+            second[i][j] * sign * density - mass * centroid[i] * centroid[j]
+        };
+
+        let mut tensor = [[Scalar::ZERO; 3]; 3];
+        for (i, row) in tensor.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = if i == j {
+                    let [k, l] = [(i + 1) % 3, (i + 2) % 3];
+                    moment(k, k) + moment(l, l)
+                } else {
+                    -moment(i, j)
+                };
+            }
+        }
+
+        tensor
+    }
+
+    /// Compute the tightest cylinder around a given axis, that contains the
+    /// solid
+    ///
+    /// `axis` defines the direction of the cylinder. The cylinder is computed
+    /// from the points of the solid's triangulation, which approximates curved
+    /// faces using `tolerance`. This means the cylinder might be smaller than
+    /// the solid by up to `tolerance`.
+    ///
+    /// Returns `None`, if the solid has no faces.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `axis` has zero length.
+    pub fn bounding_cylinder(
+        &self,
+        axis: impl Into<Vector<3>>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Option<BoundingCylinder> {
+        let axis = axis.into();
+        let tolerance = tolerance.into();
+
+        assert_ne!(
+            axis.magnitude(),
+            Scalar::ZERO,
+            "Axis of bounding cylinder must not have zero length"
+        );
+        let axis = axis.normalize();
+
+        // Two directions that are perpendicular to the axis and each other.
+        let u = {
+            let [x, y, z] = axis.components.map(Scalar::abs);
+            let least_aligned = if x <= y && x <= z {
+                Vector::unit_x()
+            } else if y <= z {
+                Vector::unit_y()
+            } else {
+                Vector::unit_z()
+            };
+
+            axis.cross(&least_aligned).normalize()
+        };
+        let v = axis.cross(&u);
+
+        let mesh = self.triangulate(tolerance);
+
+        let mut points = Vec::new();
+        let mut min = Scalar::MAX;
+        let mut max = -Scalar::MAX;
+
+        for point in mesh.vertices() {
+            points.push(Point::from([
+                point.coords.dot(&u),
+                point.coords.dot(&v),
+            ]));
+
+            let height = point.coords.dot(&axis);
+            min = min.min(height);
+            max = max.max(height);
+        }
+
+        if points.is_empty() {
+            return None;
+        }
+
+        let (center, radius) = enclosing_circle(&points, tolerance.inner());
+        let center = Point::origin()
+            + u * center.u
+            + v * center.v
+            + axis * ((min + max) / 2.);
+
+        Some(BoundingCylinder {
+            center,
+            axis,
+            radius,
+            height: max - min,
+        })
+    }
+}
+
+/// A cylinder that bounds a solid
+///
+/// See [`Solid::bounding_cylinder`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BoundingCylinder {
+    /// The center of the cylinder
+    ///
+    /// This is the point on the cylinder's axis, that is halfway between its
+    /// two ends.
+    pub center: Point<3>,
+
+    /// The direction of the cylinder's axis, normalized
+    pub axis: Vector<3>,
+
+    /// The radius of the cylinder
+    pub radius: Scalar,
+
+    /// The extent of the cylinder along its axis
+    pub height: Scalar,
+}
+
+/// The result of a convexity check
+///
+/// See [`Solid::convexity`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Convexity {
+    /// The number of shells that bound the solid
+    ///
+    /// A convex solid is bounded by exactly one shell.
+    pub num_shells: usize,
+
+    /// The concave edges of the solid
+    pub concave_edges: Vec<GlobalEdge>,
+
+    /// The curved faces of the solid that bulge inward
+    pub concave_faces: Vec<Face>,
+}
+
+impl Convexity {
+    /// Indicate whether the checked solid is convex
+    pub fn is_convex(&self) -> bool {
+        self.num_shells == 1
+            && self.concave_edges.is_empty()
+            && self.concave_faces.is_empty()
+    }
 }
 
 impl Default for Solid {
@@ -67,3 +391,258 @@ impl Default for Solid {
         Self::new()
     }
 }
+
+impl Eq for Solid {}
+
+impl PartialEq for Solid {
+    fn eq(&self, other: &Self) -> bool {
+        self.shells == other.shells
+    }
+}
+
+impl Hash for Solid {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.shells.hash(state);
+    }
+}
+
+impl Ord for Solid {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.shells.cmp(&other.shells)
+    }
+}
+
+impl PartialOrd for Solid {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn is_concave_face(face: &Face) -> bool {
+    let circle = match face.surface().u() {
+        GlobalPath::Circle(circle) => circle,
+        GlobalPath::Line(_) => return false,
+    };
+
+    // Faces on a swept circle are curved the same way everywhere, so it's
+    // enough to check at a single point.
+    let point = match face.exterior().vertices().next() {
+        Some(vertex) => vertex.surface_form().position(),
+        None => return false,
+    };
+
+    let point_global = face.surface().point_from_surface_coords(point);
+    let center = circle.center() + face.surface().v() * point.v;
+
+    // If the face bulges outward, its normal points away from the center of
+    // the circle.
+    (point_global - center).dot(&face.normal_at(point)) < Scalar::ZERO
+}
+
+/// Compute the smallest circle that contains all points
+///
+/// This is Welzl's algorithm, in its iterative form. Points that are outside
+/// of the circle by no more than `epsilon` are considered to be contained.
+fn enclosing_circle(
+    points: &[Point<2>],
+    epsilon: Scalar,
+) -> (Point<2>, Scalar) {
+    let is_outside = |center: Point<2>, radius: Scalar, point: Point<2>| {
+        (point - center).magnitude() > radius + epsilon
+    };
+
+    let mut center = points[0];
+    let mut radius = Scalar::ZERO;
+
+    for (i, &a) in points.iter().enumerate() {
+        if !is_outside(center, radius, a) {
+            continue;
+        }
+
+        center = a;
+        radius = Scalar::ZERO;
+
+        for (j, &b) in points[..i].iter().enumerate() {
+            if !is_outside(center, radius, b) {
+                continue;
+            }
+
+            center = Point {
+                coords: (a.coords + b.coords) / 2.,
+            };
+            radius = (a - b).magnitude() / 2.;
+
+            for &c in &points[..j] {
+                if is_outside(center, radius, c) {
+                    (center, radius) = circumcircle([a, b, c]);
+                }
+            }
+        }
+    }
+
+    (center, radius)
+}
+
+/// Compute the circle that runs through three points
+///
+/// Falls back to the smallest circle that contains the points, if they are
+/// collinear.
+fn circumcircle([a, b, c]: [Point<2>; 3]) -> (Point<2>, Scalar) {
+    let [ab, ac] = [b - a, c - a];
+    let d = ab.cross(&ac) * 2.;
+
+    if d == Scalar::ZERO {
+        let [p, q] = [[a, b], [a, c], [b, c]]
+            .into_iter()
+            .max_by_key(|&[p, q]| (p - q).magnitude())
+            .expect("Array is not empty");
+
+        let center = Point {
+            coords: (p.coords + q.coords) / 2.,
+        };
+        return (center, (p - q).magnitude() / 2.);
+    }
+
+    let [ab_2, ac_2] = [ab.dot(&ab), ac.dot(&ac)];
+    let offset =
+        Vector::from([ac.v * ab_2 - ab.v * ac_2, ab.u * ac_2 - ac.u * ab_2])
+            / d;
+
+    (a + offset, offset.magnitude())
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar, Vector};
+
+    use crate::{
+        algorithms::sweep::Sweep,
+        objects::{Cycle, Face, HalfEdge, Sketch, Solid, Surface},
+    };
+
+    #[test]
+    fn inertia_tensor_of_cube() {
+        let cube = Sketch::build(Surface::xy_plane())
+            .polygon_from_points([[0., 0.], [2., 0.], [2., 2.], [0., 2.]])
+            .sweep([0., 0., 2.]);
+
+        let density = 3.;
+        let tensor = cube.inertia_tensor(density, 0.001);
+
+        // For a cube, the inertia about each axis through the centroid is
+        // `m * s^2 / 6`, and the products of inertia are zero.
+        let mass = density * 8.;
+        let expected = mass * 4. / 6.;
+
+        for (i, row) in tensor.iter().enumerate() {
+            for (j, &value) in row.iter().enumerate() {
+                let expected = if i == j { expected } else { 0. };
+                assert!((value - expected).abs() < Scalar::from(1e-9));
+            }
+        }
+    }
+
+    #[test]
+    fn select_faces() {
+        let cube = Sketch::build(Surface::xy_plane())
+            .polygon_from_points([[0., 0.], [1., 0.], [1., 1.], [0., 1.]])
+            .sweep([0., 0., 1.]);
+
+        let facing_up = cube.select_faces(|face| {
+            face.normal_at([0., 0.]).dot(&Vector::unit_z()) > Scalar::ZERO
+        });
+        assert_eq!(facing_up.len(), 1);
+        for face in &facing_up {
+            let point = face.surface().point_from_surface_coords([0., 0.]);
+            assert_eq!(point.z, Scalar::ONE);
+        }
+
+        let all = cube.select_faces(|_| true);
+        assert_eq!(all.len(), 6);
+
+        // Selections match the faces of an identical, rebuilt solid.
+        let rebuilt = Sketch::build(Surface::xy_plane())
+            .polygon_from_points([[0., 0.], [1., 0.], [1., 1.], [0., 1.]])
+            .sweep([0., 0., 1.]);
+        for face in &facing_up {
+            assert_eq!(rebuilt.find_face(face).as_ref(), Some(face));
+        }
+    }
+
+    #[test]
+    fn bounding_cylinder() {
+        let tolerance = 0.001;
+
+        let cylinder = {
+            let surface = Surface::xy_plane();
+            let half_edge = HalfEdge::build(surface).circle_from_radius(2.);
+            let face = Face::new(surface, Cycle::new(surface, [half_edge]));
+            Solid::new().with_shells([face.sweep([0., 0., 3.])])
+        };
+        let bounds = cylinder
+            .bounding_cylinder([0., 0., 1.], tolerance)
+            .expect("Solid is not empty");
+
+        assert!(
+            (bounds.radius - Scalar::from(2.)).abs() <= Scalar::from(tolerance)
+        );
+        assert_eq!(bounds.height, Scalar::from(3.));
+        assert!(
+            (bounds.center - Point::from([0., 0., 1.5])).magnitude()
+                < Scalar::from(1e-9)
+        );
+
+        let cube = Sketch::build(Surface::xy_plane())
+            .polygon_from_points([[0., 0.], [2., 0.], [2., 2.], [0., 2.]])
+            .sweep([0., 0., 1.]);
+        let bounds = cube
+            .bounding_cylinder([0., 0., 1.], tolerance)
+            .expect("Solid is not empty");
+
+        // The circle around the square runs through its corners.
+        let expected_radius = Scalar::from(2_f64.sqrt());
+        assert!((bounds.radius - expected_radius).abs() < Scalar::from(1e-9));
+        assert_eq!(bounds.height, Scalar::ONE);
+        assert!(
+            (bounds.center - Point::from([1., 1., 0.5])).magnitude()
+                < Scalar::from(1e-9)
+        );
+
+        assert!(Solid::new()
+            .bounding_cylinder([0., 0., 1.], tolerance)
+            .is_none());
+    }
+
+    #[test]
+    fn convexity() {
+        let surface = Surface::xy_plane();
+
+        let cube = Sketch::build(surface)
+            .polygon_from_points([[0., 0.], [1., 0.], [1., 1.], [0., 1.]])
+            .sweep([0., 0., 1.]);
+        assert!(cube.is_convex());
+
+        let cylinder = {
+            let half_edge = HalfEdge::build(surface).circle_from_radius(1.);
+            let cycle = Cycle::new(surface, [half_edge]);
+            let face = Face::new(surface, cycle);
+
+            Solid::new().with_shells([face.sweep([0., 0., 1.])])
+        };
+        assert!(cylinder.is_convex());
+
+        let l_shape = Sketch::build(surface)
+            .polygon_from_points([
+                [0., 0.],
+                [2., 0.],
+                [2., 1.],
+                [1., 1.],
+                [1., 2.],
+                [0., 2.],
+            ])
+            .sweep([0., 0., 1.]);
+        let convexity = l_shape.convexity();
+        assert!(!convexity.is_convex());
+        assert_eq!(convexity.concave_edges.len(), 1);
+    }
+}
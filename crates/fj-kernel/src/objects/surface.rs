@@ -1,6 +1,6 @@
 use fj_math::{Line, Point, Vector};
 
-use crate::path::GlobalPath;
+use crate::{algorithms::intersect::SurfaceIntersection, path::GlobalPath};
 
 /// A two-dimensional shape
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
@@ -80,6 +80,45 @@ impl Surface {
             + self.path_to_line().vector_from_line_coords([vector.v])
     }
 
+    /// Compute the tangents of the surface at the given point
+    ///
+    /// The point is given in surface coordinates. Returns the derivatives of
+    /// [`Surface::point_from_surface_coords`] along the u and v coordinates,
+    /// in that order. They are not normalized.
+    pub fn tangents_at(
+        &self,
+        point: impl Into<Point<2>>,
+    ) -> (Vector<3>, Vector<3>) {
+        let point = point.into();
+        (self.u.tangent_at([point.u]), self.v)
+    }
+
+    /// Compute the normal of the surface at the given point
+    ///
+    /// The point is given in surface coordinates. The normal is the normalized
+    /// cross product of the u and v [tangents](Surface::tangents_at). Surfaces
+    /// don't have an orientation, so this doesn't consider which side is the
+    /// front side of a face in the surface. Use [`Face::normal_at`] for that.
+    ///
+    /// [`Face::normal_at`]: crate::objects::Face::normal_at
+    pub fn normal_at(&self, point: impl Into<Point<2>>) -> Vector<3> {
+        let (tangent_u, tangent_v) = self.tangents_at(point);
+        tangent_u.cross(&tangent_v).normalize()
+    }
+
+    /// Compute the intersection of this surface with another one
+    ///
+    /// Returns the curve along which the surfaces intersect, in global
+    /// coordinates, so it can be converted into the coordinates of either
+    /// surface. Returns [`SurfaceIntersection::Coincident`], if both surfaces
+    /// are the same plane, and `None`, if they are parallel planes.
+    ///
+    /// Also returns `None`, if either surface is not a plane. Only planes are
+    /// supported so far.
+    pub fn intersect(&self, other: &Surface) -> Option<SurfaceIntersection> {
+        SurfaceIntersection::compute([self, other])
+    }
+
     fn path_to_line(&self) -> Line<3> {
         Line::from_origin_and_direction(self.u.origin(), self.v)
     }
@@ -87,10 +126,16 @@ impl Surface {
 
 #[cfg(test)]
 mod tests {
-    use fj_math::{Line, Point, Vector};
+    use fj_math::{Line, Point, Scalar, Vector};
     use pretty_assertions::assert_eq;
 
-    use crate::path::GlobalPath;
+    use crate::{
+        algorithms::{
+            intersect::SurfaceIntersection, transform::TransformObject,
+        },
+        objects::GlobalCurve,
+        path::GlobalPath,
+    };
 
     use super::Surface;
 
@@ -125,4 +170,76 @@ mod tests {
             Vector::from([0., 4., 8.]),
         );
     }
+
+    #[test]
+    fn normal_at() {
+        let plane = Surface::xy_plane();
+        assert_eq!(plane.normal_at([1., 2.]), Vector::from([0., 0., 1.]));
+
+        // The normal of a plane is `u` × `v`, which for the xz-plane is -y.
+        let plane = Surface::xz_plane();
+        assert_eq!(plane.normal_at([1., 2.]), Vector::from([0., -1., 0.]));
+
+        let cylinder = Surface {
+            u: GlobalPath::circle_from_radius(2.),
+            v: Vector::from([0., 0., 1.]),
+        };
+
+        for t in [0., 1., 2., 4.] {
+            let point = cylinder.point_from_surface_coords([t, 0.5]);
+            let radial = Vector::from([point.x, point.y, Scalar::ZERO]);
+
+            let (tangent_u, _) = cylinder.tangents_at([t, 0.5]);
+            assert!(tangent_u.dot(&radial).abs() < Scalar::from(1e-12));
+
+            // The normal is radial. Whether it points outward depends on the
+            // direction of the circle.
+            let normal = cylinder.normal_at([t, 0.5]);
+            let expected = radial.normalize();
+            assert!(
+                (normal - expected).magnitude() < Scalar::from(1e-12)
+                    || (normal + expected).magnitude() < Scalar::from(1e-12)
+            );
+        }
+
+        // Unlike that of a plane, the normal varies around the cylinder.
+        let a = cylinder.normal_at([0., 0.5]);
+        let b = cylinder.normal_at([Scalar::PI / 2., Scalar::from(0.5)]);
+        assert!(a.dot(&b).abs() < Scalar::from(1e-12));
+    }
+
+    #[test]
+    fn intersect() {
+        let xy = Surface::xy_plane();
+        let xz = Surface::xz_plane();
+
+        // Perpendicular planes intersect along an axis.
+        let x_axis = GlobalCurve::from_path(GlobalPath::x_axis());
+        assert_eq!(xy.intersect(&xz), Some(SurfaceIntersection::Curve(x_axis)));
+
+        // The curve is in global coordinates, and both surfaces share it.
+        let point = x_axis.path().point_from_path_coords([2.]);
+        assert_eq!(point, Point::from([2., 0., 0.]));
+        for surface in [xy, xz] {
+            assert_eq!(surface.point_from_surface_coords([2., 0.]), point);
+        }
+
+        // Parallel planes don't intersect.
+        let offset = xy.translate([0., 0., 1.]);
+        assert_eq!(xy.intersect(&offset), None);
+
+        // Coincident planes are detected, regardless of their orientation.
+        let flipped = Surface::new(GlobalPath::x_axis(), [0., -1., 0.]);
+        assert_eq!(xy.intersect(&xy), Some(SurfaceIntersection::Coincident));
+        assert_eq!(
+            xy.intersect(&flipped),
+            Some(SurfaceIntersection::Coincident)
+        );
+
+        // Intersections with other kinds of surfaces are not supported.
+        let cylinder =
+            Surface::new(GlobalPath::circle_from_radius(1.), [0., 0., 1.]);
+        assert_eq!(xy.intersect(&cylinder), None);
+        assert_eq!(cylinder.intersect(&xy), None);
+    }
 }
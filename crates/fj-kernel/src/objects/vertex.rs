@@ -1,7 +1,7 @@
-use fj_math::Point;
+use fj_math::{Point, PointKey, Scalar};
 use pretty_assertions::assert_eq;
 
-use crate::builder::VertexBuilder;
+use crate::{algorithms::approx::Tolerance, builder::VertexBuilder};
 
 use super::{Curve, Surface};
 
@@ -50,6 +50,25 @@ impl Vertex {
         }
     }
 
+    /// Construct a vertex at a position on a curve
+    ///
+    /// The surface and global forms of the vertex are computed from `curve`,
+    /// which makes sure they are consistent with `position`.
+    pub fn on_curve(curve: Curve, position: impl Into<Point<1>>) -> Self {
+        let position = position.into();
+
+        let global_form = GlobalVertex::from_position(
+            curve.global_form().path().point_from_path_coords(position),
+        );
+        let surface_form = SurfaceVertex::new(
+            curve.path().point_from_path_coords(position),
+            *curve.surface(),
+            global_form,
+        );
+
+        Self::new(position, curve, surface_form, global_form)
+    }
+
     /// Access the position of the vertex on the curve
     pub fn position(&self) -> Point<1> {
         self.position
@@ -144,4 +163,46 @@ impl GlobalVertex {
     pub fn position(&self) -> Point<3> {
         self.position
     }
+
+    /// Compute the distance to another vertex
+    pub fn distance_to(&self, other: &Self) -> Scalar {
+        (self.position - other.position).magnitude()
+    }
+
+    /// Compute a key that can be used to order vertices robustly
+    ///
+    /// The position of the vertex is quantized to a grid, whose cell size is
+    /// `tolerance`. See [`Point::key`] for details, and note that the resulting
+    /// order is tolerance-quantized, not exact.
+    pub fn key(&self, tolerance: impl Into<Tolerance>) -> PointKey<3> {
+        self.position.key(tolerance.into().inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::objects::{Curve, Surface};
+
+    use super::Vertex;
+
+    #[test]
+    fn on_curve() {
+        let surface = Surface::xz_plane();
+        let curve = Curve::build(surface).circle_from_radius(1.);
+
+        let vertex = Vertex::on_curve(curve, [Scalar::PI / 2.]);
+
+        assert_eq!(vertex.position(), Point::from([Scalar::PI / 2.]));
+        assert_eq!(vertex.curve(), &curve);
+        assert_eq!(vertex.surface_form().surface(), &surface);
+        assert_eq!(vertex.surface_form().global_form(), vertex.global_form());
+
+        let point_from_surface =
+            surface.point_from_surface_coords(vertex.surface_form().position());
+        let distance =
+            (vertex.global_form().position() - point_from_surface).magnitude();
+        assert!(distance < Scalar::from(1e-12));
+    }
 }
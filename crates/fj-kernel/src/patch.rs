@@ -0,0 +1,308 @@
+//! Parametric surface patches
+//!
+//! See [`SurfacePatch`].
+//!
+//! # Implementation Note
+//!
+//! [`Surface`] can only represent surfaces that are created by sweeping a path
+//! along a straight line, and a [`Face`] is always defined in such a surface.
+//! Patches don't fit that model, which is why faces that are bounded in a
+//! patch are approximated using planar triangles, when they are created. See
+//! [`SurfacePatch::faces_from_boundary`].
+//!
+//! [`Surface`]: crate::objects::Surface
+//! [`Face`]: crate::objects::Face
+
+use fj_interop::mesh::Color;
+use fj_math::{signed_area, Point, Scalar, Vector};
+
+use crate::{
+    algorithms::{approx::Tolerance, triangulate::triangulate_2d},
+    builder::triangle_from_points,
+    objects::{Face, Shell, Surface},
+};
+
+/// A parametric surface patch
+///
+/// Patches are defined over the domain `[0, 1] x [0, 1]` of patch coordinates
+/// `u` and `v`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum SurfacePatch {
+    /// A bilinear patch
+    Bilinear(BilinearPatch),
+}
+
+impl SurfacePatch {
+    /// Construct a bilinear patch from its corners
+    ///
+    /// See [`BilinearPatch::from_corners`].
+    pub fn bilinear(corners: [impl Into<Point<3>>; 4]) -> Self {
+        Self::Bilinear(BilinearPatch::from_corners(corners))
+    }
+
+    /// Convert a point in patch coordinates to model coordinates
+    pub fn point_from_patch_coords(
+        &self,
+        point: impl Into<Point<2>>,
+    ) -> Point<3> {
+        match self {
+            Self::Bilinear(patch) => patch.point_from_patch_coords(point),
+        }
+    }
+
+    /// Create faces from a boundary in patch coordinates
+    ///
+    /// `boundary` is a polygon in patch coordinates, which bounds the region
+    /// of the patch that the faces cover. The region is sampled, so the
+    /// triangular faces that approximate it deviate from the patch by no more
+    /// than `tolerance`. Points of `boundary` are always part of the result.
+    ///
+    /// The front side of the faces points into the direction of the cross
+    /// product of the `u` and `v` derivatives of the patch, if `boundary` is
+    /// wound counter-clockwise.
+    ///
+    /// Returns an error, if `boundary` has fewer than three points, or if any
+    /// of its points lie outside of the domain of the patch.
+    pub fn faces_from_boundary(
+        &self,
+        boundary: impl IntoIterator<Item = impl Into<Point<2>>>,
+        tolerance: impl Into<Tolerance>,
+        color: Color,
+    ) -> Result<Shell, PatchError> {
+        let boundary = boundary.into_iter().map(Into::into).collect::<Vec<_>>();
+        let tolerance = tolerance.into();
+
+        if boundary.len() < 3 {
+            return Err(PatchError::TooFewPoints(boundary.len()));
+        }
+        for &point in &boundary {
+            let is_inside_domain = [point.u, point.v]
+                .into_iter()
+                .all(|coord| coord >= Scalar::ZERO && coord <= Scalar::ONE);
+            if !is_inside_domain {
+                return Err(PatchError::OutsideDomain(point));
+            }
+        }
+
+        let num_cells = self.num_cells(tolerance);
+        let cell_size = Scalar::ONE / Scalar::from_f64(num_cells as f64);
+
+        let is_ccw = signed_area(&boundary) > Scalar::ZERO;
+
+        // Subdivide the boundary, so its segments are no longer than the cells
+        // of the sampling grid.
+        let mut points = Vec::new();
+        for (i, &a) in boundary.iter().enumerate() {
+            let b = boundary[(i + 1) % boundary.len()];
+
+            let num_segments =
+                ((b - a).magnitude() / cell_size).ceil().into_f64() as usize;
+            let num_segments = num_segments.max(1);
+
+            for j in 0..num_segments {
+                let t = Scalar::from_f64(j as f64 / num_segments as f64);
+                points.push(a + (b - a) * t);
+            }
+        }
+        let segments = (0..points.len())
+            .map(|i| [i, (i + 1) % points.len()])
+            .collect::<Vec<_>>();
+
+        // Add the points of the sampling grid that are inside of the boundary.
+        // Points that are too close to it are skipped, to prevent slivers.
+        let boundary_face = Face::build(Surface::xy_plane())
+            .polygon_from_points(boundary.iter().copied())
+            .into_face();
+        let num_boundary_points = points.len();
+        for i in 0..=num_cells {
+            for j in 0..=num_cells {
+                let point = Point::from([
+                    Scalar::from_f64(i as f64) * cell_size,
+                    Scalar::from_f64(j as f64) * cell_size,
+                ]);
+
+                if !boundary_face.contains_point(point) {
+                    continue;
+                }
+                let boundary = &points[..num_boundary_points];
+                if distance_to_polygon(point, boundary) < cell_size / 4. {
+                    continue;
+                }
+
+                points.push(point);
+            }
+        }
+
+        let mut faces = Vec::new();
+        for [a, b, c] in triangulate_2d(&points, &segments) {
+            // The triangles are wound counter-clockwise. Make sure their
+            // orientation matches that of the boundary.
+            let triangle = if is_ccw { [a, b, c] } else { [a, c, b] };
+
+            let points_global =
+                triangle.map(|i| self.point_from_patch_coords(points[i]));
+            faces.push(triangle_from_points(points_global, color));
+        }
+
+        Ok(Shell::new().with_faces(faces))
+    }
+
+    /// Compute the number of grid cells per axis required for `tolerance`
+    fn num_cells(&self, tolerance: Tolerance) -> usize {
+        match self {
+            Self::Bilinear(patch) => {
+                // Within a grid cell of size `h`, the bilinear patch deviates
+                // from a flat triangle by at most `|twist| * h^2 / 4`.
+                let twist = patch.twist().magnitude();
+                if twist == Scalar::ZERO {
+                    return 1;
+                }
+
+                let max_cell_size = Scalar::from_f64(
+                    (tolerance.inner() * 4. / twist).into_f64().sqrt(),
+                );
+                let num_cells = (Scalar::ONE / max_cell_size).ceil();
+
+                (num_cells.into_f64() as usize).max(1)
+            }
+        }
+    }
+}
+
+/// A bilinear surface patch
+///
+/// Interpolates linearly between its four corners, along both patch
+/// coordinates.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct BilinearPatch {
+    corners: [Point<3>; 4],
+}
+
+impl BilinearPatch {
+    /// Construct a bilinear patch from its corners
+    ///
+    /// The corners are expected at the patch coordinates `[0, 0]`, `[1, 0]`,
+    /// `[1, 1]`, and `[0, 1]`, in that order.
+    pub fn from_corners(corners: [impl Into<Point<3>>; 4]) -> Self {
+        let corners = corners.map(Into::into);
+        Self { corners }
+    }
+
+    /// Access the corners of the patch
+    pub fn corners(&self) -> [Point<3>; 4] {
+        self.corners
+    }
+
+    /// Convert a point in patch coordinates to model coordinates
+    pub fn point_from_patch_coords(
+        &self,
+        point: impl Into<Point<2>>,
+    ) -> Point<3> {
+        let point = point.into();
+        let [a, b, c, d] = self.corners;
+
+        let bottom = a + (b - a) * point.u;
+        let top = d + (c - d) * point.u;
+
+        bottom + (top - bottom) * point.v
+    }
+
+    /// The vector that describes how much the patch is twisted
+    ///
+    /// This is the coefficient of the `u * v` term of the patch. It is zero,
+    /// if the patch is a parallelogram, which means the patch is flat.
+    fn twist(&self) -> Vector<3> {
+        let [a, b, c, d] = self.corners;
+        (a - b) + (c - d)
+    }
+}
+
+/// Error creating faces from a [`SurfacePatch`]
+#[derive(Debug, thiserror::Error)]
+pub enum PatchError {
+    /// The boundary doesn't have enough points to bound a region
+    #[error("Boundary must have at least three points (has {0})")]
+    TooFewPoints(usize),
+
+    /// A point of the boundary lies outside of the domain of the patch
+    #[error("Boundary point outside of patch domain: {0:?}")]
+    OutsideDomain(Point<2>),
+}
+
+fn distance_to_polygon(point: Point<2>, polygon: &[Point<2>]) -> Scalar {
+    polygon
+        .iter()
+        .enumerate()
+        .map(|(i, &a)| {
+            let b = polygon[(i + 1) % polygon.len()];
+            let ab = b - a;
+
+            let t = ((point - a).dot(&ab) / ab.dot(&ab))
+                .clamp(Scalar::ZERO, Scalar::ONE);
+            (point - (a + ab * t)).magnitude()
+        })
+        .fold(Scalar::MAX, Scalar::min)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::mesh::Color;
+    use fj_math::{Point, Scalar};
+
+    use crate::iter::ObjectIters;
+
+    use super::{PatchError, SurfacePatch};
+
+    #[test]
+    fn flat_bilinear_patch() -> anyhow::Result<()> {
+        let patch = SurfacePatch::bilinear([
+            [0., 0., 0.],
+            [2., 0., 0.],
+            [2., 2., 0.],
+            [0., 2., 0.],
+        ]);
+
+        let boundary = [[0., 0.], [1., 0.], [1., 1.], [0., 1.]];
+        let shell =
+            patch.faces_from_boundary(boundary, 0.01, Color::default())?;
+
+        // The patch is flat, so no sampling is required.
+        assert_eq!(shell.faces().into_iter().count(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn twisted_bilinear_patch() -> anyhow::Result<()> {
+        let patch = SurfacePatch::bilinear([
+            [0., 0., 0.],
+            [1., 0., 0.],
+            [1., 1., 1.],
+            [0., 1., 0.],
+        ]);
+
+        let boundary = [[0.25, 0.25], [0.75, 0.25], [0.75, 0.75], [0.25, 0.75]];
+        let shell =
+            patch.faces_from_boundary(boundary, 0.001, Color::default())?;
+        assert!(shell.faces().into_iter().count() > 2);
+
+        // All vertices lie on the patch, which has `z == x * y`.
+        for vertex in shell.global_vertex_iter() {
+            let [x, y, z] = vertex.position().coords.components;
+            assert!((z - x * y).abs() < Scalar::from(1e-12));
+        }
+
+        let result = patch.faces_from_boundary(
+            [[0., 0.], [1.5, 0.], [0., 1.]],
+            0.001,
+            Color::default(),
+        );
+        assert!(matches!(
+            result,
+            Err(PatchError::OutsideDomain(point))
+                if point == Point::from([1.5, 0.])
+        ));
+
+        Ok(())
+    }
+}
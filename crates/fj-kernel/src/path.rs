@@ -60,6 +60,18 @@ impl SurfacePath {
             Self::Line(line) => line.point_from_line_coords(point),
         }
     }
+
+    /// Compute the tangent of the path at the given point
+    ///
+    /// The tangent points in the direction of increasing path coordinates. It
+    /// is the derivative of [`SurfacePath::point_from_path_coords`], and thus
+    /// not normalized.
+    pub fn tangent_at(&self, point: impl Into<Point<1>>) -> Vector<2> {
+        match self {
+            Self::Circle(circle) => circle_tangent(circle, point.into()),
+            Self::Line(line) => line.direction(),
+        }
+    }
 }
 
 /// A path through global (3D) space
@@ -120,6 +132,33 @@ impl GlobalPath {
         }
     }
 
+    /// Convert a point in global coordinates into path coordinates
+    ///
+    /// Projects the point onto the path before the conversion. For circles,
+    /// the resulting coordinate is between `0.` (inclusive) and `PI * 2.`
+    /// (exclusive).
+    pub fn point_to_path_coords(&self, point: impl Into<Point<3>>) -> Point<1> {
+        let point = point.into();
+
+        match self {
+            Self::Circle(circle) => {
+                let vector = point - circle.center();
+                let angle = Scalar::atan2(
+                    vector.dot(&circle.b()),
+                    vector.dot(&circle.a()),
+                );
+
+                let coord = if angle >= Scalar::ZERO {
+                    angle
+                } else {
+                    angle + Scalar::TAU
+                };
+                Point::from([coord])
+            }
+            Self::Line(line) => line.point_to_line_coords(point),
+        }
+    }
+
     /// Convert a point on the path into global coordinates
     pub fn point_from_path_coords(
         &self,
@@ -141,4 +180,80 @@ impl GlobalPath {
             Self::Line(line) => line.vector_from_line_coords(vector),
         }
     }
+
+    /// Compute the tangent of the path at the given point
+    ///
+    /// The tangent points in the direction of increasing path coordinates. It
+    /// is the derivative of [`GlobalPath::point_from_path_coords`], and thus
+    /// not normalized.
+    pub fn tangent_at(&self, point: impl Into<Point<1>>) -> Vector<3> {
+        match self {
+            Self::Circle(circle) => circle_tangent(circle, point.into()),
+            Self::Line(line) => line.direction(),
+        }
+    }
+}
+
+fn circle_tangent<const D: usize>(
+    circle: &Circle<D>,
+    point: Point<1>,
+) -> Vector<D> {
+    let (sin, cos) = point.t.sin_cos();
+    circle.b() * cos - circle.a() * sin
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Circle, Point, Scalar, Vector};
+    use pretty_assertions::assert_eq;
+
+    use super::{GlobalPath, SurfacePath};
+
+    #[test]
+    fn line() {
+        let path = GlobalPath::line_from_points([[1., 0., 0.], [1., 2., 0.]]);
+
+        assert_eq!(
+            path.point_from_path_coords([0.5]),
+            Point::from([1., 1., 0.])
+        );
+        assert_eq!(path.tangent_at([0.5]), Vector::from([0., 2., 0.]));
+        assert_eq!(path.point_to_path_coords([2., 1., 1.]), Point::from([0.5]));
+    }
+
+    #[test]
+    fn global_circle() {
+        let path = GlobalPath::Circle(Circle::new(
+            [0., 0., 1.],
+            [0., 2., 0.],
+            [0., 0., 2.],
+        ));
+
+        for t in [0., 1., 3., 5.] {
+            let point = path.point_from_path_coords([t]);
+            let coord = path.point_to_path_coords(point);
+
+            assert!((coord.t - Scalar::from(t)).abs() < Scalar::from(1e-12));
+        }
+    }
+
+    #[test]
+    fn circle() {
+        let path = SurfacePath::circle_from_radius(2.);
+
+        assert_eq!(path.point_from_path_coords([0.]), Point::from([2., 0.]));
+        assert_eq!(path.tangent_at([0.]), Vector::from([0., 2.]));
+
+        let t = Scalar::PI / 2.;
+        let point = path.point_from_path_coords([t]);
+        let tangent = path.tangent_at([t]);
+
+        assert!(
+            (point - Point::from([0., 2.])).magnitude() < Scalar::from(1e-12)
+        );
+        assert!(
+            (tangent - Vector::from([-2., 0.])).magnitude()
+                < Scalar::from(1e-12)
+        );
+    }
 }
@@ -1,6 +1,6 @@
 use parry3d_f64::bounding_volume::BoundingVolume as _;
 
-use super::{Point, Vector};
+use super::{Point, Scalar, Vector};
 
 /// An axis-aligned bounding box (AABB)
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
@@ -15,34 +15,131 @@ pub struct Aabb<const D: usize> {
 
 impl<const D: usize> Aabb<D> {
     /// Determine whether the AABB contains a given point
+    ///
+    /// Points on the boundary of the AABB are considered to be contained.
     pub fn contains(&self, point: impl Into<Point<D>>) -> bool {
+        self.contains_with_tolerance(point, Scalar::ZERO)
+    }
+
+    /// Determine whether the AABB contains a given point, within a tolerance
+    ///
+    /// Like [`Aabb::contains`], but also considers points to be contained, if
+    /// they are outside of the AABB by no more than `tolerance` along any axis.
+    pub fn contains_with_tolerance(
+        &self,
+        point: impl Into<Point<D>>,
+        tolerance: impl Into<Scalar>,
+    ) -> bool {
         let point = point.into();
+        let tolerance = tolerance.into();
 
-        let min = self
+        let bounds = self
             .min
             .coords
             .components
             .into_iter()
+            .zip(self.max.coords.components)
             .zip(point.coords.components);
-        for (min, p) in min {
-            if min > p {
+        for ((min, max), p) in bounds {
+            if p < min - tolerance || p > max + tolerance {
                 return false;
             }
         }
 
-        let max = self
-            .max
-            .coords
-            .components
-            .into_iter()
-            .zip(point.coords.components);
-        for (max, p) in max {
-            if max < p {
-                return false;
-            }
+        true
+    }
+
+    /// Determine whether the AABB fully contains another AABB
+    ///
+    /// An AABB that touches the boundary of this one from the inside is
+    /// considered to be contained.
+    pub fn contains_aabb(&self, other: &Self) -> bool {
+        self.contains_aabb_with_tolerance(other, Scalar::ZERO)
+    }
+
+    /// Determine whether the AABB contains another AABB, within a tolerance
+    ///
+    /// See [`Aabb::contains_with_tolerance`].
+    pub fn contains_aabb_with_tolerance(
+        &self,
+        other: &Self,
+        tolerance: impl Into<Scalar>,
+    ) -> bool {
+        let tolerance = tolerance.into();
+
+        self.contains_with_tolerance(other.min, tolerance)
+            && self.contains_with_tolerance(other.max, tolerance)
+    }
+
+    /// Merge this AABB with another
+    ///
+    /// Returns the smallest AABB that contains both AABBs.
+    pub fn merged(&self, other: &Self) -> Self {
+        let min = std::array::from_fn(|i| {
+            self.min.coords.components[i].min(other.min.coords.components[i])
+        });
+        let max = std::array::from_fn(|i| {
+            self.max.coords.components[i].max(other.max.coords.components[i])
+        });
+
+        Self {
+            min: min.into(),
+            max: max.into(),
         }
+    }
 
-        true
+    /// Compute the intersection of this AABB with another
+    ///
+    /// Returns `None`, if the AABBs are disjoint. AABBs that only touch result
+    /// in a degenerate AABB, which has zero size along at least one axis.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let min: [Scalar; D] = std::array::from_fn(|i| {
+            self.min.coords.components[i].max(other.min.coords.components[i])
+        });
+        let max: [Scalar; D] = std::array::from_fn(|i| {
+            self.max.coords.components[i].min(other.max.coords.components[i])
+        });
+
+        if min.iter().zip(&max).any(|(min, max)| min > max) {
+            return None;
+        }
+
+        Some(Self {
+            min: min.into(),
+            max: max.into(),
+        })
+    }
+
+    /// Compute the minimum distance between this AABB and another
+    ///
+    /// Returns zero, if the AABBs overlap or touch.
+    pub fn distance_to(&self, other: &Self) -> Scalar {
+        let gaps: [Scalar; D] = std::array::from_fn(|i| {
+            let before =
+                other.min.coords.components[i] - self.max.coords.components[i];
+            let after =
+                self.min.coords.components[i] - other.max.coords.components[i];
+
+            before.max(after).max(Scalar::ZERO)
+        });
+
+        Vector::from(gaps).magnitude()
+    }
+
+    /// Compute the point within the AABB that is closest to the given point
+    ///
+    /// Points that are contained in the AABB are returned unchanged.
+    pub fn closest_point(&self, point: impl Into<Point<D>>) -> Point<D> {
+        let point = point.into();
+
+        let coords: [Scalar; D] = std::array::from_fn(|i| {
+            point.coords.components[i].clamp(
+                self.min.coords.components[i],
+                self.max.coords.components[i],
+            )
+        });
+
+        coords.into()
     }
 }
 
@@ -67,6 +164,24 @@ impl Aabb<2> {
             max: aabb.maxs.into(),
         }
     }
+
+    /// Convert the AABB to a Parry AABB
+    pub fn to_parry(self) -> parry2d_f64::bounding_volume::AABB {
+        parry2d_f64::bounding_volume::AABB {
+            mins: self.min.to_na(),
+            maxs: self.max.to_na(),
+        }
+    }
+
+    /// Compute the center point of the AABB
+    pub fn center(&self) -> Point<2> {
+        self.to_parry().center().into()
+    }
+
+    /// Compute the size of the AABB
+    pub fn size(&self) -> Vector<2> {
+        self.to_parry().extents().into()
+    }
 }
 
 impl Aabb<3> {
@@ -121,11 +236,6 @@ impl Aabb<3> {
 
         Self::from_parry(aabb)
     }
-
-    /// Merge this AABB with another
-    pub fn merged(&self, other: &Self) -> Self {
-        self.to_parry().merged(&other.to_parry()).into()
-    }
 }
 
 impl From<parry2d_f64::bounding_volume::AABB> for Aabb<2> {
@@ -142,8 +252,76 @@ impl From<parry3d_f64::bounding_volume::AABB> for Aabb<3> {
 
 #[cfg(test)]
 mod tests {
+    use crate::{Point, Scalar, Vector};
+
     use super::Aabb;
 
+    #[test]
+    fn merged() {
+        let a = Aabb::<3>::from_points([[0., 0., 0.], [1., 1., 1.]]);
+        let b = Aabb::<3>::from_points([[2., -1., 0.5], [3., 0.5, 0.5]]);
+
+        assert_eq!(
+            a.merged(&b),
+            Aabb::<3>::from_points([[0., -1., 0.], [3., 1., 1.]])
+        );
+    }
+
+    #[test]
+    fn intersection() {
+        let a = Aabb::<2>::from_points([[0., 0.], [2., 2.]]);
+
+        let overlapping = Aabb::<2>::from_points([[1., 1.], [3., 3.]]);
+        assert_eq!(
+            a.intersection(&overlapping),
+            Some(Aabb::<2>::from_points([[1., 1.], [2., 2.]]))
+        );
+
+        // Touching AABBs result in a degenerate intersection.
+        let touching = Aabb::<2>::from_points([[2., 0.], [3., 1.]]);
+        assert_eq!(
+            a.intersection(&touching),
+            Some(Aabb::<2>::from_points([[2., 0.], [2., 1.]]))
+        );
+
+        let disjoint = Aabb::<2>::from_points([[3., 0.], [4., 1.]]);
+        assert_eq!(a.intersection(&disjoint), None);
+    }
+
+    #[test]
+    fn distance_to() {
+        let a = Aabb::<3>::from_points([[0., 0., 0.], [1., 1., 1.]]);
+
+        let overlapping =
+            Aabb::<3>::from_points([[0.5, 0.5, 0.5], [2., 2., 2.]]);
+        assert_eq!(a.distance_to(&overlapping), Scalar::ZERO);
+        assert_eq!(a.distance_to(&a), Scalar::ZERO);
+
+        // Separated along one axis only.
+        let beside = Aabb::<3>::from_points([[3., 0.5, 0.], [4., 2., 1.]]);
+        assert_eq!(a.distance_to(&beside), Scalar::from(2.));
+
+        // Separated diagonally, so the distance is between the closest corners.
+        let diagonal = Aabb::<3>::from_points([[2., 3., 3.], [4., 4., 4.]]);
+        assert_eq!(a.distance_to(&diagonal), Scalar::from(3.));
+        assert_eq!(diagonal.distance_to(&a), Scalar::from(3.));
+    }
+
+    #[test]
+    fn closest_point() {
+        let aabb = Aabb::<3>::from_points([[0., 0., 0.], [1., 2., 3.]]);
+
+        assert_eq!(
+            aabb.closest_point([0.5, 1., 1.]),
+            Point::from([0.5, 1., 1.])
+        );
+        assert_eq!(
+            aabb.closest_point([-1., 1., 5.]),
+            Point::from([0., 1., 3.])
+        );
+        assert_eq!(aabb.closest_point([2., 3., 4.]), Point::from([1., 2., 3.]));
+    }
+
     #[test]
     fn contains() {
         let aabb = Aabb::<2>::from_points([[1., 1.], [3., 3.]]);
@@ -159,5 +337,51 @@ mod tests {
         assert!(!aabb.contains([2., 4.]));
         assert!(!aabb.contains([0., 2.]));
         assert!(!aabb.contains([4., 2.]));
+
+        // Points on the boundary are contained.
+        assert!(aabb.contains([1., 1.]));
+        assert!(aabb.contains([3., 2.]));
+    }
+
+    #[test]
+    fn center_and_size() {
+        let aabb = Aabb::<3>::from_points([[1., 2., 3.], [3., 6., 4.]]);
+
+        assert_eq!(aabb.center(), Point::from([2., 4., 3.5]));
+        assert_eq!(aabb.size(), Vector::from([2., 4., 1.]));
+        assert!(aabb.contains(aabb.center()));
+        assert!(aabb.contains(aabb.max));
+
+        let aabb = Aabb::<2>::from_points([[1., 2.], [3., 6.]]);
+
+        assert_eq!(aabb.center(), Point::from([2., 4.]));
+        assert_eq!(aabb.size(), Vector::from([2., 4.]));
+    }
+
+    #[test]
+    fn contains_with_tolerance() {
+        let aabb = Aabb::<2>::from_points([[1., 1.], [3., 3.]]);
+
+        assert!(aabb.contains_with_tolerance([3.05, 2.], 0.1));
+        assert!(!aabb.contains_with_tolerance([3.2, 2.], 0.1));
+    }
+
+    #[test]
+    fn contains_aabb() {
+        let aabb = Aabb::<2>::from_points([[1., 1.], [3., 3.]]);
+
+        let inside = Aabb::<2>::from_points([[1., 1.5], [2., 2.]]);
+        let overlapping = Aabb::<2>::from_points([[2., 2.], [4., 4.]]);
+        let outside = Aabb::<2>::from_points([[4., 4.], [5., 5.]]);
+
+        assert!(aabb.contains_aabb(&aabb));
+        assert!(aabb.contains_aabb(&inside));
+        assert!(!aabb.contains_aabb(&overlapping));
+        assert!(!aabb.contains_aabb(&outside));
+
+        assert!(aabb.contains_aabb_with_tolerance(
+            &Aabb::<2>::from_points([[0.95, 1.], [3., 3.05]]),
+            0.1
+        ));
     }
 }
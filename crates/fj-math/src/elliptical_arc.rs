@@ -0,0 +1,123 @@
+use crate::{Point, Scalar, Vector};
+
+/// An n-dimensional elliptical arc
+///
+/// The dimensionality of the arc is defined by the const generic `D`
+/// parameter.
+///
+/// The arc is a section of the ellipse that is defined by a center and two
+/// semi-axis vectors, `a` and `b`. Points on the ellipse are identified by an
+/// angle `t`, which defines the point `center + a * cos(t) + b * sin(t)`. The
+/// arc covers the angles in its range.
+///
+/// Please note that `t` is not the angle between `a` and the point on the
+/// ellipse, as seen from the center, unless the ellipse is a circle.
+///
+/// # Implementation Note
+///
+/// Elliptical arcs are not a kind of curve. Edges can't be elliptical arcs, so
+/// ellipses in sketches are approximated as polygons, with the kernel's
+/// adaptive approximation of this type.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct EllipticalArc<const D: usize> {
+    center: Point<D>,
+    a: Vector<D>,
+    b: Vector<D>,
+    range: [Scalar; 2],
+}
+
+impl<const D: usize> EllipticalArc<D> {
+    /// Construct an elliptical arc
+    ///
+    /// The arc starts at the angle `range[0]` and ends at the angle
+    /// `range[1]`, both given in radians. If the end is smaller than the start,
+    /// the arc runs in the opposite direction. The range may span more than a
+    /// full turn.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `a` or `b` have zero length, or if the range is empty.
+    pub fn new(
+        center: impl Into<Point<D>>,
+        a: impl Into<Vector<D>>,
+        b: impl Into<Vector<D>>,
+        range: [impl Into<Scalar>; 2],
+    ) -> Self {
+        let center = center.into();
+        let a = a.into();
+        let b = b.into();
+        let range = range.map(Into::into);
+
+        assert_ne!(
+            a.magnitude(),
+            Scalar::ZERO,
+            "semi-axis `a` must not have zero length"
+        );
+        assert_ne!(
+            b.magnitude(),
+            Scalar::ZERO,
+            "semi-axis `b` must not have zero length"
+        );
+        let [start, end] = range;
+        assert_ne!(start, end, "range of elliptical arc must not be empty");
+
+        Self {
+            center,
+            a,
+            b,
+            range,
+        }
+    }
+
+    /// Access the center point of the ellipse
+    pub fn center(&self) -> Point<D> {
+        self.center
+    }
+
+    /// Access the semi-axis vector that defines the angle zero
+    pub fn a(&self) -> Vector<D> {
+        self.a
+    }
+
+    /// Access the semi-axis vector that defines the angle `PI / 2.`
+    pub fn b(&self) -> Vector<D> {
+        self.b
+    }
+
+    /// Access the range of angles that the arc covers
+    pub fn range(&self) -> [Scalar; 2] {
+        self.range
+    }
+
+    /// Convert an angle on the ellipse into a `D`-dimensional point
+    ///
+    /// The angle doesn't need to be within the range of the arc.
+    pub fn point_from_arc_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Point<D> {
+        let (sin, cos) = point.into().t.sin_cos();
+        self.center + self.a * cos + self.b * sin
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use crate::{Point, Scalar};
+
+    use super::EllipticalArc;
+
+    #[test]
+    fn point_from_arc_coords() {
+        let arc = EllipticalArc::new([1., 1.], [2., 0.], [0., 1.], [0., 1.]);
+
+        assert_eq!(arc.point_from_arc_coords([0.]), Point::from([3., 1.]));
+
+        let point = arc.point_from_arc_coords([FRAC_PI_2]);
+        assert!(
+            (point - Point::from([1., 2.])).magnitude() < Scalar::from(1e-12)
+        );
+    }
+}
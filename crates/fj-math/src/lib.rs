@@ -38,6 +38,7 @@
 mod aabb;
 mod circle;
 mod coordinates;
+mod elliptical_arc;
 mod line;
 mod point;
 mod poly_chain;
@@ -51,10 +52,11 @@ pub use self::{
     aabb::Aabb,
     circle::Circle,
     coordinates::{Uv, Xyz, T},
+    elliptical_arc::EllipticalArc,
     line::Line,
-    point::Point,
-    poly_chain::PolyChain,
-    scalar::{Scalar, Sign},
+    point::{Point, PointKey},
+    poly_chain::{convex_hull_2d, signed_area, PolyChain},
+    scalar::{Scalar, Sign, DEFAULT_EPSILON},
     segment::Segment,
     transform::Transform,
     triangle::{Triangle, Winding},
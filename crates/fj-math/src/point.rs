@@ -63,6 +63,60 @@ impl<const D: usize> Point<D> {
     pub fn distance(p1: &Point<D>, p2: &Point<D>) -> Scalar {
         (p1.coords - p2.coords).magnitude()
     }
+
+    /// Snap the point to a grid
+    ///
+    /// Rounds each coordinate to the nearest multiple of `grid_size`. Points
+    /// that are meant to be coincident, but differ due to numerical
+    /// inaccuracy, become identical, as long as they snap to the same grid
+    /// point.
+    pub fn snap_to_grid(self, grid_size: impl Into<Scalar>) -> Self {
+        let grid_size = grid_size.into();
+
+        let components = self
+            .coords
+            .components
+            .map(|coord| coord.round_to(grid_size));
+
+        Self::from(components)
+    }
+
+    /// Compute a key that can be used to order points robustly
+    ///
+    /// Each coordinate is divided by `resolution` and rounded to the nearest
+    /// integer, like [`Point::snap_to_grid`] does. The resulting key has a
+    /// total order that only depends on those integers, which makes it
+    /// suitable for use in `BTreeMap`/`BTreeSet` or `HashMap`/`HashSet`.
+    ///
+    /// Please note that this ordering is quantized, not exact: Points that
+    /// are within the same grid cell get the same key, even if they are not
+    /// equal. Points that are very close to each other, but on different
+    /// sides of a cell boundary, get different keys.
+    pub fn key(&self, resolution: impl Into<Scalar>) -> PointKey<D> {
+        let resolution = resolution.into();
+
+        let cells = self
+            .coords
+            .components
+            .map(|coord| (coord / resolution).round().into_f64() as i64);
+
+        PointKey { cells }
+    }
+}
+
+/// A quantized key for a [`Point`]
+///
+/// See [`Point::key`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct PointKey<const D: usize> {
+    cells: [i64; D],
+}
+
+impl<const D: usize> PointKey<D> {
+    /// Access the grid cell that the key refers to
+    pub fn cells(&self) -> [i64; D] {
+        self.cells
+    }
 }
 
 impl ops::Deref for Point<1> {
@@ -205,6 +259,28 @@ impl<const D: usize> fmt::Debug for Point<D> {
     }
 }
 
+/// Serializes as an array of numbers, just like [`Vector`]
+#[cfg(feature = "serde")]
+impl<const D: usize> serde::Serialize for Point<D> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(&self.coords, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const D: usize> serde::Deserialize<'de> for Point<D> {
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: serde::Deserializer<'de>,
+    {
+        let coords = serde::Deserialize::deserialize(deserializer)?;
+        Ok(Self { coords })
+    }
+}
+
 impl<const D: usize> approx::AbsDiffEq for Point<D> {
     type Epsilon = <Vector<D> as approx::AbsDiffEq>::Epsilon;
 
@@ -216,3 +292,59 @@ impl<const D: usize> approx::AbsDiffEq for Point<D> {
         self.coords.abs_diff_eq(&other.coords, epsilon)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::Point;
+
+    #[test]
+    fn key() {
+        let a = Point::from([0.1 + 0.2, 1.]);
+        let b = Point::from([0.3, 1.0000000001]);
+        let c = Point::from([0.3, 1.1]);
+
+        assert_eq!(a.key(1e-6), b.key(1e-6));
+        assert!(a.key(1e-6) < c.key(1e-6));
+        assert_eq!(c.key(0.1).cells(), [3, 11]);
+    }
+
+    #[test]
+    fn snap_to_grid() {
+        let a = Point::from([0.1 + 0.2, 1.]);
+        let b = Point::from([0.3, 1.0000000001]);
+        assert_ne!(a, b);
+
+        let a = a.snap_to_grid(1e-6);
+        let b = b.snap_to_grid(1e-6);
+        assert_eq!(a, b);
+
+        let [a_u, a_v] = a.coords.components.map(|c| c.into_f64().to_bits());
+        let [b_u, b_v] = b.coords.components.map(|c| c.into_f64().to_bits());
+        assert_eq!(a_u, b_u);
+        assert_eq!(a_v, b_v);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip() {
+        use serde_json::{from_str, to_string};
+
+        let point = Point::from([0.1, -1e-300, 1. / 3.]);
+
+        let json = to_string(&point).expect("failed to serialize point");
+        assert_eq!(json, "[0.1,-1e-300,0.3333333333333333]");
+
+        let point_de: Point<3> =
+            from_str(&json).expect("failed to deserialize point");
+
+        // The round trip must be exact, down to the last bit.
+        let bits = |point: Point<3>| {
+            point.coords.components.map(|c| c.into_f64().to_bits())
+        };
+        assert_eq!(bits(point), bits(point_de));
+
+        // Arrays of the wrong length are rejected.
+        assert!(from_str::<Point<3>>("[0.1,0.2]").is_err());
+        assert!(from_str::<Point<3>>("[0.1,0.2,0.3,0.4]").is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Point, Segment};
+use crate::{Point, Scalar, Segment};
 
 /// A polygonal chain
 ///
@@ -73,6 +73,76 @@ impl<const D: usize> PolyChain<D> {
     }
 }
 
+/// Compute the signed area of a polygon
+///
+/// The polygon is implicitly closed, meaning the last point connects back to
+/// the first one. The area is positive, if the polygon is wound
+/// counter-clockwise, and negative, if it's wound clockwise.
+pub fn signed_area(points: &[Point<2>]) -> Scalar {
+    let mut sum = Scalar::ZERO;
+
+    for (i, a) in points.iter().enumerate() {
+        let b = points[(i + 1) % points.len()];
+        sum += a.u * b.v - b.u * a.v;
+    }
+
+    sum / 2.
+}
+
+/// Compute the convex hull of a set of points
+///
+/// Uses Andrew's monotone chain algorithm. Returns the vertices of the hull in
+/// counter-clockwise order, starting with the lowest point along the u-axis.
+/// Points that lie on an edge of the hull are not included.
+///
+/// For degenerate inputs, the extreme points of the input are returned. That
+/// means no points for an empty input, a single point, if all points are the
+/// same, and the two end points, if all points are collinear.
+pub fn convex_hull_2d(points: &[Point<2>]) -> Vec<Point<2>> {
+    let mut points = points.to_vec();
+    points.sort();
+    points.dedup();
+
+    if points.len() < 3 {
+        return points;
+    }
+
+    // Positive, if `o`, `a`, and `b` make a counter-clockwise turn.
+    let cross = |o: Point<2>, a: Point<2>, b: Point<2>| {
+        (a.u - o.u) * (b.v - o.v) - (a.v - o.v) * (b.u - o.u)
+    };
+
+    let mut hull: Vec<Point<2>> = Vec::with_capacity(points.len() * 2);
+
+    // Lower hull
+    for &point in &points {
+        while hull.len() >= 2
+            && cross(hull[hull.len() - 2], hull[hull.len() - 1], point)
+                <= Scalar::ZERO
+        {
+            hull.pop();
+        }
+        hull.push(point);
+    }
+
+    // Upper hull. The last point of the lower hull is its first point.
+    let lower_len = hull.len() + 1;
+    for &point in points.iter().rev().skip(1) {
+        while hull.len() >= lower_len
+            && cross(hull[hull.len() - 2], hull[hull.len() - 1], point)
+                <= Scalar::ZERO
+        {
+            hull.pop();
+        }
+        hull.push(point);
+    }
+
+    // The last point is the first point again.
+    hull.pop();
+
+    hull
+}
+
 impl<P, Ps, const D: usize> From<Ps> for PolyChain<D>
 where
     P: Into<Point<D>>,
@@ -82,3 +152,60 @@ where
         Self::from_points(points)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{Point, Scalar};
+
+    use super::{convex_hull_2d, signed_area};
+
+    #[test]
+    fn signed_area_depends_on_winding() {
+        let mut points =
+            [[0., 0.], [2., 0.], [2., 1.], [0., 1.]].map(Point::from);
+        assert_eq!(signed_area(&points), Scalar::from(2.));
+
+        points.reverse();
+        assert_eq!(signed_area(&points), Scalar::from(-2.));
+
+        assert_eq!(signed_area(&[]), Scalar::ZERO);
+    }
+
+    #[test]
+    fn convex_hull_2d_square_with_interior_points() {
+        let points = [
+            [0.5, 0.5],
+            [1., 1.],
+            [0., 0.],
+            [0.25, 0.75],
+            [0., 1.],
+            [0.5, 0.], // on an edge of the hull
+            [1., 0.],
+            [0.9, 0.1],
+        ]
+        .map(Point::from);
+
+        let hull = convex_hull_2d(&points);
+
+        assert_eq!(
+            hull,
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]].map(Point::from)
+        );
+        assert!(signed_area(&hull) > Scalar::ZERO);
+    }
+
+    #[test]
+    fn convex_hull_2d_degenerate() {
+        assert!(convex_hull_2d(&[]).is_empty());
+
+        let point = Point::from([1., 2.]);
+        assert_eq!(convex_hull_2d(&[point, point]), [point]);
+
+        let collinear =
+            [[1., 1.], [0., 0.], [3., 3.], [2., 2.]].map(Point::from);
+        assert_eq!(
+            convex_hull_2d(&collinear),
+            [[0., 0.], [3., 3.]].map(Point::from)
+        );
+    }
+}
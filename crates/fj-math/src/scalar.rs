@@ -8,6 +8,13 @@ use std::{
 
 use decorum::R64;
 
+/// The default epsilon for [`Scalar::approx_eq`]
+///
+/// Far smaller than any dimension that's meaningful in a model, but large
+/// enough to absorb the floating-point drift that accumulates in geometric
+/// computations.
+pub const DEFAULT_EPSILON: Scalar = Scalar(1e-9);
+
 /// A rational, finite scalar value
 ///
 /// This is a wrapper around `f64`. On construction, it checks that the `f64`
@@ -124,10 +131,48 @@ impl Scalar {
     }
 
     /// Round the scalar
+    ///
+    /// Values that are exactly halfway between two integers are rounded away
+    /// from zero.
     pub fn round(self) -> Self {
         self.0.round().into()
     }
 
+    /// Round the scalar to the nearest multiple of `grid`
+    ///
+    /// Values that are exactly halfway between two multiples of `grid` are
+    /// rounded away from zero, same as [`Scalar::round`].
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `grid` is zero.
+    pub fn round_to(self, grid: impl Into<Self>) -> Self {
+        let grid = grid.into();
+        (self / grid).round() * grid
+    }
+
+    /// Restrict the scalar to the range between `min` and `max`
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `min` is larger than `max`.
+    pub fn clamp(self, min: impl Into<Self>, max: impl Into<Self>) -> Self {
+        self.0.clamp(min.into().0, max.into().0).into()
+    }
+
+    /// Check whether this scalar is approximately equal to another
+    ///
+    /// The scalars are considered equal, if their difference is not larger
+    /// than `epsilon`. Use [`DEFAULT_EPSILON`], unless there's a reason to
+    /// use a specific value.
+    pub fn approx_eq(
+        self,
+        other: impl Into<Self>,
+        epsilon: impl Into<Self>,
+    ) -> bool {
+        (self - other.into()).abs() <= epsilon.into()
+    }
+
     /// Compute the cosine
     pub fn cos(self) -> Self {
         self.0.cos().into()
@@ -577,6 +622,36 @@ impl fmt::Display for Scalar {
     }
 }
 
+/// Serializes as a plain number
+///
+/// Please note that infinite values can't be represented in some formats,
+/// JSON among them.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Scalar {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_f64(self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Scalar {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let scalar: f64 = serde::Deserialize::deserialize(deserializer)?;
+
+        if scalar.is_nan() {
+            return Err(serde::de::Error::custom("Invalid scalar value: NaN"));
+        }
+
+        Ok(Self(scalar))
+    }
+}
+
 impl approx::AbsDiffEq for Scalar {
     type Epsilon = Self;
 
@@ -614,3 +689,45 @@ impl Sign {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{Scalar, DEFAULT_EPSILON};
+
+    #[test]
+    fn round_to() {
+        assert_eq!(Scalar::from(0.2).round_to(0.5), Scalar::ZERO);
+        assert_eq!(Scalar::from(0.3).round_to(0.5), Scalar::from(0.5));
+        assert_eq!(Scalar::from(-0.8).round_to(0.5), Scalar::from(-1.));
+
+        // Exact half-grid values are rounded away from zero.
+        assert_eq!(Scalar::from(0.25).round_to(0.5), Scalar::from(0.5));
+        assert_eq!(Scalar::from(-0.25).round_to(0.5), Scalar::from(-0.5));
+    }
+
+    #[test]
+    fn approx_eq() {
+        let epsilon = DEFAULT_EPSILON;
+
+        assert!(Scalar::ONE.approx_eq(1. + 0.5e-9, epsilon));
+        assert!(!Scalar::ONE.approx_eq(1. + 2e-9, epsilon));
+        assert!(Scalar::ONE.approx_eq(1. - 0.5e-9, epsilon));
+        assert!(!Scalar::ONE.approx_eq(1. - 2e-9, epsilon));
+
+        // The difference may be exactly epsilon.
+        assert!(Scalar::from(0.25).approx_eq(0.5, 0.25));
+        assert!(!Scalar::from(0.25).approx_eq(0.5 + f64::EPSILON, 0.25));
+
+        // Values with different signs are equal, if they're close to zero.
+        assert!(Scalar::from(0.4e-9).approx_eq(-0.4e-9, epsilon));
+        assert!(!Scalar::from(0.6e-9).approx_eq(-0.6e-9, epsilon));
+        assert!(!Scalar::ONE.approx_eq(-1., epsilon));
+    }
+
+    #[test]
+    fn clamp() {
+        assert_eq!(Scalar::from(-2.).clamp(-1., 1.), Scalar::from(-1.));
+        assert_eq!(Scalar::from(0.5).clamp(-1., 1.), Scalar::from(0.5));
+        assert_eq!(Scalar::from(2.).clamp(-1., 1.), Scalar::ONE);
+    }
+}
@@ -40,6 +40,43 @@ impl Transform {
         ))
     }
 
+    /// Construct a scaling
+    ///
+    /// The components of `factors` define the scale factors along the x, y,
+    /// and z axes. The scaling is relative to the origin.
+    pub fn scale(factors: impl Into<Vector<3>>) -> Self {
+        let factors = factors.into();
+
+        Self(nalgebra::Transform::from_matrix_unchecked(
+            nalgebra::OMatrix::new_nonuniform_scaling(&factors.to_na()),
+        ))
+    }
+
+    /// Construct a reflection through a plane
+    ///
+    /// The plane goes through `origin` and is perpendicular to `normal`, which
+    /// doesn't need to be normalized.
+    pub fn reflection(
+        origin: impl Into<Point<3>>,
+        normal: impl Into<Vector<3>>,
+    ) -> Self {
+        let origin = origin.into();
+        let normal = normal.into().normalize();
+
+        let d = origin.coords.dot(&normal).into_f64();
+        let [x, y, z] = normal.components.map(Scalar::into_f64);
+
+        #[rustfmt::skip]
+        let matrix = nalgebra::Matrix4::new(
+            1. - 2. * x * x, -2. * x * y, -2. * x * z, 2. * d * x,
+            -2. * x * y, 1. - 2. * y * y, -2. * y * z, 2. * d * y,
+            -2. * x * z, -2. * y * z, 1. - 2. * z * z, 2. * d * z,
+            0., 0., 0., 1.,
+        );
+
+        Self(nalgebra::Transform::from_matrix_unchecked(matrix))
+    }
+
     /// Transform the given point
     pub fn transform_point(&self, point: &Point<3>) -> Point<3> {
         Point::from(self.0.transform_point(&point.to_na()))
@@ -88,6 +125,14 @@ impl Transform {
         )
     }
 
+    /// Determine whether the transform reverses orientation
+    ///
+    /// This is the case for reflections, for example. An object that is
+    /// transformed by such a transform becomes a mirror image of itself.
+    pub fn reverses_orientation(&self) -> bool {
+        self.0.matrix().fixed_resize::<3, 3>(0.).determinant() < 0.
+    }
+
     /// Inverse transform
     pub fn inverse(&self) -> Transform {
         Self(self.0.inverse())
@@ -182,6 +227,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn composition() {
+        let translation = Transform::translation([1., 2., 3.]);
+        let rotation =
+            Transform::rotation(Vector::unit_z() * (Scalar::PI / 2.));
+
+        // The product applies the right-hand transform first.
+        let product = rotation * translation;
+
+        for point in [[0., 0., 0.], [1., 0., 0.], [-2., 3., 5.]] {
+            let point = Point::from(point);
+            let expected =
+                rotation.transform_point(&translation.transform_point(&point));
+
+            assert_abs_diff_eq!(
+                product.transform_point(&point),
+                expected,
+                epsilon = Scalar::from(1e-12),
+            );
+        }
+
+        assert_abs_diff_eq!(
+            product.transform_point(&Point::from([1., 0., 0.])),
+            Point::from([-2., 2., 3.]),
+            epsilon = Scalar::from(1e-12),
+        );
+    }
+
     #[test]
     fn extract_rotation_translation() {
         let rotation =
@@ -212,4 +285,21 @@ mod tests {
             epsilon = 1e-8,
         );
     }
+
+    #[test]
+    fn reflection() {
+        let transform = Transform::reflection([0., 1., 0.], [0., 2., 0.]);
+
+        assert_eq!(
+            transform.transform_point(&Point::from([1., 3., 2.])),
+            Point::from([1., -1., 2.])
+        );
+        assert_eq!(
+            transform.transform_point(&Point::from([1., 1., 2.])),
+            Point::from([1., 1., 2.])
+        );
+
+        assert!(transform.reverses_orientation());
+        assert!(!Transform::rotation([0., 0., 1.]).reverses_orientation());
+    }
 }
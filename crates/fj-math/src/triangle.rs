@@ -62,6 +62,37 @@ impl Triangle<2> {
         let [v0, v1, v2] = self.points.map(|point| point.to_na());
         corner_direction(&v0, &v1, &v2).into()
     }
+
+    /// Compute the barycentric coordinates of a point
+    ///
+    /// Each coordinate is the weight of the respective point of the triangle.
+    /// The coordinates add up to one, and all of them are non-negative, if the
+    /// point lies within the triangle.
+    pub fn barycentric_coords(
+        &self,
+        point: impl Into<Point<2>>,
+    ) -> [Scalar; 3] {
+        let point = point.into();
+        let [a, b, c] = self.points;
+
+        let [ab, ac, ap] = [b - a, c - a, point - a];
+        let area = ab.cross(&ac);
+
+        // The triangle is validated on construction, so `area` is not zero.
+        let v = ap.cross(&ac) / area;
+        let w = ab.cross(&ap) / area;
+
+        [Scalar::ONE - v - w, v, w]
+    }
+
+    /// Determine whether the triangle contains a point
+    ///
+    /// Points on the boundary of the triangle are considered to be contained.
+    pub fn contains_point(&self, point: impl Into<Point<2>>) -> bool {
+        self.barycentric_coords(point)
+            .into_iter()
+            .all(|coord| coord >= Scalar::ZERO)
+    }
 }
 
 impl Triangle<3> {
@@ -134,7 +165,7 @@ impl From<Orientation> for Winding {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Point, Vector};
+    use crate::{Point, Scalar, Vector};
 
     use super::Triangle;
 
@@ -172,6 +203,25 @@ mod tests {
         let _triangle = Triangle::from([a, b, c]);
     }
 
+    #[test]
+    fn barycentric_coords() {
+        let triangle = Triangle::from([[0., 0.], [2., 0.], [0., 2.]]);
+
+        assert_eq!(
+            triangle.barycentric_coords([0.5, 0.5]),
+            [0.5, 0.25, 0.25].map(Scalar::from)
+        );
+        assert_eq!(
+            triangle.barycentric_coords([2., 0.]),
+            [0., 1., 0.].map(Scalar::from)
+        );
+
+        assert!(triangle.contains_point([0.5, 0.5]));
+        assert!(triangle.contains_point([1., 1.]));
+        assert!(!triangle.contains_point([1.5, 1.]));
+        assert!(!triangle.contains_point([-0.1, 0.5]));
+    }
+
     #[test]
     fn normal() {
         let triangle =
@@ -108,6 +108,37 @@ impl<const D: usize> Vector<D> {
 
         self.dot(&other.normalize())
     }
+
+    /// Compute the vector projection of this vector onto another
+    ///
+    /// Returns the zero vector, if `other` has zero length.
+    pub fn project_onto(&self, other: &Self) -> Self {
+        if other.magnitude() == Scalar::ZERO {
+            return Self::from([Scalar::ZERO; D]);
+        }
+
+        other.normalize() * self.scalar_projection_onto(other)
+    }
+
+    /// Compute the angle between this vector and another
+    ///
+    /// Returns an angle between `0` and `PI` (inclusive), in radians. If
+    /// either of the vectors has zero length, the angle is zero.
+    pub fn angle_between(&self, other: &Self) -> Scalar {
+        if self.magnitude() == Scalar::ZERO || other.magnitude() == Scalar::ZERO
+        {
+            return Scalar::ZERO;
+        }
+
+        // Computing the angle from the dot product would be imprecise for
+        // small angles, and require clamping to be robust against numerical
+        // inaccuracy. This formulation doesn't have either problem:
+        // https://people.eecs.berkeley.edu/~wkahan/Mindless.pdf (section 12)
+        let a = *self * other.magnitude();
+        let b = *other * self.magnitude();
+
+        Scalar::TWO * Scalar::atan2((a - b).magnitude(), (a + b).magnitude())
+    }
 }
 
 impl Vector<1> {
@@ -334,6 +365,61 @@ impl<const D: usize> fmt::Debug for Vector<D> {
     }
 }
 
+/// Serializes as an array of numbers
+#[cfg(feature = "serde")]
+impl<const D: usize> serde::Serialize for Vector<D> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeTuple as _;
+
+        let mut tuple = serializer.serialize_tuple(D)?;
+        for component in &self.components {
+            tuple.serialize_element(component)?;
+        }
+        tuple.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const D: usize> serde::Deserialize<'de> for Vector<D> {
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: serde::Deserializer<'de>,
+    {
+        use serde::de::{Error as _, SeqAccess, Visitor};
+
+        // Serde only implements `Deserialize` for arrays of specific lengths,
+        // so we can't just deserialize `[Scalar; D]`.
+        struct ComponentsVisitor<const D: usize>;
+
+        impl<'de, const D: usize> Visitor<'de> for ComponentsVisitor<D> {
+            type Value = Vector<D>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "an array of {} numbers", D)
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut components = [Scalar::ZERO; D];
+                for (i, component) in components.iter_mut().enumerate() {
+                    *component = seq
+                        .next_element()?
+                        .ok_or_else(|| A::Error::invalid_length(i, &self))?;
+                }
+
+                Ok(Vector { components })
+            }
+        }
+
+        deserializer.deserialize_tuple(D, ComponentsVisitor)
+    }
+}
+
 impl<const D: usize> approx::AbsDiffEq for Vector<D> {
     type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
 
@@ -348,6 +434,8 @@ impl<const D: usize> approx::AbsDiffEq for Vector<D> {
 
 #[cfg(test)]
 mod tests {
+    use approx::assert_abs_diff_eq;
+
     use crate::{Scalar, Vector};
 
     #[test]
@@ -390,4 +478,35 @@ mod tests {
             Scalar::ZERO
         );
     }
+
+    #[test]
+    fn project_onto() {
+        let v = Vector::from([1., 2., 3.]);
+
+        assert_eq!(
+            v.project_onto(&(Vector::unit_y() * 2.)),
+            Vector::from([0., 2., 0.])
+        );
+        assert_eq!(
+            v.project_onto(&Vector::from([0., 0., 0.])),
+            Vector::from([0., 0., 0.])
+        );
+    }
+
+    #[test]
+    fn angle_between() {
+        let x = Vector::from([2., 0.]);
+        let y = Vector::from([0., 3.]);
+
+        assert_abs_diff_eq!(x.angle_between(&y), Scalar::PI / 2.);
+        assert_abs_diff_eq!(x.angle_between(&-x), Scalar::PI);
+        assert_abs_diff_eq!(x.angle_between(&x), Scalar::ZERO);
+        assert_abs_diff_eq!(
+            x.angle_between(&Vector::from([1., 1.])),
+            Scalar::PI / 4.
+        );
+
+        // Zero-length vectors should be handled as well.
+        assert_eq!(x.angle_between(&Vector::from([0., 0.])), Scalar::ZERO);
+    }
 }
@@ -0,0 +1,94 @@
+use fj_interop::{debug::DebugInfo, mesh::Color};
+use fj_kernel::{
+    algorithms::{
+        approx::Tolerance,
+        reverse::Reverse,
+        validate::{Validate, Validated, ValidationConfig},
+    },
+    objects::{Cycle, Face, HalfEdge, Sketch, Surface},
+};
+use fj_math::{Aabb, Point, Scalar};
+
+use super::{Shape, ShapeError};
+
+impl Shape for fj::Annulus {
+    type Brep = Sketch;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        _: Tolerance,
+        _: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, ShapeError> {
+        let surface = Surface::xy_plane();
+
+        // Both circles have just a single round edge with no vertices. The
+        // inner one is reversed, so it bounds a hole.
+        let [exterior, interior] = [self.outer(), self.inner()].map(|radius| {
+            let half_edge = HalfEdge::build(surface)
+                .circle_from_radius(Scalar::from_f64(radius));
+            Cycle::new(surface, [half_edge])
+        });
+        let interior = interior.reverse();
+
+        let face = Face::new(surface, exterior)
+            .with_interiors([interior])
+            .with_color(Color(self.color()));
+
+        let sketch = Sketch::new().with_faces([face]);
+        Ok(sketch.validate_with_config(config)?)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        Aabb {
+            min: Point::from([-self.outer(), -self.outer(), 0.0]),
+            max: Point::from([self.outer(), self.outer(), 0.0]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use fj_interop::debug::DebugInfo;
+    use fj_kernel::algorithms::{
+        approx::Tolerance, triangulate::Triangulate, validate::ValidationConfig,
+    };
+    use fj_math::Scalar;
+
+    use crate::Shape as _;
+
+    #[test]
+    fn annulus() {
+        let annulus = fj::Annulus::from_radii(2., 1.).unwrap();
+        let tolerance = 0.001;
+
+        let mesh = annulus
+            .compute_brep(
+                &ValidationConfig::default(),
+                Tolerance::from_scalar(0.001).unwrap(),
+                &mut DebugInfo::new(),
+            )
+            .unwrap()
+            .into_inner()
+            .into_faces()
+            .triangulate(tolerance);
+
+        let mut area = Scalar::ZERO;
+        for triangle in mesh.triangles() {
+            let [a, b, c] = triangle.inner.points();
+
+            // No triangle spans the hole.
+            let centroid = (a.coords + b.coords + c.coords) / 3.;
+            assert!(centroid.magnitude() > Scalar::ONE);
+
+            area += (b - a).cross(&(c - a)).magnitude() / 2.;
+        }
+
+        // The approximated circles are inscribed in the real ones, so the
+        // area is a bit off.
+        let expected = PI * (2. * 2. - 1. * 1.);
+        assert!((area.into_f64() - expected).abs() < expected * 0.01);
+    }
+}
@@ -1,15 +1,16 @@
 use fj_interop::{debug::DebugInfo, mesh::Color};
 use fj_kernel::{
     algorithms::{
+        approx::Tolerance,
         reverse::Reverse,
-        validate::{Validate, Validated, ValidationConfig, ValidationError},
+        validate::{Validate, Validated, ValidationConfig},
     },
     iter::ObjectIters,
     objects::{Face, Sketch},
 };
 use fj_math::Aabb;
 
-use super::Shape;
+use super::{Shape, ShapeError};
 
 impl Shape for fj::Difference2d {
     type Brep = Sketch;
@@ -17,8 +18,9 @@ impl Shape for fj::Difference2d {
     fn compute_brep(
         &self,
         config: &ValidationConfig,
+        tolerance: Tolerance,
         debug_info: &mut DebugInfo,
-    ) -> Result<Validated<Self::Brep>, ValidationError> {
+    ) -> Result<Validated<Self::Brep>, ShapeError> {
         // This method assumes that `b` is fully contained within `a`:
         // https://github.com/hannobraun/Fornjot/issues/92
 
@@ -27,12 +29,15 @@ impl Shape for fj::Difference2d {
         let mut exteriors = Vec::new();
         let mut interiors = Vec::new();
 
-        // Can be cleaned up, once `each_ref` and `try_map` are stable:
-        // - https://doc.rust-lang.org/std/primitive.array.html#method.each_ref
-        // - https://doc.rust-lang.org/std/primitive.array.html#method.try_map
-        let [a, b] = self.shapes();
-        let [a, b] = [a, b].map(|shape| shape.compute_brep(config, debug_info));
-        let [a, b] = [a?, b?];
+        // Nested differences are flattened, so all shapes that are subtracted
+        // from the same base are subtracted in one go.
+        let (base, subtractors) = flatten(self);
+
+        let a = base.compute_brep(config, tolerance, debug_info)?;
+        let subtractors = subtractors
+            .into_iter()
+            .map(|shape| shape.compute_brep(config, tolerance, debug_info))
+            .collect::<Result<Vec<_>, _>>()?;
 
         if let Some(face) = a.face_iter().next() {
             // If there's at least one face to subtract from, we can proceed.
@@ -52,7 +57,7 @@ impl Shape for fj::Difference2d {
                 }
             }
 
-            for face in b.face_iter() {
+            for face in subtractors.iter().flat_map(|b| b.face_iter()) {
                 assert_eq!(
                     surface,
                     face.surface(),
@@ -87,7 +92,7 @@ impl Shape for fj::Difference2d {
         }
 
         let difference = Sketch::new().with_faces(faces);
-        difference.validate_with_config(config)
+        Ok(difference.validate_with_config(config)?)
     }
 
     fn bounding_volume(&self) -> Aabb<3> {
@@ -97,3 +102,96 @@ impl Shape for fj::Difference2d {
         self.shapes()[0].bounding_volume()
     }
 }
+
+/// Flatten a chain of nested differences
+///
+/// Returns the shape that everything is subtracted from, and all shapes that
+/// are subtracted from it, innermost first. For a difference whose first
+/// operand is not itself a difference, these are just its two operands.
+fn flatten(difference: &fj::Difference2d) -> (&fj::Shape2d, Vec<&fj::Shape2d>) {
+    let mut subtractors = Vec::new();
+    let mut difference = difference;
+
+    loop {
+        let [a, b] = difference.shapes();
+        subtractors.push(b);
+
+        match a {
+            fj::Shape2d::Difference(inner) => difference = inner,
+            fj::Shape2d::Annulus(_)
+            | fj::Shape2d::Group(_)
+            | fj::Shape2d::Intersection(_)
+            | fj::Shape2d::Offset(_)
+            | fj::Shape2d::RegularPolygon(_)
+            | fj::Shape2d::RoundedRectangle(_)
+            | fj::Shape2d::Sketch(_)
+            | fj::Shape2d::Slot(_)
+            | fj::Shape2d::Star(_)
+            | fj::Shape2d::Union(_) => {
+                subtractors.reverse();
+                return (a, subtractors);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::debug::DebugInfo;
+    use fj_kernel::{
+        algorithms::{approx::Tolerance, validate::ValidationConfig},
+        iter::ObjectIters,
+    };
+
+    use crate::Shape as _;
+
+    use super::flatten;
+
+    #[test]
+    fn flatten_nested_differences() {
+        let square = |[x, y]: [f64; 2], size: f64| {
+            fj::Shape2d::from(fj::Sketch::from_points(vec![
+                [x, y],
+                [x + size, y],
+                [x + size, y + size],
+                [x, y + size],
+            ]))
+        };
+
+        let base = square([0., 0.], 10.);
+        let holes = (1..=3)
+            .map(|i| square([f64::from(i) * 2., 2.], 1.))
+            .collect::<Vec<_>>();
+
+        let mut difference =
+            fj::Difference2d::from_shapes([base.clone(), holes[0].clone()]);
+        for hole in &holes[1..] {
+            difference = fj::Difference2d::from_shapes([
+                difference.into(),
+                hole.clone(),
+            ]);
+        }
+
+        let (flat_base, subtractors) = flatten(&difference);
+        assert_eq!(flat_base, &base);
+        assert_eq!(subtractors, holes.iter().collect::<Vec<_>>());
+
+        let sketch = difference
+            .compute_brep(
+                &ValidationConfig::default(),
+                Tolerance::from_scalar(0.001).unwrap(),
+                &mut DebugInfo::new(),
+            )
+            .unwrap()
+            .into_inner();
+
+        let faces = sketch.face_iter().collect::<Vec<_>>();
+        assert_eq!(faces.len(), 1);
+
+        let face = faces[0];
+        assert_eq!(face.interiors().count(), holes.len());
+        for interior in face.interiors() {
+            assert_ne!(interior.winding(), face.exterior().winding());
+        }
+    }
+}
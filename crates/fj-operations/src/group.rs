@@ -1,13 +1,14 @@
 use fj_interop::debug::DebugInfo;
 use fj_kernel::{
-    algorithms::validate::{
-        Validate, Validated, ValidationConfig, ValidationError,
+    algorithms::{
+        approx::Tolerance,
+        validate::{Validate, Validated, ValidationConfig},
     },
     objects::Faces,
 };
 use fj_math::Aabb;
 
-use super::Shape;
+use super::{Shape, ShapeError};
 
 impl Shape for fj::Group {
     type Brep = Faces;
@@ -15,17 +16,18 @@ impl Shape for fj::Group {
     fn compute_brep(
         &self,
         config: &ValidationConfig,
+        tolerance: Tolerance,
         debug_info: &mut DebugInfo,
-    ) -> Result<Validated<Self::Brep>, ValidationError> {
+    ) -> Result<Validated<Self::Brep>, ShapeError> {
         let mut faces = Faces::new();
 
-        let a = self.a.compute_brep(config, debug_info)?;
-        let b = self.b.compute_brep(config, debug_info)?;
+        let a = self.a.compute_brep(config, tolerance, debug_info)?;
+        let b = self.b.compute_brep(config, tolerance, debug_info)?;
 
         faces.extend(a.into_inner());
         faces.extend(b.into_inner());
 
-        faces.validate_with_config(config)
+        Ok(faces.validate_with_config(config)?)
     }
 
     fn bounding_volume(&self) -> Aabb<3> {
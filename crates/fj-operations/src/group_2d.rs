@@ -0,0 +1,90 @@
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{
+        approx::Tolerance,
+        validate::{Validate, Validated, ValidationConfig},
+    },
+    objects::Sketch,
+};
+use fj_math::Aabb;
+
+use super::{Shape, ShapeError};
+
+impl Shape for fj::Group2d {
+    type Brep = Sketch;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, ShapeError> {
+        let mut faces = Vec::new();
+
+        for shape in self.shapes() {
+            let sketch = shape.compute_brep(config, tolerance, debug_info)?;
+            faces.extend(sketch.into_inner().into_faces());
+        }
+
+        Ok(Sketch::new()
+            .with_faces(faces)
+            .validate_with_config(config)?)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        self.shapes()
+            .iter()
+            .map(|shape| shape.bounding_volume())
+            .reduce(|a, b| a.merged(&b))
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::debug::DebugInfo;
+    use fj_kernel::{
+        algorithms::{approx::Tolerance, validate::ValidationConfig},
+        iter::ObjectIters,
+    };
+    use fj_math::Aabb;
+
+    use crate::Shape as _;
+
+    fn rectangle([x, y]: [f64; 2], [w, h]: [f64; 2]) -> fj::Shape2d {
+        fj::Sketch::from_points(vec![
+            [x, y],
+            [x + w, y],
+            [x + w, y + h],
+            [x, y + h],
+        ])
+        .into()
+    }
+
+    #[test]
+    fn separated_rectangles() {
+        let group = fj::Group2d::from_shapes([
+            rectangle([0., 0.], [1., 1.]),
+            rectangle([3., 0.], [1., 2.]),
+        ])
+        .unwrap();
+
+        let sketch = group
+            .compute_brep(
+                &ValidationConfig::default(),
+                Tolerance::from_scalar(0.001).unwrap(),
+                &mut DebugInfo::new(),
+            )
+            .unwrap()
+            .into_inner();
+
+        // The faces are not merged, and keep all their vertices.
+        assert_eq!(sketch.face_iter().count(), 2);
+        assert_eq!(sketch.global_vertex_iter().count(), 8);
+
+        assert_eq!(
+            group.bounding_volume(),
+            Aabb::<3>::from_points([[0., 0., 0.], [4., 2., 0.]])
+        );
+    }
+}
@@ -0,0 +1,238 @@
+use fj_interop::{debug::DebugInfo, mesh::Color};
+use fj_kernel::{
+    algorithms::{
+        approx::Tolerance,
+        validate::{Validate, Validated, ValidationConfig},
+    },
+    iter::ObjectIters,
+    objects::Sketch,
+};
+use fj_math::{Aabb, Scalar};
+
+use super::{
+    union_2d::{combine_boundaries, contains, overlap, rings_from_face},
+    Shape, ShapeError,
+};
+
+impl Shape for fj::Intersection2d {
+    type Brep = Sketch;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, ShapeError> {
+        let [shape_a, shape_b] = self.shapes();
+        let [a, b] = [
+            shape_a
+                .compute_brep(config, tolerance, debug_info)?
+                .into_inner(),
+            shape_b
+                .compute_brep(config, tolerance, debug_info)?
+                .into_inner(),
+        ];
+
+        let color = Color(self.color());
+        let faces_a = a.face_iter().cloned().collect::<Vec<_>>();
+        let faces_b = b.face_iter().cloned().collect::<Vec<_>>();
+
+        // If either shape is empty, so is the intersection.
+        let surface = match faces_a.first() {
+            Some(face) if !faces_b.is_empty() => *face.surface(),
+            _ => return Ok(Sketch::new().validate_with_config(config)?),
+        };
+        for face in faces_a.iter().chain(&faces_b) {
+            assert_eq!(
+                &surface,
+                face.surface(),
+                "Trying to intersect faces with different surfaces.",
+            );
+        }
+
+        let eps = tolerance.inner() / Scalar::from_f64(1000.);
+
+        let rings_a = faces_a
+            .iter()
+            .flat_map(|face| rings_from_face(face, tolerance))
+            .collect::<Vec<_>>();
+        let rings_b = faces_b
+            .iter()
+            .flat_map(|face| rings_from_face(face, tolerance))
+            .collect::<Vec<_>>();
+
+        if !overlap(&rings_a, &rings_b) {
+            return Ok(Sketch::new().validate_with_config(config)?);
+        }
+
+        // If one shape contains the other, the contained shape is the
+        // intersection. Its faces can be used as they are, which keeps them
+        // exact, if they are bounded by curves.
+        let contained = if contains(&rings_a, &rings_b, eps) {
+            Some(faces_b)
+        } else if contains(&rings_b, &rings_a, eps) {
+            Some(faces_a)
+        } else {
+            None
+        };
+        if let Some(faces) = contained {
+            let faces = faces.into_iter().map(|face| face.with_color(color));
+
+            let intersection = Sketch::new().with_faces(faces);
+            return Ok(intersection.validate_with_config(config)?);
+        }
+
+        let faces = combine_boundaries(surface, &rings_a, &rings_b, true, eps)
+            .into_iter()
+            .map(|face| face.with_color(color));
+
+        let intersection = Sketch::new().with_faces(faces);
+        Ok(intersection.validate_with_config(config)?)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        let [a, b] = self.shapes();
+        let [a, b] = [a.bounding_volume(), b.bounding_volume()];
+
+        // If the bounding volumes don't intersect, the intersection is empty,
+        // and any bounding volume contains it.
+        a.intersection(&b).unwrap_or(a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use fj_interop::debug::DebugInfo;
+    use fj_kernel::{
+        algorithms::{
+            approx::{Approx, Tolerance},
+            validate::ValidationConfig,
+        },
+        iter::ObjectIters,
+        objects::Sketch,
+        path::SurfacePath,
+    };
+    use fj_math::{signed_area, Point, Scalar};
+
+    use crate::Shape as _;
+
+    fn rectangle([x, y]: [f64; 2], [w, h]: [f64; 2]) -> fj::Shape2d {
+        fj::Sketch::from_points(vec![
+            [x, y],
+            [x + w, y],
+            [x + w, y + h],
+            [x, y + h],
+        ])
+        .into()
+    }
+
+    fn circle(radius: f64) -> fj::Shape2d {
+        fj::Sketch::from_circle(fj::Circle::from_radius(radius)).into()
+    }
+
+    fn intersection(a: fj::Shape2d, b: fj::Shape2d) -> Sketch {
+        fj::Intersection2d::from_shapes([a, b])
+            .compute_brep(
+                &ValidationConfig::default(),
+                Tolerance::from_scalar(0.001).unwrap(),
+                &mut DebugInfo::new(),
+            )
+            .unwrap()
+            .into_inner()
+    }
+
+    fn area(sketch: &Sketch) -> f64 {
+        let tolerance = Tolerance::from_scalar(0.001).unwrap();
+        let mut area = Scalar::ZERO;
+
+        for face in sketch.face_iter() {
+            for (i, cycle) in face.all_cycles().enumerate() {
+                // Approximate the cycle, to account for curved edges.
+                let points = cycle
+                    .approx(tolerance)
+                    .points()
+                    .into_iter()
+                    .map(|point| point.local_form)
+                    .collect::<Vec<Point<2>>>();
+
+                // The first cycle is the exterior, all others are holes.
+                let cycle_area = signed_area(&points).abs();
+                if i == 0 {
+                    area += cycle_area;
+                } else {
+                    area -= cycle_area;
+                }
+            }
+        }
+
+        area.into_f64()
+    }
+
+    #[test]
+    fn rectangle_and_circle() {
+        let sketch = intersection(rectangle([0., 0.], [2., 2.]), circle(1.));
+
+        // The result is a quarter of the circle.
+        assert_eq!(sketch.face_iter().count(), 1);
+        let expected = PI / 4.;
+        assert!((area(&sketch) - expected).abs() < expected * 0.01);
+    }
+
+    #[test]
+    fn circle_and_circle() {
+        let sketch = intersection(circle(2.), circle(1.));
+
+        // The smaller circle is fully contained in the larger one, and is
+        // kept as it is, instead of being approximated.
+        assert_eq!(sketch.face_iter().count(), 1);
+        let expected = PI;
+        assert!((area(&sketch) - expected).abs() < expected * 0.01);
+
+        let face = sketch.face_iter().next().unwrap();
+        let half_edges = face.exterior().half_edges().collect::<Vec<_>>();
+        assert_eq!(half_edges.len(), 1);
+        assert!(matches!(
+            half_edges[0].curve().path(),
+            SurfacePath::Circle(circle) if circle.radius() == Scalar::ONE
+        ));
+    }
+
+    #[test]
+    fn contained() {
+        let sketch =
+            intersection(circle(1.), rectangle([-0.5, -0.5], [1., 1.]));
+
+        let exteriors = sketch
+            .face_iter()
+            .map(|face| face.exterior().vertices().count())
+            .collect::<Vec<_>>();
+        assert_eq!(exteriors, [4]);
+        assert_eq!(area(&sketch), 1.);
+    }
+
+    #[test]
+    fn with_hole() {
+        let annulus = fj::Annulus::from_radii(2., 1.).unwrap();
+        let sketch =
+            intersection(rectangle([-3., -3.], [6., 6.]), annulus.into());
+
+        assert_eq!(sketch.face_iter().count(), 1);
+        let expected = PI * (2. * 2. - 1. * 1.);
+        assert!((area(&sketch) - expected).abs() < expected * 0.01);
+    }
+
+    #[test]
+    fn disjoint() {
+        let sketch = intersection(
+            rectangle([0., 0.], [1., 1.]),
+            rectangle([2., 0.], [1., 1.]),
+        );
+        assert_eq!(sketch.face_iter().count(), 0);
+
+        // The bounding boxes overlap, but the shapes don't.
+        let sketch = intersection(circle(1.), rectangle([0.9, 0.9], [1., 1.]));
+        assert_eq!(sketch.face_iter().count(), 0);
+    }
+}
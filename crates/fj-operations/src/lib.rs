@@ -18,32 +18,61 @@
 
 pub mod shape_processor;
 
+mod annulus;
 mod difference_2d;
 mod group;
+mod group_2d;
+mod intersection_2d;
+mod offset_2d;
+mod regular_polygon;
+mod rounded_rectangle;
 mod sketch;
+mod slot;
+mod snap;
+mod star;
 mod sweep;
 mod transform;
+mod union_2d;
 
 use fj_interop::debug::DebugInfo;
 use fj_kernel::{
-    algorithms::validate::{
-        Validate, Validated, ValidationConfig, ValidationError,
+    algorithms::{
+        approx::Tolerance,
+        offset::OffsetError,
+        validate::{Validate, Validated, ValidationConfig, ValidationError},
     },
     objects::{Faces, Sketch},
 };
 use fj_math::Aabb;
 
+/// An error computing the boundary representation of a shape
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, thiserror::Error)]
+pub enum ShapeError {
+    /// Validation of the computed shape failed
+    #[error("Validation failed")]
+    Validation(#[from] ValidationError),
+
+    /// A shape could not be offset
+    #[error("Offsetting failed")]
+    Offset(#[from] OffsetError),
+}
+
 /// Implemented for all operations from the [`fj`] crate
 pub trait Shape {
     /// The type that is used for the shape's boundary representation
     type Brep;
 
     /// Compute the boundary representation of the shape
+    ///
+    /// Shapes whose boundary can't be represented exactly are approximated
+    /// with the given tolerance.
     fn compute_brep(
         &self,
         config: &ValidationConfig,
+        tolerance: Tolerance,
         debug_info: &mut DebugInfo,
-    ) -> Result<Validated<Self::Brep>, ValidationError>;
+    ) -> Result<Validated<Self::Brep>, ShapeError>;
 
     /// Access the axis-aligned bounding box of a shape
     ///
@@ -58,17 +87,20 @@ impl Shape for fj::Shape {
     fn compute_brep(
         &self,
         config: &ValidationConfig,
+        tolerance: Tolerance,
         debug_info: &mut DebugInfo,
-    ) -> Result<Validated<Self::Brep>, ValidationError> {
+    ) -> Result<Validated<Self::Brep>, ShapeError> {
         match self {
-            Self::Shape2d(shape) => shape
-                .compute_brep(config, debug_info)?
+            Self::Shape2d(shape) => Ok(shape
+                .compute_brep(config, tolerance, debug_info)?
                 .into_inner()
                 .into_faces()
-                .validate_with_config(config),
-            Self::Group(shape) => shape.compute_brep(config, debug_info),
-            Self::Sweep(shape) => shape
-                .compute_brep(config, debug_info)?
+                .validate_with_config(config)?),
+            Self::Group(shape) => {
+                shape.compute_brep(config, tolerance, debug_info)
+            }
+            Self::Sweep(shape) => Ok(shape
+                .compute_brep(config, tolerance, debug_info)?
                 .into_inner()
                 .into_shells()
                 .map(|shell| shell.into_faces())
@@ -77,8 +109,10 @@ impl Shape for fj::Shape {
                     a
                 })
                 .unwrap_or_default()
-                .validate_with_config(config),
-            Self::Transform(shape) => shape.compute_brep(config, debug_info),
+                .validate_with_config(config)?),
+            Self::Transform(shape) => {
+                shape.compute_brep(config, tolerance, debug_info)
+            }
         }
     }
 
@@ -98,18 +132,59 @@ impl Shape for fj::Shape2d {
     fn compute_brep(
         &self,
         config: &ValidationConfig,
+        tolerance: Tolerance,
         debug_info: &mut DebugInfo,
-    ) -> Result<Validated<Self::Brep>, ValidationError> {
+    ) -> Result<Validated<Self::Brep>, ShapeError> {
         match self {
-            Self::Difference(shape) => shape.compute_brep(config, debug_info),
-            Self::Sketch(shape) => shape.compute_brep(config, debug_info),
+            Self::Annulus(shape) => {
+                shape.compute_brep(config, tolerance, debug_info)
+            }
+            Self::Difference(shape) => {
+                shape.compute_brep(config, tolerance, debug_info)
+            }
+            Self::Group(shape) => {
+                shape.compute_brep(config, tolerance, debug_info)
+            }
+            Self::Intersection(shape) => {
+                shape.compute_brep(config, tolerance, debug_info)
+            }
+            Self::Offset(shape) => {
+                shape.compute_brep(config, tolerance, debug_info)
+            }
+            Self::RegularPolygon(shape) => {
+                shape.compute_brep(config, tolerance, debug_info)
+            }
+            Self::RoundedRectangle(shape) => {
+                shape.compute_brep(config, tolerance, debug_info)
+            }
+            Self::Sketch(shape) => {
+                shape.compute_brep(config, tolerance, debug_info)
+            }
+            Self::Slot(shape) => {
+                shape.compute_brep(config, tolerance, debug_info)
+            }
+            Self::Star(shape) => {
+                shape.compute_brep(config, tolerance, debug_info)
+            }
+            Self::Union(shape) => {
+                shape.compute_brep(config, tolerance, debug_info)
+            }
         }
     }
 
     fn bounding_volume(&self) -> Aabb<3> {
         match self {
+            Self::Annulus(shape) => shape.bounding_volume(),
             Self::Difference(shape) => shape.bounding_volume(),
+            Self::Group(shape) => shape.bounding_volume(),
+            Self::Intersection(shape) => shape.bounding_volume(),
+            Self::Offset(shape) => shape.bounding_volume(),
+            Self::RegularPolygon(shape) => shape.bounding_volume(),
+            Self::RoundedRectangle(shape) => shape.bounding_volume(),
             Self::Sketch(shape) => shape.bounding_volume(),
+            Self::Slot(shape) => shape.bounding_volume(),
+            Self::Star(shape) => shape.bounding_volume(),
+            Self::Union(shape) => shape.bounding_volume(),
         }
     }
 }
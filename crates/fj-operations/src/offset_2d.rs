@@ -0,0 +1,142 @@
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{
+        approx::Tolerance,
+        offset::Offset,
+        validate::{Validate, Validated, ValidationConfig},
+    },
+    objects::Sketch,
+};
+use fj_math::{Aabb, Scalar, Vector};
+
+use super::{Shape, ShapeError};
+
+impl Shape for fj::Offset2d {
+    type Brep = Sketch;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, ShapeError> {
+        let sketch =
+            self.shape().compute_brep(config, tolerance, debug_info)?;
+
+        let offset = sketch
+            .into_inner()
+            .offset(Scalar::from_f64(self.distance()), tolerance)?;
+
+        Ok(offset.validate_with_config(config)?)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        let aabb = self.shape().bounding_volume();
+
+        // Shrinking a shape keeps it within its original bounds, but growing it
+        // moves every edge outwards by the offset distance.
+        if self.distance() <= 0. {
+            return aabb;
+        }
+
+        let offset = Vector::from([self.distance(), self.distance(), 0.]);
+        Aabb {
+            min: aabb.min - offset,
+            max: aabb.max + offset,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::debug::DebugInfo;
+    use fj_kernel::{
+        algorithms::{approx::Tolerance, validate::ValidationConfig},
+        objects::Sketch,
+        path::SurfacePath,
+    };
+    use fj_math::Scalar;
+
+    use crate::{Shape as _, ShapeError};
+
+    fn offset(shape: fj::Shape2d, distance: f64) -> Sketch {
+        fj::Offset2d::from_shape_and_distance(shape, distance)
+            .compute_brep(
+                &ValidationConfig::default(),
+                Tolerance::from_scalar(0.001).unwrap(),
+                &mut DebugInfo::new(),
+            )
+            .unwrap()
+            .into_inner()
+    }
+
+    #[test]
+    fn rectangle_grows_into_rounded_rectangle() {
+        let rectangle = fj::Sketch::from_points(vec![
+            [0., 0.],
+            [4., 0.],
+            [4., 2.],
+            [0., 2.],
+        ])
+        .into();
+
+        let sketch = offset(rectangle, 0.5);
+
+        let faces = sketch.faces().into_iter().collect::<Vec<_>>();
+        assert_eq!(faces.len(), 1);
+
+        let mut num_lines = 0;
+        let mut num_arcs = 0;
+        for half_edge in faces[0].exterior().half_edges() {
+            match half_edge.curve().path() {
+                SurfacePath::Circle(circle) => {
+                    assert_eq!(circle.radius(), Scalar::from(0.5));
+                    num_arcs += 1;
+                }
+                SurfacePath::Line(_) => num_lines += 1,
+            }
+        }
+        assert_eq!(num_lines, 4);
+        assert_eq!(num_arcs, 4);
+    }
+
+    #[test]
+    fn circle_changes_radius() {
+        let circle: fj::Shape2d =
+            fj::Sketch::from_circle(fj::Circle::from_radius(2.)).into();
+
+        for (distance, radius) in [(0.5, 2.5), (-0.5, 1.5)] {
+            let sketch = offset(circle.clone(), distance);
+
+            let face = sketch.faces().into_iter().next().unwrap();
+            let half_edges = face.exterior().half_edges().collect::<Vec<_>>();
+            assert_eq!(half_edges.len(), 1);
+
+            match half_edges[0].curve().path() {
+                SurfacePath::Circle(circle) => {
+                    assert_eq!(circle.radius(), Scalar::from(radius));
+                }
+                SurfacePath::Line(_) => panic!("Expected circle"),
+            }
+        }
+    }
+
+    #[test]
+    fn collapsing_offset_is_an_error() {
+        let square = fj::Sketch::from_points(vec![
+            [0., 0.],
+            [1., 0.],
+            [1., 1.],
+            [0., 1.],
+        ])
+        .into();
+
+        let result = fj::Offset2d::from_shape_and_distance(square, -1.)
+            .compute_brep(
+                &ValidationConfig::default(),
+                Tolerance::from_scalar(0.001).unwrap(),
+                &mut DebugInfo::new(),
+            );
+        assert!(matches!(result, Err(ShapeError::Offset(_))));
+    }
+}
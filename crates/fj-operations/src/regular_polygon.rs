@@ -0,0 +1,77 @@
+use fj_interop::{debug::DebugInfo, mesh::Color};
+use fj_kernel::{
+    algorithms::{
+        approx::Tolerance,
+        validate::{Validate, Validated, ValidationConfig},
+    },
+    objects::{Face, Sketch, Surface},
+};
+use fj_math::{Aabb, Point};
+
+use super::{Shape, ShapeError};
+
+impl Shape for fj::RegularPolygon {
+    type Brep = Sketch;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        _: Tolerance,
+        _: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, ShapeError> {
+        let surface = Surface::xy_plane();
+
+        let face = Face::build(surface)
+            .polygon_from_points(self.vertices())
+            .into_face()
+            .with_color(Color(self.color()));
+
+        let sketch = Sketch::new().with_faces([face]);
+        Ok(sketch.validate_with_config(config)?)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        Aabb::<3>::from_points(
+            self.vertices()
+                .into_iter()
+                .map(|[x, y]| Point::from([x, y, 0.])),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::debug::DebugInfo;
+    use fj_kernel::{
+        algorithms::{approx::Tolerance, validate::ValidationConfig},
+        iter::ObjectIters,
+    };
+    use fj_math::Scalar;
+
+    use crate::Shape as _;
+
+    #[test]
+    fn regular_polygon() {
+        let hexagon = fj::RegularPolygon::from_sides_and_radius(6, 2.).unwrap();
+
+        let sketch = hexagon
+            .compute_brep(
+                &ValidationConfig::default(),
+                Tolerance::from_scalar(0.001).unwrap(),
+                &mut DebugInfo::new(),
+            )
+            .unwrap()
+            .into_inner();
+
+        let vertices = sketch.global_vertex_iter().collect::<Vec<_>>();
+        assert_eq!(vertices.len(), 6);
+        for vertex in vertices {
+            let distance = vertex.position().coords.magnitude();
+            assert!((distance - Scalar::from(2.)).abs() < Scalar::from(1e-12));
+        }
+
+        let aabb = hexagon.bounding_volume();
+        assert_eq!(aabb.max.x, Scalar::from(2.));
+        assert_eq!(aabb.min.x, Scalar::from(-2.));
+    }
+}
@@ -0,0 +1,185 @@
+use fj_interop::{debug::DebugInfo, mesh::Color};
+use fj_kernel::{
+    algorithms::{
+        approx::Tolerance,
+        validate::{Validate, Validated, ValidationConfig},
+    },
+    objects::{Cycle, Face, HalfEdge, Sketch, Surface},
+};
+use fj_math::{Aabb, Point, Scalar};
+
+use super::{Shape, ShapeError};
+
+impl Shape for fj::RoundedRectangle {
+    type Brep = Sketch;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        _: Tolerance,
+        _: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, ShapeError> {
+        let surface = Surface::xy_plane();
+
+        let radius = Scalar::from_f64(self.radius());
+        let [u, v] = [self.x(), self.y()]
+            .map(|size| Scalar::from_f64(size / 2.) - radius);
+
+        // If the radius is half of both sides, nothing but the corners remain.
+        if u == Scalar::ZERO && v == Scalar::ZERO {
+            let half_edge = HalfEdge::build(surface).circle_from_radius(radius);
+            let cycle = Cycle::new(surface, [half_edge]);
+            let face =
+                Face::new(surface, cycle).with_color(Color(self.color()));
+
+            let sketch = Sketch::new().with_faces([face]);
+            return Ok(sketch.validate_with_config(config)?);
+        }
+
+        // The corners are quarter circles, counter-clockwise from the bottom
+        // right one. If the radius is half of one side, the sides between
+        // some corners are empty, and those corners merge into half circles.
+        //
+        // Start at a corner that follows a side which isn't empty, so no
+        // merged corner wraps around the end of the list.
+        let first = if v > Scalar::ZERO { 1 } else { 0 };
+        let mut corners: Vec<(Point<2>, [Scalar; 2])> = Vec::new();
+        for i in first..first + 4 {
+            let center = match i % 4 {
+                0 => [u, -v],
+                1 => [u, v],
+                2 => [-u, v],
+                _ => [-u, -v],
+            };
+            let start = Scalar::PI / 2. * Scalar::from_f64(i as f64 - 1.);
+            let end = start + Scalar::PI / 2.;
+
+            // The sides below and above the rectangle come before even
+            // corners, the sides to its left and right before odd ones.
+            let side_before = if i % 2 == 0 { u } else { v };
+
+            if side_before == Scalar::ZERO {
+                let (_, angles) = corners
+                    .last_mut()
+                    .expect("First corner follows a side that isn't empty");
+                angles[1] = end;
+            } else {
+                corners.push((Point::from(center), [start, end]));
+            }
+        }
+
+        let arcs = corners
+            .into_iter()
+            .map(|(center, [start, end])| {
+                HalfEdge::build(surface)
+                    .arc_from_center_and_radius(center, radius, start, end)
+            })
+            .collect::<Vec<_>>();
+
+        // The sides connect the exact positions of the vertices of the arcs,
+        // so the edges of the cycle connect.
+        let mut half_edges = Vec::new();
+        for (i, arc) in arcs.iter().enumerate() {
+            let next = &arcs[(i + 1) % arcs.len()];
+
+            let [_, a] = arc.vertices();
+            let [b, _] = next.vertices();
+
+            half_edges.push(arc.clone());
+            half_edges.push(HalfEdge::build(surface).line_segment_from_points(
+                [a, b].map(|vertex| vertex.surface_form().position()),
+            ));
+        }
+
+        let exterior = Cycle::new(surface, half_edges);
+        let face = Face::new(surface, exterior).with_color(Color(self.color()));
+
+        let sketch = Sketch::new().with_faces([face]);
+        Ok(sketch.validate_with_config(config)?)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        let [u, v] = [self.x() / 2., self.y() / 2.];
+
+        Aabb {
+            min: Point::from([-u, -v, 0.0]),
+            max: Point::from([u, v, 0.0]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::debug::DebugInfo;
+    use fj_kernel::{
+        algorithms::{
+            approx::Tolerance, sweep::Sweep, triangulate::Triangulate,
+            validate::ValidationConfig,
+        },
+        objects::Sketch,
+        path::SurfacePath,
+    };
+    use fj_math::Scalar;
+
+    use crate::Shape as _;
+
+    fn rounded_rectangle(x: f64, y: f64, radius: f64) -> Sketch {
+        fj::RoundedRectangle::from_size_and_radius(x, y, radius)
+            .unwrap()
+            .compute_brep(
+                &ValidationConfig::default(),
+                Tolerance::from_scalar(0.001).unwrap(),
+                &mut DebugInfo::new(),
+            )
+            .unwrap()
+            .into_inner()
+    }
+
+    fn count_edges(sketch: &Sketch) -> [usize; 2] {
+        let face = sketch.faces().into_iter().next().unwrap();
+
+        let mut num_lines = 0;
+        let mut num_arcs = 0;
+        for half_edge in face.exterior().half_edges() {
+            match half_edge.curve().path() {
+                SurfacePath::Circle(_) => num_arcs += 1,
+                SurfacePath::Line(_) => num_lines += 1,
+            }
+        }
+
+        [num_lines, num_arcs]
+    }
+
+    #[test]
+    fn rounded_rectangle_edges() {
+        let sketch = rounded_rectangle(4., 2., 0.5);
+        assert_eq!(count_edges(&sketch), [4, 4]);
+
+        // All vertices lie on the sides of the rectangle.
+        let face = sketch.faces().into_iter().next().unwrap();
+        for vertex in face.exterior().vertices() {
+            let [u, v] = vertex.surface_form().position().coords.components;
+            let is_on_side = is_near(u.abs(), 2.) || is_near(v.abs(), 1.);
+            assert!(is_on_side);
+        }
+
+        // Sweeping the rounded rectangle results in a watertight solid.
+        let mesh = sketch.sweep([0., 0., 1.]).triangulate(0.01);
+        assert!(mesh.validate().is_closed());
+    }
+
+    #[test]
+    fn rounded_rectangle_with_maximum_radius() {
+        // The sides along the x-axis remain, the corners merge into half
+        // circles.
+        assert_eq!(count_edges(&rounded_rectangle(4., 2., 1.)), [2, 2]);
+        assert_eq!(count_edges(&rounded_rectangle(2., 4., 1.)), [2, 2]);
+
+        // Nothing but a circle remains.
+        assert_eq!(count_edges(&rounded_rectangle(2., 2., 1.)), [0, 1]);
+    }
+
+    fn is_near(a: Scalar, b: f64) -> bool {
+        (a - b).abs() < Scalar::from(1e-12)
+    }
+}
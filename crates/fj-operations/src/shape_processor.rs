@@ -4,21 +4,37 @@ use fj_interop::{debug::DebugInfo, processed_shape::ProcessedShape};
 use fj_kernel::algorithms::{
     approx::{InvalidTolerance, Tolerance},
     triangulate::Triangulate,
-    validate::{ValidationConfig, ValidationError},
+    validate::ValidationConfig,
 };
 use fj_math::Scalar;
 
-use crate::Shape as _;
+use crate::{snap, Shape as _, ShapeError};
 
 /// Processes an [`fj::Shape`] into a [`ProcessedShape`]
 pub struct ShapeProcessor {
     /// The tolerance value used for creating the triangle mesh
     pub tolerance: Option<Tolerance>,
+
+    /// The size of the grid that input coordinates are snapped to
+    ///
+    /// Snapping makes points that are meant to be coincident, but aren't due
+    /// to numerical inaccuracy in the input, exactly identical. Snapping is
+    /// disabled, if this is `None`.
+    pub snap_grid: Option<Scalar>,
 }
 
 impl ShapeProcessor {
     /// Process an [`fj::Shape`] into [`ProcessedShape`]
     pub fn process(&self, shape: &fj::Shape) -> Result<ProcessedShape, Error> {
+        let snapped;
+        let shape = match self.snap_grid {
+            Some(grid_size) => {
+                snapped = snap::snap_to_grid(shape, grid_size);
+                &snapped
+            }
+            None => shape,
+        };
+
         let aabb = shape.bounding_volume();
 
         let tolerance = match self.tolerance {
@@ -41,7 +57,7 @@ impl ShapeProcessor {
 
         let config = ValidationConfig::default();
         let mut debug_info = DebugInfo::new();
-        let shape = shape.compute_brep(&config, &mut debug_info)?;
+        let shape = shape.compute_brep(&config, tolerance, &mut debug_info)?;
         let mesh = shape.into_inner().triangulate(tolerance);
 
         Ok(ProcessedShape {
@@ -58,7 +74,7 @@ impl ShapeProcessor {
 pub enum Error {
     /// Error converting to shape
     #[error("Error converting to shape")]
-    ToShape(#[from] ValidationError),
+    ToShape(#[from] ShapeError),
 
     /// Model has zero size
     #[error("Model has zero size")]
@@ -1,13 +1,16 @@
+use std::f64::consts::TAU;
+
 use fj_interop::{debug::DebugInfo, mesh::Color};
 use fj_kernel::{
-    algorithms::validate::{
-        Validate, Validated, ValidationConfig, ValidationError,
+    algorithms::{
+        approx::{Approx, Tolerance},
+        validate::{Validate, Validated, ValidationConfig},
     },
     objects::{Cycle, Face, HalfEdge, Sketch, Surface},
 };
-use fj_math::{Aabb, Point, Scalar};
+use fj_math::{Aabb, EllipticalArc, Point, Scalar};
 
-use super::Shape;
+use super::{Shape, ShapeError};
 
 impl Shape for fj::Sketch {
     type Brep = Sketch;
@@ -15,8 +18,9 @@ impl Shape for fj::Sketch {
     fn compute_brep(
         &self,
         config: &ValidationConfig,
+        tolerance: Tolerance,
         _: &mut DebugInfo,
-    ) -> Result<Validated<Self::Brep>, ValidationError> {
+    ) -> Result<Validated<Self::Brep>, ShapeError> {
         let surface = Surface::xy_plane();
 
         let face = match self.chain() {
@@ -30,6 +34,14 @@ impl Shape for fj::Sketch {
 
                 Face::new(surface, cycle).with_color(Color(self.color()))
             }
+            fj::Chain::Ellipse(ellipse) => {
+                // There's no curve that can represent an ellipse, so it needs
+                // to be approximated by a polygon.
+                Face::build(surface)
+                    .polygon_from_points(ellipse_to_polygon(ellipse, tolerance))
+                    .into_face()
+                    .with_color(Color(self.color()))
+            }
             fj::Chain::PolyChain(poly_chain) => {
                 let points =
                     poly_chain.to_points().into_iter().map(Point::from);
@@ -42,7 +54,7 @@ impl Shape for fj::Sketch {
         };
 
         let sketch = Sketch::new().with_faces([face]);
-        sketch.validate_with_config(config)
+        Ok(sketch.validate_with_config(config)?)
     }
 
     fn bounding_volume(&self) -> Aabb<3> {
@@ -51,6 +63,10 @@ impl Shape for fj::Sketch {
                 min: Point::from([-circle.radius(), -circle.radius(), 0.0]),
                 max: Point::from([circle.radius(), circle.radius(), 0.0]),
             },
+            fj::Chain::Ellipse(ellipse) => Aabb {
+                min: Point::from([-ellipse.a(), -ellipse.b(), 0.0]),
+                max: Point::from([ellipse.a(), ellipse.b(), 0.0]),
+            },
             fj::Chain::PolyChain(poly_chain) => Aabb::<3>::from_points(
                 poly_chain
                     .to_points()
@@ -61,3 +77,92 @@ impl Shape for fj::Sketch {
         }
     }
 }
+
+/// Approximate an ellipse using a polygon
+///
+/// The points are spaced adaptively, so they are closer together where the
+/// ellipse is curved more strongly.
+fn ellipse_to_polygon(
+    ellipse: &fj::Ellipse,
+    tolerance: Tolerance,
+) -> Vec<Point<2>> {
+    let arc = EllipticalArc::new(
+        Point::origin(),
+        [ellipse.a(), 0.],
+        [0., ellipse.b()],
+        [0., TAU],
+    );
+
+    // The approximation doesn't include the boundaries of the arc. Both of
+    // them are the same point, which needs to be added once.
+    let mut points = vec![arc.point_from_arc_coords([0.])];
+    points.extend(arc.approx(tolerance).into_iter().map(|(_, point)| point));
+
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::debug::DebugInfo;
+    use fj_kernel::{
+        algorithms::{approx::Tolerance, validate::ValidationConfig},
+        iter::ObjectIters,
+    };
+    use fj_math::Scalar;
+
+    use crate::Shape as _;
+
+    #[test]
+    fn ellipse() {
+        let sketch = fj::Sketch::from_ellipse(
+            fj::Ellipse::from_semi_axes(3., 1.).unwrap(),
+        );
+
+        let brep = sketch
+            .compute_brep(
+                &ValidationConfig::default(),
+                Tolerance::from_scalar(0.001).unwrap(),
+                &mut DebugInfo::new(),
+            )
+            .unwrap()
+            .into_inner();
+
+        let mut num_vertices = 0;
+        for vertex in brep.global_vertex_iter() {
+            let [x, y, z] = vertex.position().coords.components;
+            let on_ellipse = x * x / 9. + y * y;
+
+            assert!((on_ellipse - Scalar::ONE).abs() < Scalar::from(1e-12));
+            assert_eq!(z, Scalar::ZERO);
+
+            num_vertices += 1;
+        }
+        assert!(num_vertices > 3);
+
+        let aabb = sketch.bounding_volume();
+        assert_eq!(aabb.min, [-3., -1., 0.].into());
+        assert_eq!(aabb.max, [3., 1., 0.].into());
+    }
+
+    #[test]
+    fn ellipse_with_large_aspect_ratio() {
+        let sketch = fj::Sketch::from_ellipse(
+            fj::Ellipse::from_semi_axes(100., 1.).unwrap(),
+        );
+
+        let brep = sketch
+            .compute_brep(
+                &ValidationConfig::default(),
+                Tolerance::from_scalar(0.001).unwrap(),
+                &mut DebugInfo::new(),
+            )
+            .unwrap()
+            .into_inner();
+
+        // The points are concentrated where the ellipse is curved strongly,
+        // instead of being spaced for the worst case all around.
+        let num_vertices = brep.global_vertex_iter().count();
+        assert!(num_vertices > 3);
+        assert!(num_vertices < 1000);
+    }
+}
@@ -0,0 +1,120 @@
+use fj_interop::{debug::DebugInfo, mesh::Color};
+use fj_kernel::{
+    algorithms::{
+        approx::Tolerance,
+        validate::{Validate, Validated, ValidationConfig},
+    },
+    objects::{Cycle, Face, HalfEdge, Sketch, Surface},
+};
+use fj_math::{Aabb, Point, Scalar};
+
+use super::{Shape, ShapeError};
+
+impl Shape for fj::Slot {
+    type Brep = Sketch;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        _: Tolerance,
+        _: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, ShapeError> {
+        let surface = Surface::xy_plane();
+
+        let radius = Scalar::from_f64(self.width() / 2.);
+        let offset = Scalar::from_f64(self.length() / 2.) - radius;
+
+        // The rounded ends are half circles around these centers. They are
+        // connected by straight lines at the top and bottom.
+        let [right, left] =
+            [offset, -offset].map(|u| Point::from([u, Scalar::ZERO]));
+
+        let right_arc = HalfEdge::build(surface).arc_from_center_and_radius(
+            right,
+            radius,
+            -Scalar::PI / 2.,
+            Scalar::PI / 2.,
+        );
+        let left_arc = HalfEdge::build(surface).arc_from_center_and_radius(
+            left,
+            radius,
+            Scalar::PI / 2.,
+            Scalar::PI * 3. / 2.,
+        );
+
+        // The lines connect the exact positions of the vertices of the arcs,
+        // so the edges of the cycle connect.
+        let [right_bottom, right_top] = right_arc
+            .vertices()
+            .map(|vertex| vertex.surface_form().position());
+        let [left_top, left_bottom] = left_arc
+            .vertices()
+            .map(|vertex| vertex.surface_form().position());
+
+        let bottom = HalfEdge::build(surface)
+            .line_segment_from_points([left_bottom, right_bottom]);
+        let top = HalfEdge::build(surface)
+            .line_segment_from_points([right_top, left_top]);
+
+        let exterior = Cycle::new(surface, [bottom, right_arc, top, left_arc]);
+        let face = Face::new(surface, exterior).with_color(Color(self.color()));
+
+        let sketch = Sketch::new().with_faces([face]);
+        Ok(sketch.validate_with_config(config)?)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        let [u, v] = [self.length() / 2., self.width() / 2.];
+
+        Aabb {
+            min: Point::from([-u, -v, 0.0]),
+            max: Point::from([u, v, 0.0]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::debug::DebugInfo;
+    use fj_kernel::{
+        algorithms::{
+            approx::Tolerance, sweep::Sweep, triangulate::Triangulate,
+            validate::ValidationConfig,
+        },
+        path::SurfacePath,
+    };
+
+    use crate::Shape as _;
+
+    #[test]
+    fn slot() {
+        let slot = fj::Slot::from_length_and_width(4., 1.).unwrap();
+
+        let sketch = slot
+            .compute_brep(
+                &ValidationConfig::default(),
+                Tolerance::from_scalar(0.001).unwrap(),
+                &mut DebugInfo::new(),
+            )
+            .unwrap()
+            .into_inner();
+
+        let face = sketch.faces().into_iter().next().unwrap();
+        let half_edges = face.exterior().half_edges().collect::<Vec<_>>();
+        assert_eq!(half_edges.len(), 4);
+        assert_eq!(
+            half_edges
+                .iter()
+                .filter(|half_edge| matches!(
+                    half_edge.curve().path(),
+                    SurfacePath::Circle(_)
+                ))
+                .count(),
+            2
+        );
+
+        // Sweeping the slot results in a watertight solid.
+        let mesh = sketch.sweep([0., 0., 1.]).triangulate(0.01);
+        assert!(mesh.validate().is_closed());
+    }
+}
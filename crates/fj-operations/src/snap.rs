@@ -0,0 +1,151 @@
+//! Snapping of input coordinates to a grid
+//!
+//! See [`snap_to_grid`].
+
+use fj_math::{Point, Scalar, Vector};
+
+/// Snap all coordinates of a shape to a grid
+///
+/// Rounds the points of all sketches, as well as all sweep paths and
+/// translation offsets, to the nearest multiple of `grid_size`. Radii,
+/// rotations, and offset distances are left as they are.
+///
+/// A sweep path that is shorter than the grid size could be rounded to zero,
+/// resulting in a degenerate sweep. Such paths are left as they are, too.
+pub fn snap_to_grid(shape: &fj::Shape, grid_size: Scalar) -> fj::Shape {
+    match shape {
+        fj::Shape::Group(group) => fj::Group {
+            a: snap_to_grid(&group.a, grid_size),
+            b: snap_to_grid(&group.b, grid_size),
+        }
+        .into(),
+        fj::Shape::Shape2d(shape) => {
+            fj::Shape::Shape2d(snap_2d_to_grid(shape, grid_size))
+        }
+        fj::Shape::Sweep(sweep) => fj::Sweep::from_path(
+            snap_2d_to_grid(sweep.shape(), grid_size),
+            snap_path(sweep.path(), grid_size),
+        )
+        .with_twist(sweep.twist())
+        .with_symmetric(sweep.is_symmetric())
+        .into(),
+        fj::Shape::Transform(transform) => fj::Transform {
+            shape: snap_to_grid(&transform.shape, grid_size),
+            axis: transform.axis,
+            angle: transform.angle,
+            offset: snap_vector(transform.offset, grid_size),
+        }
+        .into(),
+    }
+}
+
+fn snap_2d_to_grid(shape: &fj::Shape2d, grid_size: Scalar) -> fj::Shape2d {
+    match shape {
+        fj::Shape2d::Annulus(annulus) => annulus.clone().into(),
+        fj::Shape2d::RegularPolygon(polygon) => polygon.clone().into(),
+        fj::Shape2d::RoundedRectangle(rectangle) => rectangle.clone().into(),
+        fj::Shape2d::Slot(slot) => slot.clone().into(),
+        fj::Shape2d::Star(star) => star.clone().into(),
+        fj::Shape2d::Difference(difference) => {
+            let [a, b] = difference.shapes();
+            fj::Difference2d::from_shapes([
+                snap_2d_to_grid(a, grid_size),
+                snap_2d_to_grid(b, grid_size),
+            ])
+            .into()
+        }
+        fj::Shape2d::Group(group) => fj::Group2d::from_shapes(
+            group
+                .shapes()
+                .iter()
+                .map(|shape| snap_2d_to_grid(shape, grid_size)),
+        )
+        .expect("Snapping doesn't change the number of shapes")
+        .into(),
+        fj::Shape2d::Intersection(intersection) => {
+            let [a, b] = intersection.shapes();
+            fj::Intersection2d::from_shapes([
+                snap_2d_to_grid(a, grid_size),
+                snap_2d_to_grid(b, grid_size),
+            ])
+            .into()
+        }
+        fj::Shape2d::Offset(offset) => fj::Offset2d::from_shape_and_distance(
+            snap_2d_to_grid(offset.shape(), grid_size),
+            offset.distance(),
+        )
+        .into(),
+        fj::Shape2d::Sketch(sketch) => match sketch.chain() {
+            fj::Chain::Circle(_) | fj::Chain::Ellipse(_) => {
+                sketch.clone().into()
+            }
+            fj::Chain::PolyChain(poly_chain) => {
+                let points = poly_chain
+                    .to_points()
+                    .into_iter()
+                    .map(|point| {
+                        let point = Point::from(point).snap_to_grid(grid_size);
+                        point.coords.components.map(Scalar::into_f64)
+                    })
+                    .collect();
+
+                fj::Sketch::from_points(points)
+                    .with_color(sketch.color())
+                    .into()
+            }
+        },
+        fj::Shape2d::Union(union) => {
+            let [a, b] = union.shapes();
+            fj::Union2d::from_shapes([
+                snap_2d_to_grid(a, grid_size),
+                snap_2d_to_grid(b, grid_size),
+            ])
+            .into()
+        }
+    }
+}
+
+fn snap_path(path: [f64; 3], grid_size: Scalar) -> [f64; 3] {
+    let snapped = snap_vector(path, grid_size);
+
+    if snapped == [0.; 3] {
+        return path;
+    }
+
+    snapped
+}
+
+fn snap_vector(vector: [f64; 3], grid_size: Scalar) -> [f64; 3] {
+    let point = Point::from(vector).snap_to_grid(grid_size);
+    let vector: Vector<3> = point.coords;
+    vector.components.map(Scalar::into_f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use super::snap_to_grid;
+
+    #[test]
+    fn short_sweep_path_is_not_collapsed() {
+        let square = fj::Sketch::from_points(vec![
+            [0., 0.],
+            [1., 0.],
+            [1., 1.],
+            [0., 1.],
+        ]);
+        let grid_size = Scalar::from(0.1);
+
+        let sweep = |path| {
+            let sweep = fj::Sweep::from_path(square.clone().into(), path);
+            match snap_to_grid(&sweep.into(), grid_size) {
+                fj::Shape::Sweep(sweep) => sweep.path(),
+                _ => unreachable!(),
+            }
+        };
+
+        assert_eq!(sweep([0., 0., 1.01]), [0., 0., 1.]);
+        assert_eq!(sweep([0., 0., 0.01]), [0., 0., 0.01]);
+    }
+}
@@ -0,0 +1,86 @@
+use fj_interop::{debug::DebugInfo, mesh::Color};
+use fj_kernel::{
+    algorithms::{
+        approx::Tolerance,
+        validate::{Validate, Validated, ValidationConfig},
+    },
+    objects::{Face, Sketch, Surface},
+};
+use fj_math::{Aabb, Point};
+
+use super::{Shape, ShapeError};
+
+impl Shape for fj::Star {
+    type Brep = Sketch;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        _: Tolerance,
+        _: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, ShapeError> {
+        let surface = Surface::xy_plane();
+
+        let face = Face::build(surface)
+            .polygon_from_points(self.vertices())
+            .into_face()
+            .with_color(Color(self.color()));
+
+        let sketch = Sketch::new().with_faces([face]);
+        Ok(sketch.validate_with_config(config)?)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        Aabb::<3>::from_points(
+            self.vertices()
+                .into_iter()
+                .map(|[x, y]| Point::from([x, y, 0.])),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use fj_interop::debug::DebugInfo;
+    use fj_kernel::{
+        algorithms::{
+            approx::Tolerance, triangulate::Triangulate,
+            validate::ValidationConfig,
+        },
+        iter::ObjectIters,
+    };
+    use fj_math::Scalar;
+
+    use crate::Shape as _;
+
+    #[test]
+    fn star() {
+        let star = fj::Star::from_points_and_radii(5, 2., 1.).unwrap();
+
+        let sketch = star
+            .compute_brep(
+                &ValidationConfig::default(),
+                Tolerance::from_scalar(0.001).unwrap(),
+                &mut DebugInfo::new(),
+            )
+            .unwrap()
+            .into_inner();
+        assert_eq!(sketch.global_vertex_iter().count(), 10);
+
+        let mesh = sketch.into_faces().triangulate(0.001);
+
+        let mut area = Scalar::ZERO;
+        for triangle in mesh.triangles() {
+            let [a, b, c] = triangle.inner.points();
+            area += (b - a).cross(&(c - a)).magnitude() / 2.;
+        }
+
+        // The star is made up of ten triangles, each spanning from the center
+        // to one outer and one inner vertex. If the triangulation covered any
+        // of the concave notches, the area would be too large.
+        let expected = 5. * 2. * 1. * (PI / 5.).sin();
+        assert!((area.into_f64() - expected).abs() < 1e-9);
+    }
+}
@@ -1,14 +1,18 @@
+use std::f64::consts::PI;
+
 use fj_interop::debug::DebugInfo;
 use fj_kernel::{
     algorithms::{
-        sweep::Sweep,
-        validate::{Validate, Validated, ValidationConfig, ValidationError},
+        approx::Tolerance,
+        sweep::{Sweep, SweepWithTwist},
+        transform::TransformObject,
+        validate::{Validate, Validated, ValidationConfig},
     },
     objects::Solid,
 };
-use fj_math::{Aabb, Vector};
+use fj_math::{Aabb, Transform, Vector};
 
-use super::Shape;
+use super::{Shape, ShapeError};
 
 impl Shape for fj::Sweep {
     type Brep = Solid;
@@ -16,23 +20,153 @@ impl Shape for fj::Sweep {
     fn compute_brep(
         &self,
         config: &ValidationConfig,
+        tolerance: Tolerance,
         debug_info: &mut DebugInfo,
-    ) -> Result<Validated<Self::Brep>, ValidationError> {
-        let sketch = self.shape().compute_brep(config, debug_info)?;
+    ) -> Result<Validated<Self::Brep>, ShapeError> {
+        let sketch =
+            self.shape().compute_brep(config, tolerance, debug_info)?;
         let path = Vector::from(self.path());
 
-        let solid = sketch.into_inner().sweep(path);
-        solid.validate_with_config(config)
+        let sketch = sketch.into_inner().translate(start_offset(self));
+
+        let solid = if self.twist() == 0. {
+            sketch.sweep(path)
+        } else {
+            // Circular edges are approximated, as the twisted sides can only
+            // connect to straight edges.
+            sketch.sweep_with_twist(
+                path,
+                self.twist(),
+                num_twist_steps(self.twist()),
+                tolerance,
+            )
+        };
+        Ok(solid.validate_with_config(config)?)
     }
 
     fn bounding_volume(&self) -> Aabb<3> {
-        self.shape()
-            .bounding_volume()
-            .merged(&Aabb::<3>::from_points(
-                self.shape()
-                    .bounding_volume()
-                    .vertices()
-                    .map(|v| v + self.path()),
-            ))
+        let shape = {
+            let aabb = self.shape().bounding_volume();
+            let offset = start_offset(self);
+
+            Aabb {
+                min: aabb.min + offset,
+                max: aabb.max + offset,
+            }
+        };
+
+        if self.twist() == 0. {
+            return shape.merged(&Aabb::<3>::from_points(
+                shape.vertices().map(|v| v + self.path()),
+            ));
+        }
+
+        // The vertices of the twisted sweep are the vertices of the shape,
+        // rotated and translated at each step. They are all contained in the
+        // bounding volume of the shape, transformed the same way.
+        let path = Vector::from(self.path());
+        let num_steps = num_twist_steps(self.twist());
+        let points = (0..=num_steps).flat_map(|step| {
+            let fraction = step as f64 / num_steps as f64;
+            let transform = Transform::translation(path * fraction)
+                * Transform::rotation(
+                    path.normalize() * self.twist() * fraction,
+                );
+
+            shape
+                .vertices()
+                .map(move |vertex| transform.transform_point(&vertex))
+        });
+
+        Aabb::<3>::from_points(points)
+    }
+}
+
+/// The offset of the start of the sweep from the plane of the shape
+fn start_offset(sweep: &fj::Sweep) -> Vector<3> {
+    if sweep.is_symmetric() {
+        -Vector::from(sweep.path()) / 2.
+    } else {
+        Vector::from([0., 0., 0.])
+    }
+}
+
+/// The number of steps used to approximate the sides of a twisted sweep
+///
+/// Each step rotates the shape by 5 degrees at most.
+fn num_twist_steps(twist: f64) -> usize {
+    const MAX_ANGLE_PER_STEP: f64 = PI / 36.;
+
+    let num_steps = (twist.abs() / MAX_ANGLE_PER_STEP).ceil() as usize;
+    num_steps.max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::debug::DebugInfo;
+    use fj_kernel::{
+        algorithms::{approx::Tolerance, validate::ValidationConfig},
+        iter::ObjectIters,
+    };
+    use fj_math::Scalar;
+
+    use crate::Shape as _;
+
+    #[test]
+    fn symmetric_sweep() {
+        let sketch = fj::Sketch::from_points(vec![
+            [0., 0.],
+            [1., 0.],
+            [1., 1.],
+            [0., 1.],
+        ]);
+        let sweep = fj::Sweep::from_path(sketch.into(), [0., 0., 2.])
+            .with_symmetric(true);
+
+        let solid = sweep
+            .compute_brep(
+                &ValidationConfig::default(),
+                Tolerance::from_scalar(0.001).unwrap(),
+                &mut DebugInfo::new(),
+            )
+            .unwrap()
+            .into_inner();
+
+        // The solid is a prism, so its centroid is in the middle between its
+        // top and bottom. That needs to be the plane of the sketch.
+        let z = solid
+            .global_vertex_iter()
+            .map(|vertex| vertex.position().z)
+            .collect::<Vec<_>>();
+        let min = z.iter().copied().fold(Scalar::MAX, Scalar::min);
+        let max = z.iter().copied().fold(-Scalar::MAX, Scalar::max);
+
+        assert_eq!(min, Scalar::from(-1.));
+        assert_eq!(max, Scalar::from(1.));
+        assert_eq!((min + max) / 2., Scalar::ZERO);
+
+        let aabb = sweep.bounding_volume();
+        assert_eq!(aabb.min.z, Scalar::from(-1.));
+        assert_eq!(aabb.max.z, Scalar::from(1.));
+    }
+
+    #[test]
+    fn twisted_sweep_of_circle() {
+        let circle = fj::Sketch::from_circle(fj::Circle::from_radius(1.));
+        let sweep =
+            fj::Sweep::from_path(circle.into(), [0., 0., 2.]).with_twist(1.);
+
+        let solid = sweep
+            .compute_brep(
+                &ValidationConfig::default(),
+                Tolerance::from_scalar(0.001).unwrap(),
+                &mut DebugInfo::new(),
+            )
+            .unwrap()
+            .into_inner();
+
+        // The circle is approximated, and its sides connected to the top and
+        // bottom using triangles.
+        assert!(solid.face_iter().count() > 2);
     }
 }
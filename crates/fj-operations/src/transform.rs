@@ -1,14 +1,15 @@
 use fj_interop::debug::DebugInfo;
 use fj_kernel::{
     algorithms::{
+        approx::Tolerance,
         transform::TransformObject,
-        validate::{Validate, Validated, ValidationConfig, ValidationError},
+        validate::{Validate, Validated, ValidationConfig},
     },
     objects::Faces,
 };
 use fj_math::{Aabb, Transform, Vector};
 
-use super::Shape;
+use super::{Shape, ShapeError};
 
 impl Shape for fj::Transform {
     type Brep = Faces;
@@ -16,15 +17,16 @@ impl Shape for fj::Transform {
     fn compute_brep(
         &self,
         config: &ValidationConfig,
+        tolerance: Tolerance,
         debug_info: &mut DebugInfo,
-    ) -> Result<Validated<Self::Brep>, ValidationError> {
+    ) -> Result<Validated<Self::Brep>, ShapeError> {
         let faces = self
             .shape
-            .compute_brep(config, debug_info)?
+            .compute_brep(config, tolerance, debug_info)?
             .into_inner()
             .transform(&make_transform(self));
 
-        faces.validate_with_config(config)
+        Ok(faces.validate_with_config(config)?)
     }
 
     fn bounding_volume(&self) -> Aabb<3> {
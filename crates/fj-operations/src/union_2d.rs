@@ -0,0 +1,600 @@
+use std::collections::BTreeMap;
+
+use fj_interop::{debug::DebugInfo, mesh::Color};
+use fj_kernel::{
+    algorithms::{
+        approx::{Approx, Tolerance},
+        validate::{Validate, Validated, ValidationConfig},
+    },
+    iter::ObjectIters,
+    objects::{Cycle, Face, Sketch, Surface},
+};
+use fj_math::{signed_area, Aabb, Point, Scalar};
+
+use super::{Shape, ShapeError};
+
+impl Shape for fj::Union2d {
+    type Brep = Sketch;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, ShapeError> {
+        let [a, b] = self.shapes();
+        let [a, b] = [
+            a.compute_brep(config, tolerance, debug_info)?.into_inner(),
+            b.compute_brep(config, tolerance, debug_info)?.into_inner(),
+        ];
+
+        let color = Color(self.color());
+        let faces_a = a.face_iter().cloned().collect::<Vec<_>>();
+        let faces_b = b.face_iter().cloned().collect::<Vec<_>>();
+
+        let surface = match faces_a.iter().chain(&faces_b).next() {
+            Some(face) => *face.surface(),
+            None => return Ok(Sketch::new().validate_with_config(config)?),
+        };
+        for face in faces_a.iter().chain(&faces_b) {
+            assert_eq!(
+                &surface,
+                face.surface(),
+                "Trying to unite faces with different surfaces.",
+            );
+        }
+
+        let eps = tolerance.inner() / Scalar::from_f64(1000.);
+
+        let rings_a = faces_a
+            .iter()
+            .flat_map(|face| rings_from_face(face, tolerance))
+            .collect::<Vec<_>>();
+        let rings_b = faces_b
+            .iter()
+            .flat_map(|face| rings_from_face(face, tolerance))
+            .collect::<Vec<_>>();
+
+        // If the shapes are apart, there's nothing to merge. Their faces can be
+        // used as they are, which keeps them exact, if they are bounded by
+        // curves.
+        if !overlap(&rings_a, &rings_b) {
+            let faces = faces_a
+                .into_iter()
+                .chain(faces_b)
+                .map(|face| face.with_color(color));
+
+            let union = Sketch::new().with_faces(faces);
+            return Ok(union.validate_with_config(config)?);
+        }
+
+        let faces = combine_boundaries(surface, &rings_a, &rings_b, false, eps)
+            .into_iter()
+            .map(|face| face.with_color(color));
+
+        let union = Sketch::new().with_faces(faces);
+        Ok(union.validate_with_config(config)?)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        let [a, b] = self.shapes();
+        a.bounding_volume().merged(&b.bounding_volume())
+    }
+}
+
+/// Merge the boundaries of two overlapping shapes into new faces
+///
+/// Keeps the region covered by either shape, or, if `keep_inside` is set, the
+/// region covered by both.
+pub(super) fn combine_boundaries(
+    surface: Surface,
+    rings_a: &[Ring],
+    rings_b: &[Ring],
+    keep_inside: bool,
+    eps: Scalar,
+) -> Vec<Face> {
+    let mut points = PointSet::new(eps);
+    for &point in rings_a.iter().chain(rings_b).flatten() {
+        points.snap(point);
+    }
+
+    let segments_a = segments_of(rings_a);
+    let segments_b = segments_of(rings_b);
+    let faces_a = faces_from_rings(rings_a);
+    let faces_b = faces_from_rings(rings_b);
+
+    // Keep all parts of both boundaries that are outside of the other shape,
+    // or inside of it, if `keep_inside` is set. Parts that both boundaries
+    // share are only kept once, and only if both shapes are on the same side
+    // of them. Otherwise they separate the shapes, and are removed.
+    let mut segments = Vec::new();
+    for segment in split_segments(&segments_a, &segments_b, &mut points) {
+        if keep_segment(segment, &segments_b, &faces_b, true, keep_inside, eps)
+        {
+            segments.push(segment);
+        }
+    }
+    for segment in split_segments(&segments_b, &segments_a, &mut points) {
+        if keep_segment(segment, &segments_a, &faces_a, false, keep_inside, eps)
+        {
+            segments.push(segment);
+        }
+    }
+
+    let mut exteriors = Vec::new();
+    let mut holes = Vec::new();
+    for ring in stitch(&segments) {
+        let ring = remove_collinear_points(ring, eps);
+        if ring.len() < 3 {
+            continue;
+        }
+
+        // Zero-area rings are slivers, that are left over where the
+        // boundaries of the shapes overlap.
+        let area = signed_area(&ring);
+        if area > eps * eps {
+            exteriors.push((ring, Vec::new()));
+        } else if area < -eps * eps {
+            holes.push(ring);
+        }
+    }
+
+    let exterior_faces =
+        faces_from_rings(exteriors.iter().map(|(exterior, _)| exterior));
+    for hole in holes {
+        let point = hole[0] + (hole[1] - hole[0]) / 2.;
+
+        let exterior = exteriors
+            .iter_mut()
+            .zip(&exterior_faces)
+            .filter(|(_, face)| face.contains_point(point))
+            .map(|(exterior, _)| exterior)
+            .min_by_key(|exterior| signed_area(&exterior.0));
+
+        if let Some((_, holes)) = exterior {
+            holes.push(hole);
+        }
+    }
+
+    exteriors
+        .into_iter()
+        .map(|(exterior, holes)| {
+            let mut polygon =
+                Face::build(surface).polygon_from_points(exterior);
+            for hole in holes {
+                polygon = polygon.with_hole(hole);
+            }
+
+            polygon.into_face()
+        })
+        .collect()
+}
+
+/// A closed polygon, with the region it bounds on its left side
+pub(super) type Ring = Vec<Point<2>>;
+
+type Segment = [Point<2>; 2];
+
+pub(super) fn rings_from_face(face: &Face, tolerance: Tolerance) -> Vec<Ring> {
+    let mut rings = vec![ring_from_cycle(face.exterior(), tolerance, true)];
+    for cycle in face.interiors() {
+        rings.push(ring_from_cycle(cycle, tolerance, false));
+    }
+
+    rings
+}
+
+fn ring_from_cycle(cycle: &Cycle, tolerance: Tolerance, is_ccw: bool) -> Ring {
+    let mut ring = cycle
+        .approx(tolerance)
+        .points()
+        .into_iter()
+        .map(|point| point.local_form)
+        .collect::<Vec<_>>();
+
+    // The approximation repeats the first point at the end.
+    ring.dedup();
+    ring.pop();
+
+    if (signed_area(&ring) > Scalar::ZERO) != is_ccw {
+        ring.reverse();
+    }
+
+    ring
+}
+
+/// Determine whether the areas bounded by the rings might overlap or touch
+///
+/// This is a conservative check, that looks at the bounding boxes only.
+pub(super) fn overlap(a: &[Ring], b: &[Ring]) -> bool {
+    let [a, b] = [a, b].map(|rings| Aabb::<2>::from_points(rings.concat()));
+
+    a.min.u <= b.max.u
+        && b.min.u <= a.max.u
+        && a.min.v <= b.max.v
+        && b.min.v <= a.max.v
+}
+
+/// Determine whether the area bounded by `inner` lies within `outer`
+///
+/// This is only the case, if the boundaries neither cross, nor touch.
+pub(super) fn contains(outer: &[Ring], inner: &[Ring], eps: Scalar) -> bool {
+    let segments_outer = segments_of(outer);
+    let segments_inner = segments_of(inner);
+    let faces_outer = faces_from_rings(outer);
+    let faces_inner = faces_from_rings(inner);
+
+    let is_apart_from = |point: Point<2>, segments: &[Segment]| {
+        segments
+            .iter()
+            .all(|&segment| distance_to_segment(point, segment) >= eps)
+    };
+
+    let inner_is_inside = inner.iter().flatten().all(|&point| {
+        is_inside(point, &faces_outer) && is_apart_from(point, &segments_outer)
+    });
+    let outer_is_outside = outer.iter().flatten().all(|&point| {
+        !is_inside(point, &faces_inner) && is_apart_from(point, &segments_inner)
+    });
+    let boundaries_cross = segments_inner.iter().any(|&segment| {
+        segments_outer
+            .iter()
+            .any(|&other| !intersection_params(segment, other, eps).is_empty())
+    });
+
+    inner_is_inside && outer_is_outside && !boundaries_cross
+}
+
+fn segments_of(rings: &[Ring]) -> Vec<Segment> {
+    rings
+        .iter()
+        .flat_map(|ring| {
+            (0..ring.len()).map(|i| [ring[i], ring[(i + 1) % ring.len()]])
+        })
+        .collect()
+}
+
+/// Split segments wherever they touch or cross one of `others`
+fn split_segments(
+    segments: &[Segment],
+    others: &[Segment],
+    points: &mut PointSet,
+) -> Vec<Segment> {
+    let mut result = Vec::new();
+
+    for &[p, q] in segments {
+        let mut params = vec![Scalar::ZERO, Scalar::ONE];
+        for &other in others {
+            params.extend(intersection_params([p, q], other, points.eps));
+        }
+        params.sort();
+
+        let split_points = params
+            .into_iter()
+            .map(|t| points.snap(p + (q - p) * t))
+            .collect::<Vec<_>>();
+
+        for window in split_points.windows(2) {
+            if window[0] != window[1] {
+                result.push([window[0], window[1]]);
+            }
+        }
+    }
+
+    result
+}
+
+/// Compute where a segment needs to be split, to account for another one
+///
+/// Returns parameters in the range `(0, 1)` along `segment`.
+fn intersection_params(
+    [p, q]: Segment,
+    [r, s]: Segment,
+    eps: Scalar,
+) -> Vec<Scalar> {
+    let d = q - p;
+    let e = s - r;
+
+    let mut params = Vec::new();
+
+    // Endpoints of the other segment, that lie on this one. This covers
+    // segments that touch, as well as collinear segments that overlap.
+    for point in [r, s] {
+        let t = (point - p).dot(&d) / d.dot(&d);
+        let closest = p + d * t;
+
+        if t > Scalar::ZERO
+            && t < Scalar::ONE
+            && (point - closest).magnitude() < eps
+        {
+            params.push(t);
+        }
+    }
+
+    // Segments that cross each other.
+    let denominator = d.cross(&e);
+    if denominator != Scalar::ZERO {
+        let t = (r - p).cross(&e) / denominator;
+        let u = (r - p).cross(&d) / denominator;
+
+        if t > Scalar::ZERO
+            && t < Scalar::ONE
+            && u > Scalar::ZERO
+            && u < Scalar::ONE
+        {
+            params.push(t);
+        }
+    }
+
+    params
+}
+
+fn keep_segment(
+    [p, q]: Segment,
+    other_segments: &[Segment],
+    other_faces: &[Face],
+    keep_shared: bool,
+    keep_inside: bool,
+    eps: Scalar,
+) -> bool {
+    let midpoint = p + (q - p) / 2.;
+
+    for &[r, s] in other_segments {
+        if distance_to_segment(midpoint, [r, s]) < eps {
+            return keep_shared && (q - p).dot(&(s - r)) > Scalar::ZERO;
+        }
+    }
+
+    is_inside(midpoint, other_faces) == keep_inside
+}
+
+/// Connect segments into rings
+///
+/// Where more than one segment continues from the same point, the shapes
+/// touch there. Taking the leftmost turn keeps the rings of the shapes apart,
+/// instead of creating one ring that touches itself.
+fn stitch(segments: &[Segment]) -> Vec<Ring> {
+    let mut outgoing: BTreeMap<Point<2>, Vec<usize>> = BTreeMap::new();
+    for (i, &[p, _]) in segments.iter().enumerate() {
+        outgoing.entry(p).or_default().push(i);
+    }
+
+    let mut is_used = vec![false; segments.len()];
+    let mut rings = Vec::new();
+
+    for first in 0..segments.len() {
+        if is_used[first] {
+            continue;
+        }
+        is_used[first] = true;
+
+        let mut ring = vec![segments[first][0]];
+        let mut current = first;
+
+        loop {
+            let [p, q] = segments[current];
+            let incoming = q - p;
+
+            let next = outgoing
+                .get(&q)
+                .into_iter()
+                .flatten()
+                .copied()
+                .filter(|&i| !is_used[i] || i == first)
+                .max_by_key(|&i| {
+                    let [r, s] = segments[i];
+                    let outgoing = s - r;
+
+                    incoming.cross(&outgoing).atan2(incoming.dot(&outgoing))
+                });
+
+            match next {
+                Some(next) if next == first => {
+                    rings.push(ring);
+                    break;
+                }
+                Some(next) => {
+                    is_used[next] = true;
+                    ring.push(q);
+                    current = next;
+                }
+                None => {
+                    // The boundary is not closed. This can't happen for valid
+                    // input, and there's nothing sensible to do with it.
+                    break;
+                }
+            }
+        }
+    }
+
+    rings
+}
+
+fn remove_collinear_points(mut ring: Ring, eps: Scalar) -> Ring {
+    let mut i = 0;
+    while i < ring.len() && ring.len() >= 3 {
+        let n = ring.len();
+        let [a, b, c] = [ring[(i + n - 1) % n], ring[i], ring[(i + 1) % n]];
+
+        if distance_to_segment(b, [a, c]) < eps {
+            ring.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+
+    ring
+}
+
+/// Build one face per ring, to determine which points the rings contain
+fn faces_from_rings<'r>(
+    rings: impl IntoIterator<Item = &'r Ring>,
+) -> Vec<Face> {
+    rings
+        .into_iter()
+        .map(|ring| {
+            Face::build(Surface::xy_plane())
+                .polygon_from_points(ring.iter().copied())
+                .into_face()
+        })
+        .collect()
+}
+
+/// Determine whether a point is inside the area that the rings bound
+///
+/// Expects the faces built from the rings by [`faces_from_rings`]. Points on
+/// a ring are not handled consistently, so callers must rule those out first.
+fn is_inside(point: Point<2>, ring_faces: &[Face]) -> bool {
+    let num_containing = ring_faces
+        .iter()
+        .filter(|face| face.contains_point(point))
+        .count();
+
+    num_containing % 2 == 1
+}
+
+fn distance_to_segment(point: Point<2>, [a, b]: Segment) -> Scalar {
+    let ab = b - a;
+    if a == b {
+        return (point - a).magnitude();
+    }
+
+    let t =
+        ((point - a).dot(&ab) / ab.dot(&ab)).clamp(Scalar::ZERO, Scalar::ONE);
+    (point - (a + ab * t)).magnitude()
+}
+
+/// Points that have been snapped together, if they are closer than `eps`
+struct PointSet {
+    points: Vec<Point<2>>,
+    eps: Scalar,
+}
+
+impl PointSet {
+    fn new(eps: Scalar) -> Self {
+        Self {
+            points: Vec::new(),
+            eps,
+        }
+    }
+
+    fn snap(&mut self, point: Point<2>) -> Point<2> {
+        let existing = self
+            .points
+            .iter()
+            .copied()
+            .find(|&existing| (existing - point).magnitude() < self.eps);
+
+        match existing {
+            Some(existing) => existing,
+            None => {
+                self.points.push(point);
+                point
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::debug::DebugInfo;
+    use fj_kernel::{
+        algorithms::{approx::Tolerance, validate::ValidationConfig},
+        iter::ObjectIters,
+        objects::Sketch,
+    };
+    use fj_math::{signed_area, Point, Scalar};
+
+    use crate::Shape as _;
+
+    fn rectangle([x, y]: [f64; 2], [w, h]: [f64; 2]) -> fj::Shape2d {
+        fj::Sketch::from_points(vec![
+            [x, y],
+            [x + w, y],
+            [x + w, y + h],
+            [x, y + h],
+        ])
+        .into()
+    }
+
+    fn union(a: fj::Shape2d, b: fj::Shape2d) -> Sketch {
+        fj::Union2d::from_shapes([a, b])
+            .compute_brep(
+                &ValidationConfig::default(),
+                Tolerance::from_scalar(0.001).unwrap(),
+                &mut DebugInfo::new(),
+            )
+            .unwrap()
+            .into_inner()
+    }
+
+    fn exteriors(sketch: &Sketch) -> Vec<Vec<Point<2>>> {
+        sketch
+            .face_iter()
+            .map(|face| {
+                face.exterior()
+                    .vertices()
+                    .map(|vertex| vertex.surface_form().position())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn overlapping() {
+        let sketch =
+            union(rectangle([0., 0.], [2., 2.]), rectangle([1., 1.], [2., 2.]));
+
+        let exteriors = exteriors(&sketch);
+        assert_eq!(exteriors.len(), 1);
+        assert_eq!(exteriors[0].len(), 8);
+        assert_eq!(signed_area(&exteriors[0]).abs(), Scalar::from(7.));
+    }
+
+    #[test]
+    fn shared_edge() {
+        let sketch =
+            union(rectangle([0., 0.], [1., 1.]), rectangle([1., 0.], [1., 1.]));
+
+        // The shared edge is removed, and its vertices with it.
+        let exteriors = exteriors(&sketch);
+        assert_eq!(exteriors.len(), 1);
+        assert_eq!(exteriors[0].len(), 4);
+        assert_eq!(signed_area(&exteriors[0]).abs(), Scalar::from(2.));
+    }
+
+    #[test]
+    fn partially_shared_edge() {
+        let sketch =
+            union(rectangle([0., 0.], [2., 1.]), rectangle([1., 1.], [2., 1.]));
+
+        let exteriors = exteriors(&sketch);
+        assert_eq!(exteriors.len(), 1);
+        assert_eq!(exteriors[0].len(), 8);
+        assert_eq!(signed_area(&exteriors[0]).abs(), Scalar::from(4.));
+    }
+
+    #[test]
+    fn touching_at_vertex() {
+        let l_shape = fj::Sketch::from_points(vec![
+            [0., 0.],
+            [2., 0.],
+            [2., 1.],
+            [1., 1.],
+            [1., 2.],
+            [0., 2.],
+        ]);
+        let triangle =
+            fj::Sketch::from_points(vec![[1., 1.], [2., 1.5], [1.5, 2.]]);
+        let sketch = union(l_shape.into(), triangle.into());
+
+        // The shapes only touch, so they stay separate faces, without any
+        // zero-area slivers between them.
+        let mut num_vertices = exteriors(&sketch)
+            .iter()
+            .map(|exterior| exterior.len())
+            .collect::<Vec<_>>();
+        num_vertices.sort();
+        assert_eq!(num_vertices, [3, 6]);
+    }
+}
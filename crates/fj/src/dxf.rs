@@ -0,0 +1,714 @@
+//! Import of 2D profiles from DXF files
+//!
+//! See [`import_dxf`].
+
+use std::{
+    error,
+    f64::consts::TAU,
+    fmt,
+    io::{self, Read},
+};
+
+use crate::{Circle, Difference2d, Shape2d, Sketch, Union2d};
+
+/// The number of segments that approximate a full circle
+///
+/// Only circles centered on the origin can be represented exactly. All other
+/// circles and arcs are approximated by line segments.
+const SEGMENTS_PER_TURN: usize = 64;
+
+/// The maximum distance between two points that are considered to be the same
+///
+/// Used to connect the entities of a DXF file into closed loops.
+const CONNECT_TOLERANCE: f64 = 1e-6;
+
+/// Import a 2D profile from an ASCII DXF file
+///
+/// Reads the `LINE`, `LWPOLYLINE`, `CIRCLE`, and `ARC` entities from the
+/// `ENTITIES` section of the file, and connects them into closed loops. A
+/// closed polyline becomes a polygon, and a circle becomes a [`Circle`], if it
+/// is centered on the origin. Lines, arcs, and open polylines are connected at
+/// their end points. Arcs, polyline bulges, and circles that aren't centered on
+/// the origin are approximated by line segments.
+///
+/// A loop that is inside another loop is a hole, which is subtracted from the
+/// enclosing loop using [`Difference2d`]. A loop inside a hole is material
+/// again, and so forth. Multiple disjoint outer loops are combined using
+/// [`Union2d`].
+///
+/// Returns an error, if the file can't be read or parsed, if it contains any
+/// other entities, or if its entities don't form closed loops.
+pub fn import_dxf(mut reader: impl Read) -> Result<Shape2d, DxfError> {
+    let mut source = String::new();
+    reader.read_to_string(&mut source).map_err(DxfError::Io)?;
+
+    let pairs = parse_pairs(&source)?;
+
+    let mut closed = Vec::new();
+    let mut open = Vec::new();
+    for entity in entities(&pairs) {
+        match entity.kind {
+            "LINE" => {
+                let start = entity.point(10)?;
+                let end = entity.point(11)?;
+                open.push(vec![start, end]);
+            }
+            "LWPOLYLINE" => {
+                let (points, is_closed) = entity.polyline()?;
+                if is_closed {
+                    closed.push(Loop::Polygon(points));
+                } else {
+                    open.push(points);
+                }
+            }
+            "CIRCLE" => {
+                let center = entity.point(10)?;
+                let radius = entity.value(40)?;
+                closed.push(Loop::Circle { center, radius });
+            }
+            "ARC" => {
+                let center = entity.point(10)?;
+                let radius = entity.value(40)?;
+                let start = entity.value(50)?.to_radians();
+                let end = entity.value(51)?.to_radians();
+
+                // Arcs go counter-clockwise from start to end. Equal angles
+                // describe a full circle.
+                let sweep = (end - start).rem_euclid(TAU);
+                let sweep = if sweep == 0. { TAU } else { sweep };
+                open.push(arc_points(center, radius, start, sweep));
+            }
+            kind => {
+                return Err(DxfError::UnsupportedEntity {
+                    kind: kind.to_string(),
+                    line: entity.line,
+                })
+            }
+        }
+    }
+
+    closed.extend(connect(open)?.into_iter().map(Loop::Polygon));
+
+    combine(closed).ok_or(DxfError::NoGeometry)
+}
+
+/// Error importing a DXF file
+///
+/// See [`import_dxf`].
+#[derive(Debug)]
+pub enum DxfError {
+    /// The file could not be read
+    Io(io::Error),
+
+    /// The file is not a valid ASCII DXF file
+    Parse {
+        /// The line at which the error was detected
+        line: usize,
+
+        /// A description of the error
+        message: String,
+    },
+
+    /// The file contains an entity that can't be imported
+    UnsupportedEntity {
+        /// The type of the entity
+        kind: String,
+
+        /// The line at which the entity starts
+        line: usize,
+    },
+
+    /// The entities of the file don't form a closed loop
+    OpenLoop {
+        /// The end point of the loop that doesn't connect to anything
+        point: [f64; 2],
+    },
+
+    /// The file doesn't contain any entities that could be imported
+    NoGeometry,
+}
+
+impl fmt::Display for DxfError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "Failed to read DXF file: {err}"),
+            Self::Parse { line, message } => {
+                write!(f, "Invalid DXF file (line {line}): {message}")
+            }
+            Self::UnsupportedEntity { kind, line } => write!(
+                f,
+                "Unsupported DXF entity `{kind}` (line {line}); supported are \
+                `LINE`, `LWPOLYLINE`, `CIRCLE`, and `ARC`"
+            ),
+            Self::OpenLoop { point: [x, y] } => {
+                write!(f, "DXF outline is not closed at ({x}, {y})")
+            }
+            Self::NoGeometry => write!(f, "DXF file contains no geometry"),
+        }
+    }
+}
+
+impl error::Error for DxfError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// A group code and its value, the basic building block of a DXF file
+struct Pair<'a> {
+    code: i32,
+    value: &'a str,
+    line: usize,
+}
+
+fn parse_pairs(source: &str) -> Result<Vec<Pair>, DxfError> {
+    let mut lines = source.lines().enumerate();
+    let mut pairs = Vec::new();
+
+    while let Some((index, code)) = lines.next() {
+        let line = index + 1;
+
+        let code = code.trim();
+        if code.is_empty() {
+            continue;
+        }
+
+        let code = code.parse().map_err(|_| DxfError::Parse {
+            line,
+            message: format!("Expected group code, found `{code}`"),
+        })?;
+        let value = match lines.next() {
+            Some((_, value)) => value.trim(),
+            None => {
+                return Err(DxfError::Parse {
+                    line,
+                    message: format!("Missing value for group code {code}"),
+                })
+            }
+        };
+
+        pairs.push(Pair { code, value, line });
+    }
+
+    Ok(pairs)
+}
+
+/// An entity from the `ENTITIES` section
+struct Entity<'a> {
+    kind: &'a str,
+    line: usize,
+    pairs: &'a [Pair<'a>],
+}
+
+impl Entity<'_> {
+    fn value(&self, code: i32) -> Result<f64, DxfError> {
+        let pair = self
+            .pairs
+            .iter()
+            .find(|pair| pair.code == code)
+            .ok_or_else(|| DxfError::Parse {
+                line: self.line,
+                message: format!(
+                    "`{}` is missing group code {code}",
+                    self.kind
+                ),
+            })?;
+
+        parse_number(pair)
+    }
+
+    fn point(&self, code: i32) -> Result<[f64; 2], DxfError> {
+        Ok([self.value(code)?, self.value(code + 10)?])
+    }
+
+    /// Convert a `LWPOLYLINE` into points, and whether it is closed
+    ///
+    /// If the polyline is closed, the points don't repeat the first one.
+    fn polyline(&self) -> Result<(Vec<[f64; 2]>, bool), DxfError> {
+        let mut is_closed = false;
+
+        // Each vertex has a position and a bulge, which defines the arc to
+        // the next vertex.
+        let mut vertices: Vec<([f64; 2], f64)> = Vec::new();
+        for pair in self.pairs {
+            match pair.code {
+                10 => vertices.push(([parse_number(pair)?, 0.], 0.)),
+                20 => {
+                    if let Some((point, _)) = vertices.last_mut() {
+                        point[1] = parse_number(pair)?;
+                    }
+                }
+                42 => {
+                    if let Some((_, bulge)) = vertices.last_mut() {
+                        *bulge = parse_number(pair)?;
+                    }
+                }
+                70 => {
+                    let flags: i32 =
+                        pair.value.parse().map_err(|_| DxfError::Parse {
+                            line: pair.line,
+                            message: format!(
+                                "Expected integer, found `{}`",
+                                pair.value
+                            ),
+                        })?;
+                    is_closed = flags & 1 == 1;
+                }
+                _ => {}
+            }
+        }
+
+        if vertices.len() < 2 {
+            return Err(DxfError::Parse {
+                line: self.line,
+                message: "`LWPOLYLINE` has fewer than two vertices".into(),
+            });
+        }
+
+        let num_segments = if is_closed {
+            vertices.len()
+        } else {
+            vertices.len() - 1
+        };
+
+        let mut points = vec![vertices[0].0];
+        for i in 0..num_segments {
+            let (start, bulge) = vertices[i];
+            let (end, _) = vertices[(i + 1) % vertices.len()];
+
+            if bulge == 0. {
+                points.push(end);
+            } else {
+                points.extend(bulge_points(start, end, bulge).skip(1));
+            }
+        }
+
+        if is_closed {
+            points.pop();
+        }
+
+        Ok((points, is_closed))
+    }
+}
+
+fn parse_number(pair: &Pair) -> Result<f64, DxfError> {
+    let number: f64 = pair.value.parse().map_err(|_| DxfError::Parse {
+        line: pair.line,
+        message: format!("Expected number, found `{}`", pair.value),
+    })?;
+
+    if !number.is_finite() {
+        return Err(DxfError::Parse {
+            line: pair.line,
+            message: format!("Expected finite number, found `{}`", pair.value),
+        });
+    }
+
+    Ok(number)
+}
+
+/// Split the `ENTITIES` section of a DXF file into entities
+///
+/// All other sections are ignored.
+fn entities<'a>(pairs: &'a [Pair<'a>]) -> Vec<Entity<'a>> {
+    let mut entities = Vec::new();
+
+    let mut i = 0;
+    let mut in_entities = false;
+    while i < pairs.len() {
+        let pair = &pairs[i];
+        i += 1;
+
+        if pair.code != 0 {
+            continue;
+        }
+
+        match pair.value {
+            "SECTION" => {
+                in_entities = pairs.get(i).map_or(false, |next| {
+                    next.code == 2 && next.value == "ENTITIES"
+                });
+            }
+            "ENDSEC" => in_entities = false,
+            kind if in_entities => {
+                let start = i;
+                while i < pairs.len() && pairs[i].code != 0 {
+                    i += 1;
+                }
+
+                entities.push(Entity {
+                    kind,
+                    line: pair.line,
+                    pairs: &pairs[start..i],
+                });
+            }
+            _ => {}
+        }
+    }
+
+    entities
+}
+
+/// Compute the points on a counter-clockwise arc, including its end points
+///
+/// A negative `sweep` results in a clockwise arc.
+fn arc_points(
+    center: [f64; 2],
+    radius: f64,
+    start: f64,
+    sweep: f64,
+) -> Vec<[f64; 2]> {
+    let num_segments = (sweep.abs() / TAU * SEGMENTS_PER_TURN as f64).ceil();
+    let num_segments = num_segments.max(1.) as usize;
+
+    (0..=num_segments)
+        .map(|i| {
+            let angle = start + sweep * i as f64 / num_segments as f64;
+            [
+                center[0] + radius * angle.cos(),
+                center[1] + radius * angle.sin(),
+            ]
+        })
+        .collect()
+}
+
+/// Compute the points on the arc between two polyline vertices
+///
+/// The bulge is the tangent of a quarter of the arc's included angle. It is
+/// positive, if the arc runs counter-clockwise.
+fn bulge_points(
+    start: [f64; 2],
+    end: [f64; 2],
+    bulge: f64,
+) -> impl Iterator<Item = [f64; 2]> {
+    let sweep = 4. * bulge.atan();
+
+    let chord = [end[0] - start[0], end[1] - start[1]];
+    let length = chord[0].hypot(chord[1]);
+
+    // The center is on the line that bisects the chord, at a signed distance
+    // to the left of it.
+    let distance = length / 2. / (sweep / 2.).tan();
+    let center = [
+        (start[0] + end[0]) / 2. - chord[1] / length * distance,
+        (start[1] + end[1]) / 2. + chord[0] / length * distance,
+    ];
+    let radius = (length / 2. / (sweep / 2.).sin()).abs();
+    let start_angle = (start[1] - center[1]).atan2(start[0] - center[0]);
+
+    let mut points = arc_points(center, radius, start_angle, sweep);
+
+    // Use the exact vertices, so consecutive segments connect.
+    let last = points.len() - 1;
+    points[0] = start;
+    points[last] = end;
+
+    points.into_iter()
+}
+
+/// Connect open chains of points into closed loops
+fn connect(
+    mut chains: Vec<Vec<[f64; 2]>>,
+) -> Result<Vec<Vec<[f64; 2]>>, DxfError> {
+    let is_same = |a: [f64; 2], b: [f64; 2]| {
+        (a[0] - b[0]).hypot(a[1] - b[1]) <= CONNECT_TOLERANCE
+    };
+
+    let mut loops = Vec::new();
+    while !chains.is_empty() {
+        let mut points = chains.remove(0);
+
+        loop {
+            let first = points[0];
+            let last = points[points.len() - 1];
+
+            if points.len() > 2 && is_same(first, last) {
+                points.pop();
+                loops.push(points);
+                break;
+            }
+
+            let next = chains.iter().position(|chain| {
+                is_same(chain[0], last) || is_same(chain[chain.len() - 1], last)
+            });
+            let mut next = match next {
+                Some(index) => chains.remove(index),
+                None => return Err(DxfError::OpenLoop { point: last }),
+            };
+
+            if !is_same(next[0], last) {
+                next.reverse();
+            }
+            points.extend(next.into_iter().skip(1));
+        }
+    }
+
+    Ok(loops)
+}
+
+/// A closed loop from an imported file
+pub(crate) enum Loop {
+    Circle { center: [f64; 2], radius: f64 },
+    Polygon(Vec<[f64; 2]>),
+}
+
+impl Loop {
+    fn points(&self) -> Vec<[f64; 2]> {
+        match self {
+            Self::Circle { center, radius } => {
+                let mut points = arc_points(*center, *radius, 0., TAU);
+                points.pop();
+                points
+            }
+            Self::Polygon(points) => points.clone(),
+        }
+    }
+
+    fn contains(&self, point: [f64; 2]) -> bool {
+        if let Self::Circle { center, radius } = self {
+            return (point[0] - center[0]).hypot(point[1] - center[1])
+                < *radius;
+        }
+
+        // Count the crossings of a ray in the positive x-direction.
+        let points = self.points();
+        let mut is_inside = false;
+        for i in 0..points.len() {
+            let [a, b] = [points[i], points[(i + 1) % points.len()]];
+
+            if (a[1] > point[1]) != (b[1] > point[1]) {
+                let x =
+                    a[0] + (point[1] - a[1]) / (b[1] - a[1]) * (b[0] - a[0]);
+                if point[0] < x {
+                    is_inside = !is_inside;
+                }
+            }
+        }
+
+        is_inside
+    }
+
+    fn to_shape(&self) -> Shape2d {
+        if let Self::Circle { center, radius } = *self {
+            if center[0].hypot(center[1]) <= CONNECT_TOLERANCE {
+                return Sketch::from_circle(Circle::from_radius(radius)).into();
+            }
+        }
+
+        let mut points = self.points();
+
+        // Make sure the polygon is counter-clockwise.
+        let double_area: f64 = (0..points.len())
+            .map(|i| {
+                let [a, b] = [points[i], points[(i + 1) % points.len()]];
+                a[0] * b[1] - b[0] * a[1]
+            })
+            .sum();
+        if double_area < 0. {
+            points.reverse();
+        }
+
+        Sketch::from_points(points).into()
+    }
+}
+
+/// Combine the loops into a single shape, based on how they are nested
+///
+/// See [`import_dxf`] for how nested loops are interpreted.
+pub(crate) fn combine(loops: Vec<Loop>) -> Option<Shape2d> {
+    // Loops don't intersect, so any point of a loop can be used to determine
+    // whether it is inside of another one.
+    let is_inside =
+        |i: usize, j: usize| loops[j].contains(loops[i].points()[0]);
+
+    let depths = (0..loops.len())
+        .map(|i| {
+            (0..loops.len())
+                .filter(|&j| j != i && is_inside(i, j))
+                .count()
+        })
+        .collect::<Vec<_>>();
+
+    // Loops with an even depth are material. Every loop with an odd depth is
+    // a hole in the loop that directly encloses it.
+    (0..loops.len())
+        .filter(|&i| depths[i] % 2 == 0)
+        .map(|i| {
+            (0..loops.len())
+                .filter(|&j| depths[j] == depths[i] + 1 && is_inside(j, i))
+                .fold(loops[i].to_shape(), |shape, j| {
+                    let hole = loops[j].to_shape();
+                    Difference2d::from_shapes([shape, hole]).into()
+                })
+        })
+        .reduce(|a, b| Union2d::from_shapes([a, b]).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Chain, Shape2d};
+
+    use super::{import_dxf, DxfError};
+
+    fn dxf(entities: &[&str]) -> String {
+        let mut source = String::from("0\nSECTION\n2\nHEADER\n0\nENDSEC\n");
+        source.push_str("0\nSECTION\n2\nENTITIES\n");
+        for entity in entities {
+            source.push_str(entity);
+        }
+        source.push_str("0\nENDSEC\n0\nEOF\n");
+        source
+    }
+
+    fn line([x1, y1]: [f64; 2], [x2, y2]: [f64; 2]) -> String {
+        format!("0\nLINE\n8\n0\n10\n{x1}\n20\n{y1}\n11\n{x2}\n21\n{y2}\n")
+    }
+
+    fn import(entities: &[&str]) -> Result<Shape2d, DxfError> {
+        import_dxf(dxf(entities).as_bytes())
+    }
+
+    fn polygon_points(shape: &Shape2d) -> Vec<[f64; 2]> {
+        match shape {
+            Shape2d::Sketch(sketch) => match sketch.chain() {
+                Chain::PolyChain(chain) => chain.to_points(),
+                chain => panic!("Expected polygon, got {chain:?}"),
+            },
+            shape => panic!("Expected sketch, got {shape:?}"),
+        }
+    }
+
+    const SQUARE: &str = "0\nLWPOLYLINE\n90\n4\n70\n1\n\
+        10\n-2\n20\n-2\n10\n2\n20\n-2\n10\n2\n20\n2\n10\n-2\n20\n2\n";
+
+    #[test]
+    fn test_import_dxf_closed_polyline() {
+        let shape = import(&[SQUARE]).unwrap();
+
+        assert_eq!(
+            polygon_points(&shape),
+            vec![[-2., -2.], [2., -2.], [2., 2.], [-2., 2.]]
+        );
+    }
+
+    #[test]
+    fn test_import_dxf_polyline_bulge() {
+        // A half circle below the x-axis, closed by a straight line.
+        let polyline = "0\nLWPOLYLINE\n90\n2\n70\n1\n\
+            10\n0\n20\n0\n42\n1\n10\n2\n20\n0\n";
+        let points = polygon_points(&import(&[polyline]).unwrap());
+
+        assert!(points.len() > 8);
+        assert!(points.contains(&[0., 0.]));
+        assert!(points.contains(&[2., 0.]));
+        for [x, y] in points {
+            assert!(y <= 0.);
+            assert!(((x - 1.).hypot(y) - 1.).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_import_dxf_circle() {
+        let circle = "0\nCIRCLE\n10\n0\n20\n0\n30\n0\n40\n1.5\n";
+        let shape = import(&[circle]).unwrap();
+
+        match shape {
+            Shape2d::Sketch(sketch) => match sketch.chain() {
+                Chain::Circle(circle) => assert_eq!(circle.radius(), 1.5),
+                chain => panic!("Expected circle, got {chain:?}"),
+            },
+            shape => panic!("Expected sketch, got {shape:?}"),
+        }
+
+        // A circle that isn't centered on the origin is approximated.
+        let circle = "0\nCIRCLE\n10\n1\n20\n0\n40\n1\n";
+        let shape = import(&[circle]).unwrap();
+        for [x, y] in polygon_points(&shape) {
+            assert!(((x - 1.).hypot(y) - 1.).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_import_dxf_lines_and_arcs() {
+        // A slot, made from two lines and two half circles, out of order.
+        let arc_right = "0\nARC\n10\n1\n20\n0\n40\n1\n50\n270\n51\n90\n";
+        let arc_left = "0\nARC\n10\n-1\n20\n0\n40\n1\n50\n90\n51\n270\n";
+        let bottom = line([-1., -1.], [1., -1.]);
+        let top = line([-1., 1.], [1., 1.]);
+
+        let shape = import(&[&bottom, arc_left, &top, arc_right]).unwrap();
+
+        let points = polygon_points(&shape);
+        assert!(points.len() > 8);
+        for [x, y] in points {
+            let distance = if x > 1. {
+                (x - 1.).hypot(y)
+            } else if x < -1. {
+                (x + 1.).hypot(y)
+            } else {
+                y.abs()
+            };
+            assert!((distance - 1.).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_import_dxf_hole() {
+        let hole = "0\nCIRCLE\n10\n0\n20\n0\n40\n1\n";
+        let shape = import(&[SQUARE, hole]).unwrap();
+
+        match shape {
+            Shape2d::Difference(difference) => {
+                let [outer, hole] = difference.shapes();
+                assert_eq!(polygon_points(outer).len(), 4);
+                assert!(matches!(hole, Shape2d::Sketch(_)));
+            }
+            shape => panic!("Expected difference, got {shape:?}"),
+        }
+
+        // Disjoint loops are combined.
+        let other = "0\nCIRCLE\n10\n5\n20\n0\n40\n1\n";
+        let shape = import(&[SQUARE, hole, other]).unwrap();
+        assert!(matches!(shape, Shape2d::Union(_)));
+    }
+
+    #[test]
+    fn test_import_dxf_errors() {
+        let spline = "0\nSPLINE\n10\n0\n20\n0\n";
+        assert!(matches!(
+            import(&[SQUARE, spline]),
+            Err(DxfError::UnsupportedEntity { kind, .. }) if kind == "SPLINE"
+        ));
+
+        let open = line([0., 0.], [1., 0.]);
+        assert!(matches!(
+            import(&[&open]),
+            Err(DxfError::OpenLoop { point }) if point == [1., 0.]
+        ));
+
+        assert!(matches!(import(&[]), Err(DxfError::NoGeometry)));
+
+        let invalid = "0\nLINE\n10\nx\n20\n0\n11\n1\n21\n0\n";
+        assert!(matches!(import(&[invalid]), Err(DxfError::Parse { .. })));
+
+        for value in ["inf", "-inf", "NaN"] {
+            let arc =
+                format!("0\nARC\n10\n0\n20\n0\n40\n1\n50\n{value}\n51\n90\n");
+            assert!(matches!(import(&[&arc]), Err(DxfError::Parse { .. })));
+
+            let circle = format!("0\nCIRCLE\n10\n0\n20\n0\n40\n{value}\n");
+            assert!(matches!(import(&[&circle]), Err(DxfError::Parse { .. })));
+        }
+    }
+
+    #[test]
+    fn test_import_dxf_arc_with_large_angles() {
+        // Angles this large can't be normalized by adding full turns. The
+        // import must finish anyway.
+        let arc = "0\nARC\n10\n0\n20\n0\n40\n1\n50\n1e300\n51\n1e300\n";
+        let _ = import(&[arc]);
+    }
+}
@@ -23,14 +23,22 @@ pub mod syntax;
 #[doc(hidden)]
 pub mod abi;
 mod angle;
+mod dxf;
 mod group;
 pub mod models;
 mod shape_2d;
+mod svg;
 mod sweep;
 mod transform;
 
 pub use self::{
-    angle::*, group::Group, shape_2d::*, sweep::Sweep, transform::Transform,
+    angle::*,
+    dxf::{import_dxf, DxfError},
+    group::Group,
+    shape_2d::*,
+    svg::{import_svg_path, SvgError},
+    sweep::Sweep,
+    transform::Transform,
 };
 pub use fj_proc::*;
 #[cfg(feature = "serde")]
@@ -1,66 +1,1188 @@
 #[cfg(feature = "serde")]
 use serde::{de, ser, Deserialize, Serialize};
-use std::mem;
 use std::sync::atomic;
+use std::{error, fmt, mem};
 
-use crate::Shape;
+use crate::{Angle, Shape};
 
 /// A 2-dimensional shape
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[repr(C)]
 pub enum Shape2d {
+    /// An annulus
+    Annulus(Annulus),
+
     /// A difference between two shapes
     Difference(Box<Difference2d>),
 
-    /// A sketch
-    Sketch(Sketch),
+    /// A group of shapes
+    Group(Group2d),
+
+    /// An intersection of two shapes
+    Intersection(Box<Intersection2d>),
+
+    /// A shape whose boundary has been offset
+    Offset(Box<Offset2d>),
+
+    /// A regular polygon
+    RegularPolygon(RegularPolygon),
+
+    /// A rectangle with rounded corners
+    RoundedRectangle(RoundedRectangle),
+
+    /// A sketch
+    Sketch(Sketch),
+
+    /// A slot
+    Slot(Slot),
+
+    /// A star
+    Star(Star),
+
+    /// A union of two shapes
+    Union(Box<Union2d>),
+}
+
+impl Shape2d {
+    /// Get the rendering color of the larger object in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        match &self {
+            Shape2d::Annulus(a) => a.color(),
+            Shape2d::Sketch(s) => s.color(),
+            Shape2d::Slot(s) => s.color(),
+            Shape2d::Star(s) => s.color(),
+            Shape2d::Difference(d) => d.color(),
+            Shape2d::Group(g) => g.color(),
+            Shape2d::Intersection(i) => i.color(),
+            Shape2d::Offset(o) => o.color(),
+            Shape2d::RegularPolygon(p) => p.color(),
+            Shape2d::RoundedRectangle(r) => r.color(),
+            Shape2d::Union(u) => u.color(),
+        }
+    }
+
+    /// Compare two shapes, allowing their dimensions to differ slightly
+    ///
+    /// Returns `true`, if both shapes have the same structure and color, and
+    /// all of their dimensions (radii, coordinates of points) differ by no
+    /// more than `tolerance`. This is useful for comparing shapes whose
+    /// dimensions were computed, and might be affected by rounding errors.
+    pub fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        match (self, other) {
+            (Self::Annulus(a), Self::Annulus(b)) => a.approx_eq(b, tolerance),
+            (Self::Difference(a), Self::Difference(b)) => {
+                a.approx_eq(b, tolerance)
+            }
+            (Self::Group(a), Self::Group(b)) => a.approx_eq(b, tolerance),
+            (Self::Intersection(a), Self::Intersection(b)) => {
+                a.approx_eq(b, tolerance)
+            }
+            (Self::Offset(a), Self::Offset(b)) => a.approx_eq(b, tolerance),
+            (Self::RegularPolygon(a), Self::RegularPolygon(b)) => {
+                a.approx_eq(b, tolerance)
+            }
+            (Self::RoundedRectangle(a), Self::RoundedRectangle(b)) => {
+                a.approx_eq(b, tolerance)
+            }
+            (Self::Sketch(a), Self::Sketch(b)) => a.approx_eq(b, tolerance),
+            (Self::Slot(a), Self::Slot(b)) => a.approx_eq(b, tolerance),
+            (Self::Star(a), Self::Star(b)) => a.approx_eq(b, tolerance),
+            (Self::Union(a), Self::Union(b)) => a.approx_eq(b, tolerance),
+            _ => false,
+        }
+    }
+}
+
+/// An annulus, i.e. the ring between two concentric circles
+///
+/// The annulus is centered on the origin.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Annulus {
+    /// The radius of the outer circle
+    outer: f64,
+
+    /// The radius of the inner circle
+    inner: f64,
+
+    // The color of the annulus in RGBA
+    color: [u8; 4],
+}
+
+impl Annulus {
+    /// Construct an annulus from the radii of its circles
+    ///
+    /// Returns an error, if the inner radius is not positive, or if it's not
+    /// smaller than the outer radius.
+    pub fn from_radii(outer: f64, inner: f64) -> Result<Self, InvalidAnnulus> {
+        if inner <= 0. || inner.is_nan() {
+            return Err(InvalidAnnulus::InnerNotPositive(inner));
+        }
+        if inner >= outer || outer.is_nan() {
+            return Err(InvalidAnnulus::InnerNotSmaller { outer, inner });
+        }
+
+        Ok(Self {
+            outer,
+            inner,
+            color: [255, 0, 0, 255],
+        })
+    }
+
+    /// Set the rendering color of the annulus in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Access the radius of the outer circle
+    pub fn outer(&self) -> f64 {
+        self.outer
+    }
+
+    /// Access the radius of the inner circle
+    pub fn inner(&self) -> f64 {
+        self.inner
+    }
+
+    /// Get the rendering color of the annulus in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+
+    /// Compare two annuli, allowing their radii to differ slightly
+    ///
+    /// See [`Shape2d::approx_eq`].
+    pub fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        self.color == other.color
+            && (self.outer - other.outer).abs() <= tolerance
+            && (self.inner - other.inner).abs() <= tolerance
+    }
+}
+
+impl From<Annulus> for Shape {
+    fn from(shape: Annulus) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<Annulus> for Shape2d {
+    fn from(shape: Annulus) -> Self {
+        Self::Annulus(shape)
+    }
+}
+
+/// Error creating an [`Annulus`] from invalid radii
+///
+/// See [`Annulus::from_radii`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum InvalidAnnulus {
+    /// The inner radius is zero or negative
+    InnerNotPositive(f64),
+
+    /// The inner radius is not smaller than the outer radius
+    InnerNotSmaller {
+        /// The radius of the outer circle
+        outer: f64,
+
+        /// The radius of the inner circle
+        inner: f64,
+    },
+}
+
+impl fmt::Display for InvalidAnnulus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InnerNotPositive(inner) => {
+                write!(f, "Inner radius of annulus must be positive ({inner})")
+            }
+            Self::InnerNotSmaller { outer, inner } => write!(
+                f,
+                "Inner radius of annulus ({inner}) must be smaller than outer \
+                radius ({outer})"
+            ),
+        }
+    }
+}
+
+impl error::Error for InvalidAnnulus {}
+
+/// A regular polygon
+///
+/// The polygon is centered on the origin. Its vertices are placed at evenly
+/// spaced angles on its circumscribed circle. Without rotation, the first
+/// vertex lies on the positive x-axis.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct RegularPolygon {
+    /// The number of sides
+    sides: u32,
+
+    /// The radius of the circumscribed circle
+    radius: f64,
+
+    /// The rotation of the polygon around the origin
+    rotation: Angle,
+
+    // The color of the polygon in RGBA
+    color: [u8; 4],
+}
+
+impl RegularPolygon {
+    /// Construct a regular polygon from its number of sides and its radius
+    ///
+    /// `radius` is the radius of the circumscribed circle. Returns an error,
+    /// if there are fewer than three sides, or if the radius is not positive.
+    pub fn from_sides_and_radius(
+        sides: u32,
+        radius: f64,
+    ) -> Result<Self, InvalidRegularPolygon> {
+        if sides < 3 {
+            return Err(InvalidRegularPolygon::TooFewSides(sides));
+        }
+        if radius <= 0. || radius.is_nan() {
+            return Err(InvalidRegularPolygon::RadiusNotPositive(radius));
+        }
+
+        Ok(Self {
+            sides,
+            radius,
+            rotation: Angle::from_rad(0.),
+            color: [255, 0, 0, 255],
+        })
+    }
+
+    /// Rotate the polygon around the origin
+    ///
+    /// This can be used to orient the polygon, for example to have a hexagon
+    /// stand on one of its vertices instead of on one of its sides.
+    pub fn with_rotation(mut self, rotation: Angle) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Set the rendering color of the polygon in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Access the number of sides
+    pub fn sides(&self) -> u32 {
+        self.sides
+    }
+
+    /// Access the radius of the circumscribed circle
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Access the rotation of the polygon
+    pub fn rotation(&self) -> Angle {
+        self.rotation
+    }
+
+    /// Get the rendering color of the polygon in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+
+    /// Compute the vertices of the polygon, counter-clockwise
+    pub fn vertices(&self) -> Vec<[f64; 2]> {
+        (0..self.sides)
+            .map(|i| {
+                let angle = self.rotation.rad()
+                    + std::f64::consts::TAU * f64::from(i)
+                        / f64::from(self.sides);
+                [self.radius * angle.cos(), self.radius * angle.sin()]
+            })
+            .collect()
+    }
+
+    /// Compare two polygons, allowing their dimensions to differ slightly
+    ///
+    /// See [`Shape2d::approx_eq`].
+    pub fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        self.color == other.color
+            && self.sides == other.sides
+            && (self.radius - other.radius).abs() <= tolerance
+            && (self.rotation.rad() - other.rotation.rad()).abs() <= tolerance
+    }
+}
+
+impl From<RegularPolygon> for Shape {
+    fn from(shape: RegularPolygon) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<RegularPolygon> for Shape2d {
+    fn from(shape: RegularPolygon) -> Self {
+        Self::RegularPolygon(shape)
+    }
+}
+
+/// Error creating a [`RegularPolygon`] from invalid parameters
+///
+/// See [`RegularPolygon::from_sides_and_radius`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum InvalidRegularPolygon {
+    /// The polygon has fewer than three sides
+    TooFewSides(u32),
+
+    /// The radius is zero or negative
+    RadiusNotPositive(f64),
+}
+
+impl fmt::Display for InvalidRegularPolygon {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::TooFewSides(sides) => write!(
+                f,
+                "Regular polygon must have at least three sides ({sides})"
+            ),
+            Self::RadiusNotPositive(radius) => write!(
+                f,
+                "Radius of regular polygon must be positive ({radius})"
+            ),
+        }
+    }
+}
+
+impl error::Error for InvalidRegularPolygon {}
+
+/// A rectangle with rounded corners
+///
+/// The rectangle is centered on the origin, and its sides are aligned with the
+/// x- and y-axes. Each corner is a quarter circle.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct RoundedRectangle {
+    /// The size of the rectangle along the x-axis
+    x: f64,
+
+    /// The size of the rectangle along the y-axis
+    y: f64,
+
+    /// The radius of the rounded corners
+    radius: f64,
+
+    // The color of the rounded rectangle in RGBA
+    color: [u8; 4],
+}
+
+impl RoundedRectangle {
+    /// Construct a rounded rectangle from its size and corner radius
+    ///
+    /// `x` and `y` are the overall size of the rectangle, including its
+    /// rounded corners. Returns an error, if the size or radius are not
+    /// positive, or if the radius is larger than half the smaller side.
+    pub fn from_size_and_radius(
+        x: f64,
+        y: f64,
+        radius: f64,
+    ) -> Result<Self, InvalidRoundedRectangle> {
+        if x <= 0. || y <= 0. || x.is_nan() || y.is_nan() {
+            return Err(InvalidRoundedRectangle::SizeNotPositive { x, y });
+        }
+        if radius <= 0. || radius.is_nan() {
+            return Err(InvalidRoundedRectangle::RadiusNotPositive(radius));
+        }
+        if radius > x.min(y) / 2. {
+            return Err(InvalidRoundedRectangle::RadiusTooLarge {
+                x,
+                y,
+                radius,
+            });
+        }
+
+        Ok(Self {
+            x,
+            y,
+            radius,
+            color: [255, 0, 0, 255],
+        })
+    }
+
+    /// Set the rendering color of the rounded rectangle in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Access the size of the rounded rectangle along the x-axis
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    /// Access the size of the rounded rectangle along the y-axis
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    /// Access the radius of the rounded corners
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Get the rendering color of the rounded rectangle in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+
+    /// Compare two rounded rectangles, allowing their dimensions to differ
+    /// slightly
+    ///
+    /// See [`Shape2d::approx_eq`].
+    pub fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        self.color == other.color
+            && (self.x - other.x).abs() <= tolerance
+            && (self.y - other.y).abs() <= tolerance
+            && (self.radius - other.radius).abs() <= tolerance
+    }
+}
+
+impl From<RoundedRectangle> for Shape {
+    fn from(shape: RoundedRectangle) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<RoundedRectangle> for Shape2d {
+    fn from(shape: RoundedRectangle) -> Self {
+        Self::RoundedRectangle(shape)
+    }
+}
+
+/// Error creating a [`RoundedRectangle`] from invalid dimensions
+///
+/// See [`RoundedRectangle::from_size_and_radius`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum InvalidRoundedRectangle {
+    /// The size along one of the axes is zero or negative
+    SizeNotPositive {
+        /// The size along the x-axis
+        x: f64,
+
+        /// The size along the y-axis
+        y: f64,
+    },
+
+    /// The radius is zero or negative
+    RadiusNotPositive(f64),
+
+    /// The radius is larger than half the smaller side
+    RadiusTooLarge {
+        /// The size along the x-axis
+        x: f64,
+
+        /// The size along the y-axis
+        y: f64,
+
+        /// The radius of the corners
+        radius: f64,
+    },
+}
+
+impl fmt::Display for InvalidRoundedRectangle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::SizeNotPositive { x, y } => write!(
+                f,
+                "Size of rounded rectangle must be positive ({x}, {y})"
+            ),
+            Self::RadiusNotPositive(radius) => write!(
+                f,
+                "Radius of rounded rectangle must be positive ({radius})"
+            ),
+            Self::RadiusTooLarge { x, y, radius } => write!(
+                f,
+                "Radius of rounded rectangle ({radius}) must not be larger \
+                than half its smaller side ({x}, {y})"
+            ),
+        }
+    }
+}
+
+impl error::Error for InvalidRoundedRectangle {}
+
+/// A slot, i.e. a rectangle with semicircular ends
+///
+/// The slot is centered on the origin, and its length is aligned with the
+/// x-axis.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Slot {
+    /// The overall length of the slot, including its rounded ends
+    length: f64,
+
+    /// The width of the slot, which is the diameter of its rounded ends
+    width: f64,
+
+    // The color of the slot in RGBA
+    color: [u8; 4],
+}
+
+impl Slot {
+    /// Construct a slot from its length and width
+    ///
+    /// `length` is the overall length of the slot, including its rounded ends.
+    /// `width` is the diameter of the rounded ends. Returns an error, if the
+    /// width is not positive, or if the length is not larger than the width.
+    pub fn from_length_and_width(
+        length: f64,
+        width: f64,
+    ) -> Result<Self, InvalidSlot> {
+        if width <= 0. || width.is_nan() {
+            return Err(InvalidSlot::WidthNotPositive(width));
+        }
+        if length <= width || length.is_nan() {
+            return Err(InvalidSlot::LengthNotLarger { length, width });
+        }
+
+        Ok(Self {
+            length,
+            width,
+            color: [255, 0, 0, 255],
+        })
+    }
+
+    /// Set the rendering color of the slot in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Access the overall length of the slot
+    pub fn length(&self) -> f64 {
+        self.length
+    }
+
+    /// Access the width of the slot
+    pub fn width(&self) -> f64 {
+        self.width
+    }
+
+    /// Get the rendering color of the slot in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+
+    /// Compare two slots, allowing their dimensions to differ slightly
+    ///
+    /// See [`Shape2d::approx_eq`].
+    pub fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        self.color == other.color
+            && (self.length - other.length).abs() <= tolerance
+            && (self.width - other.width).abs() <= tolerance
+    }
+}
+
+impl From<Slot> for Shape {
+    fn from(shape: Slot) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<Slot> for Shape2d {
+    fn from(shape: Slot) -> Self {
+        Self::Slot(shape)
+    }
+}
+
+/// Error creating a [`Slot`] from invalid dimensions
+///
+/// See [`Slot::from_length_and_width`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum InvalidSlot {
+    /// The width is zero or negative
+    WidthNotPositive(f64),
+
+    /// The length is not larger than the width
+    LengthNotLarger {
+        /// The overall length of the slot
+        length: f64,
+
+        /// The width of the slot
+        width: f64,
+    },
+}
+
+impl fmt::Display for InvalidSlot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::WidthNotPositive(width) => {
+                write!(f, "Width of slot must be positive ({width})")
+            }
+            Self::LengthNotLarger { length, width } => write!(
+                f,
+                "Length of slot ({length}) must be larger than its width \
+                ({width})"
+            ),
+        }
+    }
+}
+
+impl error::Error for InvalidSlot {}
+
+/// A star, i.e. a polygon whose vertices alternate between two radii
+///
+/// The star is centered on the origin. Its first outer vertex lies on the
+/// positive x-axis.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Star {
+    /// The number of points
+    points: u32,
+
+    /// The radius of the circle that the outer vertices lie on
+    outer: f64,
+
+    /// The radius of the circle that the inner vertices lie on
+    inner: f64,
+
+    // The color of the star in RGBA
+    color: [u8; 4],
+}
+
+impl Star {
+    /// Construct a star from its number of points and its radii
+    ///
+    /// Returns an error, if there are fewer than two points, if the inner
+    /// radius is not positive, or if it's not smaller than the outer radius.
+    pub fn from_points_and_radii(
+        points: u32,
+        outer: f64,
+        inner: f64,
+    ) -> Result<Self, InvalidStar> {
+        if points < 2 {
+            return Err(InvalidStar::TooFewPoints(points));
+        }
+        if inner <= 0. || inner.is_nan() {
+            return Err(InvalidStar::InnerNotPositive(inner));
+        }
+        if inner >= outer || outer.is_nan() {
+            return Err(InvalidStar::InnerNotSmaller { outer, inner });
+        }
+
+        Ok(Self {
+            points,
+            outer,
+            inner,
+            color: [255, 0, 0, 255],
+        })
+    }
+
+    /// Set the rendering color of the star in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Access the number of points
+    pub fn points(&self) -> u32 {
+        self.points
+    }
+
+    /// Access the radius of the outer vertices
+    pub fn outer(&self) -> f64 {
+        self.outer
+    }
+
+    /// Access the radius of the inner vertices
+    pub fn inner(&self) -> f64 {
+        self.inner
+    }
+
+    /// Get the rendering color of the star in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+
+    /// Compute the vertices of the star, counter-clockwise
+    ///
+    /// Starts with an outer vertex, and alternates between outer and inner
+    /// vertices from there.
+    pub fn vertices(&self) -> Vec<[f64; 2]> {
+        let num_vertices = self.points * 2;
+
+        (0..num_vertices)
+            .map(|i| {
+                let radius = if i % 2 == 0 { self.outer } else { self.inner };
+                let angle = std::f64::consts::TAU * f64::from(i)
+                    / f64::from(num_vertices);
+                [radius * angle.cos(), radius * angle.sin()]
+            })
+            .collect()
+    }
+
+    /// Compare two stars, allowing their radii to differ slightly
+    ///
+    /// See [`Shape2d::approx_eq`].
+    pub fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        self.color == other.color
+            && self.points == other.points
+            && (self.outer - other.outer).abs() <= tolerance
+            && (self.inner - other.inner).abs() <= tolerance
+    }
+}
+
+impl From<Star> for Shape {
+    fn from(shape: Star) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<Star> for Shape2d {
+    fn from(shape: Star) -> Self {
+        Self::Star(shape)
+    }
+}
+
+/// Error creating a [`Star`] from invalid parameters
+///
+/// See [`Star::from_points_and_radii`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum InvalidStar {
+    /// The star has fewer than two points
+    TooFewPoints(u32),
+
+    /// The inner radius is zero or negative
+    InnerNotPositive(f64),
+
+    /// The inner radius is not smaller than the outer radius
+    InnerNotSmaller {
+        /// The radius of the outer vertices
+        outer: f64,
+
+        /// The radius of the inner vertices
+        inner: f64,
+    },
+}
+
+impl fmt::Display for InvalidStar {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::TooFewPoints(points) => {
+                write!(f, "Star must have at least two points ({points})")
+            }
+            Self::InnerNotPositive(inner) => {
+                write!(f, "Inner radius of star must be positive ({inner})")
+            }
+            Self::InnerNotSmaller { outer, inner } => write!(
+                f,
+                "Inner radius of star ({inner}) must be smaller than outer \
+                radius ({outer})"
+            ),
+        }
+    }
+}
+
+impl error::Error for InvalidStar {}
+
+/// A difference between two shapes
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Difference2d {
+    shapes: [Shape2d; 2],
+}
+
+impl Difference2d {
+    /// Create a `Difference2d` from two shapes
+    pub fn from_shapes(shapes: [Shape2d; 2]) -> Self {
+        Self { shapes }
+    }
+
+    /// Get the rendering color of the larger object in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.shapes[0].color()
+    }
+
+    /// Access the shapes that make up the difference
+    pub fn shapes(&self) -> &[Shape2d; 2] {
+        &self.shapes
+    }
+
+    /// Compare two differences, allowing their dimensions to differ slightly
+    ///
+    /// See [`Shape2d::approx_eq`].
+    pub fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        self.shapes
+            .iter()
+            .zip(&other.shapes)
+            .all(|(a, b)| a.approx_eq(b, tolerance))
+    }
+}
+
+impl From<Difference2d> for Shape {
+    fn from(shape: Difference2d) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<Difference2d> for Shape2d {
+    fn from(shape: Difference2d) -> Self {
+        Self::Difference(Box::new(shape))
+    }
+}
+
+/// An intersection of two shapes
+///
+/// Covers the region that both shapes have in common.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Intersection2d {
+    shapes: [Shape2d; 2],
+}
+
+impl Intersection2d {
+    /// Create an `Intersection2d` from two shapes
+    pub fn from_shapes(shapes: [Shape2d; 2]) -> Self {
+        Self { shapes }
+    }
+
+    /// Get the rendering color of the first object in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.shapes[0].color()
+    }
+
+    /// Access the shapes that make up the intersection
+    pub fn shapes(&self) -> &[Shape2d; 2] {
+        &self.shapes
+    }
+
+    /// Compare two intersections, allowing their dimensions to differ slightly
+    ///
+    /// See [`Shape2d::approx_eq`].
+    pub fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        self.shapes
+            .iter()
+            .zip(&other.shapes)
+            .all(|(a, b)| a.approx_eq(b, tolerance))
+    }
+}
+
+impl From<Intersection2d> for Shape {
+    fn from(shape: Intersection2d) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<Intersection2d> for Shape2d {
+    fn from(shape: Intersection2d) -> Self {
+        Self::Intersection(Box::new(shape))
+    }
+}
+
+/// A shape whose boundary has been offset
+///
+/// Every edge of the boundary is moved along its outward normal, by the given
+/// distance. A positive distance grows the shape, and corners that are convex
+/// become rounded. A negative distance shrinks the shape.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Offset2d {
+    shape: Shape2d,
+    distance: f64,
+}
+
+impl Offset2d {
+    /// Create an `Offset2d` from a shape and the distance to offset it by
+    pub fn from_shape_and_distance(shape: Shape2d, distance: f64) -> Self {
+        Self { shape, distance }
+    }
+
+    /// Get the rendering color of the offset shape in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.shape.color()
+    }
+
+    /// Access the shape that is offset
+    pub fn shape(&self) -> &Shape2d {
+        &self.shape
+    }
+
+    /// Access the distance that the shape is offset by
+    pub fn distance(&self) -> f64 {
+        self.distance
+    }
+
+    /// Compare two offsets, allowing their dimensions to differ slightly
+    ///
+    /// See [`Shape2d::approx_eq`].
+    pub fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        self.shape.approx_eq(&other.shape, tolerance)
+            && (self.distance - other.distance).abs() <= tolerance
+    }
+}
+
+impl From<Offset2d> for Shape {
+    fn from(shape: Offset2d) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<Offset2d> for Shape2d {
+    fn from(shape: Offset2d) -> Self {
+        Self::Offset(Box::new(shape))
+    }
+}
+
+/// A union of two shapes
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Union2d {
+    shapes: [Shape2d; 2],
+}
+
+impl Union2d {
+    /// Create a `Union2d` from two shapes
+    pub fn from_shapes(shapes: [Shape2d; 2]) -> Self {
+        Self { shapes }
+    }
+
+    /// Get the rendering color of the first object in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.shapes[0].color()
+    }
+
+    /// Access the shapes that make up the union
+    pub fn shapes(&self) -> &[Shape2d; 2] {
+        &self.shapes
+    }
+
+    /// Compare two unions, allowing their dimensions to differ slightly
+    ///
+    /// See [`Shape2d::approx_eq`].
+    pub fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        self.shapes
+            .iter()
+            .zip(&other.shapes)
+            .all(|(a, b)| a.approx_eq(b, tolerance))
+    }
+}
+
+impl From<Union2d> for Shape {
+    fn from(shape: Union2d) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<Union2d> for Shape2d {
+    fn from(shape: Union2d) -> Self {
+        Self::Union(Box::new(shape))
+    }
+}
+
+/// A group of shapes
+///
+/// Unlike [`Union2d`], a group doesn't merge its shapes. It just bundles them,
+/// so they can be used as a single shape, for example to sweep a number of
+/// disjoint profiles at once.
+///
+/// # Limitations
+///
+/// Whether the shapes in the group touch or overlap is not currently checked.
+#[repr(C)]
+pub struct Group2d {
+    // The fields are the raw parts of a `Vec`. `Shape2d` needs to be FFI-safe,
+    // meaning it can't store a `Vec` directly. This takes the same detour as
+    // `PolyChain`.
+    ptr: *mut Shape2d,
+    length: usize,
+    capacity: usize,
+
+    // The group can be cloned, so we need to track the number of live
+    // instances, so as to free the buffer behind `ptr` only when the last
+    // one is dropped.
+    rc: *mut atomic::AtomicUsize,
+}
+
+impl Group2d {
+    /// Create a `Group2d` from a number of shapes
+    ///
+    /// Returns an error, if `shapes` is empty.
+    pub fn from_shapes(
+        shapes: impl IntoIterator<Item = Shape2d>,
+    ) -> Result<Self, InvalidGroup2d> {
+        let mut shapes = shapes.into_iter().collect::<Vec<_>>();
+        if shapes.is_empty() {
+            return Err(InvalidGroup2d::Empty);
+        }
+
+        // This can be cleaned up, once `Vec::into_raw_parts` is stable.
+        let ptr = shapes.as_mut_ptr();
+        let length = shapes.len();
+        let capacity = shapes.capacity();
+
+        // We're taking ownership of the memory here, so we can't allow `shapes`
+        // to deallocate it.
+        mem::forget(shapes);
+
+        // Allocate the reference counter on the heap. It will be reclaimed
+        // alongside `shapes` when it reaches 0.
+        let rc = Box::new(atomic::AtomicUsize::new(1));
+        let rc = Box::leak(rc) as *mut _;
+
+        Ok(Self {
+            ptr,
+            length,
+            capacity,
+            rc,
+        })
+    }
+
+    /// Get the rendering color of the first object in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.shapes()[0].color()
+    }
+
+    /// Access the shapes that make up the group
+    pub fn shapes(&self) -> &[Shape2d] {
+        unsafe { std::slice::from_raw_parts(self.ptr, self.length) }
+    }
+
+    /// Compare two groups, allowing their dimensions to differ slightly
+    ///
+    /// See [`Shape2d::approx_eq`].
+    pub fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        let [a, b] = [self.shapes(), other.shapes()];
+
+        a.len() == b.len()
+            && a.iter().zip(b).all(|(a, b)| a.approx_eq(b, tolerance))
+    }
 }
 
-impl Shape2d {
-    /// Get the rendering color of the larger object in RGBA
-    pub fn color(&self) -> [u8; 4] {
-        match &self {
-            Shape2d::Sketch(s) => s.color(),
-            Shape2d::Difference(d) => d.color(),
+impl Clone for Group2d {
+    fn clone(&self) -> Self {
+        // Increment the reference counter
+        unsafe {
+            (*self.rc).fetch_add(1, atomic::Ordering::AcqRel);
+        }
+
+        Self {
+            ptr: self.ptr,
+            length: self.length,
+            capacity: self.capacity,
+            rc: self.rc,
         }
     }
 }
 
-/// A difference between two shapes
-#[derive(Clone, Debug, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[repr(C)]
-pub struct Difference2d {
-    shapes: [Shape2d; 2],
+impl fmt::Debug for Group2d {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Group2d")
+            .field("shapes", &self.shapes())
+            .finish()
+    }
 }
 
-impl Difference2d {
-    /// Create a `Difference2d` from two shapes
-    pub fn from_shapes(shapes: [Shape2d; 2]) -> Self {
-        Self { shapes }
+impl PartialEq for Group2d {
+    fn eq(&self, other: &Self) -> bool {
+        self.shapes() == other.shapes()
     }
+}
 
-    /// Get the rendering color of the larger object in RGBA
-    pub fn color(&self) -> [u8; 4] {
-        self.shapes[0].color()
+impl Drop for Group2d {
+    fn drop(&mut self) {
+        // Decrement the reference counter
+        let rc_last =
+            unsafe { (*self.rc).fetch_sub(1, atomic::Ordering::AcqRel) };
+
+        // If the value of the refcount before decrementing was 1,
+        // then this must be the last Drop call. Reclaim all resources
+        // allocated on the heap.
+        if rc_last == 1 {
+            unsafe {
+                let shapes =
+                    Vec::from_raw_parts(self.ptr, self.length, self.capacity);
+                let rc = Box::from_raw(self.rc);
+
+                drop(shapes);
+                drop(rc);
+            }
+        }
     }
+}
 
-    /// Access the shapes that make up the difference
-    pub fn shapes(&self) -> &[Shape2d; 2] {
-        &self.shapes
+// `Group2d` can be `Send`, because it encapsulates the raw pointer it
+// contains, making sure memory ownership rules are observed.
+unsafe impl Send for Group2d {}
+
+#[cfg(feature = "serde")]
+impl ser::Serialize for Group2d {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        let serde_group = Group2dSerde {
+            shapes: self.shapes().to_vec(),
+        };
+
+        serde_group.serialize(serializer)
     }
 }
 
-impl From<Difference2d> for Shape {
-    fn from(shape: Difference2d) -> Self {
+#[cfg(feature = "serde")]
+impl<'de> de::Deserialize<'de> for Group2d {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        let serde_group = Group2dSerde::deserialize(deserializer)?;
+        Group2d::from_shapes(serde_group.shapes).map_err(de::Error::custom)
+    }
+}
+
+/// An owned, non-repr-C [`Group2d`]
+///
+/// Serves the same purpose as [`PolyChainSerde`], as a stepping stone for
+/// de/serializing a [`Group2d`].
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Group2d")]
+struct Group2dSerde {
+    shapes: Vec<Shape2d>,
+}
+
+/// Error creating a [`Group2d`] from an invalid list of shapes
+///
+/// See [`Group2d::from_shapes`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InvalidGroup2d {
+    /// The group contains no shapes
+    Empty,
+}
+
+impl fmt::Display for InvalidGroup2d {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "Group must contain at least one shape"),
+        }
+    }
+}
+
+impl error::Error for InvalidGroup2d {}
+
+impl From<Group2d> for Shape {
+    fn from(shape: Group2d) -> Self {
         Self::Shape2d(shape.into())
     }
 }
 
-impl From<Difference2d> for Shape2d {
-    fn from(shape: Difference2d) -> Self {
-        Self::Difference(Box::new(shape))
+impl From<Group2d> for Shape2d {
+    fn from(shape: Group2d) -> Self {
+        Self::Group(shape)
     }
 }
 
@@ -92,6 +1214,52 @@ impl Sketch {
         }
     }
 
+    /// Create a sketch from a polygon, validating it
+    ///
+    /// Like [`Sketch::from_points`], but returns an error, if the points don't
+    /// define a valid polygon. This is the case, if there are fewer than three
+    /// points, or if any two edges of the polygon intersect.
+    pub fn try_from_points(
+        points: Vec<[f64; 2]>,
+    ) -> Result<Self, InvalidPolygon> {
+        if points.len() < 3 {
+            return Err(InvalidPolygon::TooFewPoints(points.len()));
+        }
+
+        let edge = |i: usize| [points[i], points[(i + 1) % points.len()]];
+
+        for i in 0..points.len() {
+            for j in i + 1..points.len() {
+                let is_adjacent =
+                    j == i + 1 || (i == 0 && j == points.len() - 1);
+                if edges_intersect(edge(i), edge(j), is_adjacent) {
+                    return Err(InvalidPolygon::SelfIntersecting {
+                        edges: [i, j],
+                    });
+                }
+            }
+        }
+
+        Ok(Self::from_points(points))
+    }
+
+    /// Create a sketch from a triangle
+    ///
+    /// Returns an error, if the triangle is degenerate, meaning its points are
+    /// collinear. See [`Sketch::try_from_points`].
+    pub fn from_triangle(
+        points: [[f64; 2]; 3],
+    ) -> Result<Self, InvalidPolygon> {
+        let [a, b, c] = points;
+        let area2 =
+            (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0]);
+        if area2 == 0. {
+            return Err(InvalidPolygon::Collinear);
+        }
+
+        Self::try_from_points(points.to_vec())
+    }
+
     /// Create a sketch from a circle
     pub fn from_circle(circle: Circle) -> Self {
         Self {
@@ -100,6 +1268,14 @@ impl Sketch {
         }
     }
 
+    /// Create a sketch from an ellipse
+    pub fn from_ellipse(ellipse: Ellipse) -> Self {
+        Self {
+            chain: Chain::Ellipse(ellipse),
+            color: [255, 0, 0, 255],
+        }
+    }
+
     /// Set the rendering color of the sketch in RGBA
     pub fn with_color(mut self, color: [u8; 4]) -> Self {
         self.color = color;
@@ -115,6 +1291,100 @@ impl Sketch {
     pub fn color(&self) -> [u8; 4] {
         self.color
     }
+
+    /// Compare two sketches, allowing their dimensions to differ slightly
+    ///
+    /// See [`Shape2d::approx_eq`].
+    pub fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        self.color == other.color
+            && self.chain.approx_eq(&other.chain, tolerance)
+    }
+}
+
+/// Error creating a [`Sketch`] from an invalid polygon
+///
+/// See [`Sketch::try_from_points`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InvalidPolygon {
+    /// The polygon has fewer than three points
+    TooFewPoints(usize),
+
+    /// All points of the polygon are on a single line
+    Collinear,
+
+    /// Two edges of the polygon intersect
+    ///
+    /// Edges are identified by the index of their first point.
+    SelfIntersecting {
+        /// The edges that intersect
+        edges: [usize; 2],
+    },
+}
+
+impl fmt::Display for InvalidPolygon {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::TooFewPoints(num_points) => write!(
+                f,
+                "Polygon must have at least three points (has {num_points})"
+            ),
+            Self::Collinear => write!(f, "Polygon points are collinear"),
+            Self::SelfIntersecting { edges: [a, b] } => {
+                write!(f, "Polygon edges {a} and {b} intersect")
+            }
+        }
+    }
+}
+
+impl error::Error for InvalidPolygon {}
+
+/// Determine whether two edges of a polygon intersect
+///
+/// Adjacent edges always share a point, which doesn't count as an
+/// intersection. They only intersect, if they overlap.
+fn edges_intersect(
+    [a, b]: [[f64; 2]; 2],
+    [c, d]: [[f64; 2]; 2],
+    is_adjacent: bool,
+) -> bool {
+    // Positive, if `r` is to the left of the line from `p` to `q`.
+    let orient = |p: [f64; 2], q: [f64; 2], r: [f64; 2]| {
+        (q[0] - p[0]) * (r[1] - p[1]) - (q[1] - p[1]) * (r[0] - p[0])
+    };
+
+    // Whether `r`, which is collinear with `p` and `q`, is between them.
+    let is_between = |p: [f64; 2], q: [f64; 2], r: [f64; 2]| {
+        r[0] >= p[0].min(q[0])
+            && r[0] <= p[0].max(q[0])
+            && r[1] >= p[1].min(q[1])
+            && r[1] <= p[1].max(q[1])
+    };
+
+    if is_adjacent {
+        // Adjacent edges overlap, if the point of one that isn't shared lies
+        // on the other.
+        let [shared, p, q] = if b == c { [b, a, d] } else { [a, b, c] };
+
+        return (orient(shared, p, q) == 0.
+            && (is_between(shared, p, q) || is_between(shared, q, p)))
+            || p == q;
+    }
+
+    let [o1, o2, o3, o4] = [
+        orient(a, b, c),
+        orient(a, b, d),
+        orient(c, d, a),
+        orient(c, d, b),
+    ];
+
+    if o1 * o2 < 0. && o3 * o4 < 0. {
+        return true;
+    }
+
+    (o1 == 0. && is_between(a, b, c))
+        || (o2 == 0. && is_between(a, b, d))
+        || (o3 == 0. && is_between(c, d, a))
+        || (o4 == 0. && is_between(c, d, b))
 }
 
 /// A chain of elements that is part of a [`Sketch`]
@@ -125,10 +1395,29 @@ pub enum Chain {
     /// The chain is a circle
     Circle(Circle),
 
+    /// The chain is an ellipse
+    Ellipse(Ellipse),
+
     /// The chain is a polygonal chain
     PolyChain(PolyChain),
 }
 
+impl Chain {
+    /// Compare two chains, allowing their dimensions to differ slightly
+    ///
+    /// See [`Shape2d::approx_eq`].
+    pub fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        match (self, other) {
+            (Self::Circle(a), Self::Circle(b)) => a.approx_eq(b, tolerance),
+            (Self::Ellipse(a), Self::Ellipse(b)) => a.approx_eq(b, tolerance),
+            (Self::PolyChain(a), Self::PolyChain(b)) => {
+                a.approx_eq(b, tolerance)
+            }
+            _ => false,
+        }
+    }
+}
+
 /// A circle that is part of a [`Sketch`]
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -148,8 +1437,101 @@ impl Circle {
     pub fn radius(&self) -> f64 {
         self.radius
     }
+
+    /// Compare two circles, allowing their radii to differ slightly
+    ///
+    /// See [`Shape2d::approx_eq`].
+    pub fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        (self.radius - other.radius).abs() <= tolerance
+    }
+}
+
+/// An ellipse that is part of a [`Sketch`]
+///
+/// The ellipse is centered on the origin, with its semi-axes aligned to the
+/// x- and y-axis.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Ellipse {
+    /// The semi-axis along the x-axis
+    a: f64,
+
+    /// The semi-axis along the y-axis
+    b: f64,
+}
+
+impl Ellipse {
+    /// Construct a new ellipse from its semi-axes
+    ///
+    /// `a` is the semi-axis along the x-axis, `b` the one along the y-axis.
+    /// Returns an error, if either of the semi-axes is not positive.
+    pub fn from_semi_axes(a: f64, b: f64) -> Result<Self, InvalidEllipse> {
+        if a <= 0. || b <= 0. || a.is_nan() || b.is_nan() {
+            return Err(InvalidEllipse::SemiAxisNotPositive { a, b });
+        }
+
+        Ok(Self { a, b })
+    }
+
+    /// Access the semi-axis along the x-axis
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+
+    /// Access the semi-axis along the y-axis
+    pub fn b(&self) -> f64 {
+        self.b
+    }
+
+    /// Compare two ellipses, allowing their semi-axes to differ slightly
+    ///
+    /// See [`Shape2d::approx_eq`].
+    pub fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        (self.a - other.a).abs() <= tolerance
+            && (self.b - other.b).abs() <= tolerance
+    }
+}
+
+impl From<Ellipse> for Shape {
+    fn from(shape: Ellipse) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<Ellipse> for Shape2d {
+    fn from(shape: Ellipse) -> Self {
+        Shape2d::Sketch(Sketch::from_ellipse(shape))
+    }
+}
+
+/// Error creating an [`Ellipse`] from invalid semi-axes
+///
+/// See [`Ellipse::from_semi_axes`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum InvalidEllipse {
+    /// One of the semi-axes is zero or negative
+    SemiAxisNotPositive {
+        /// The semi-axis along the x-axis
+        a: f64,
+
+        /// The semi-axis along the y-axis
+        b: f64,
+    },
+}
+
+impl fmt::Display for InvalidEllipse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::SemiAxisNotPositive { a, b } => {
+                write!(f, "Semi-axes of ellipse must be positive ({a}, {b})")
+            }
+        }
+    }
 }
 
+impl error::Error for InvalidEllipse {}
+
 /// A polygonal chain that is part of a [`Sketch`]
 #[derive(Debug)]
 #[repr(C)]
@@ -216,6 +1598,19 @@ impl PolyChain {
 
         ret
     }
+
+    /// Compare two polygonal chains, allowing their points to differ slightly
+    ///
+    /// The chains are only considered equal, if they have the same number of
+    /// points. See [`Shape2d::approx_eq`].
+    pub fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        let [a, b] = [self.points(), other.points()];
+
+        a.len() == b.len()
+            && a.iter().zip(b).all(|(a, b)| {
+                a.iter().zip(b).all(|(a, b)| (a - b).abs() <= tolerance)
+            })
+    }
 }
 
 impl Clone for PolyChain {
@@ -328,6 +1723,40 @@ mod tests {
         vec![[1.0, 1.0], [2.0, 1.0], [2.0, 2.0], [1.0, 2.0]]
     }
 
+    #[test]
+    fn test_group_2d_from_shapes() {
+        let shape: Shape2d = Sketch::from_points(test_points()).into();
+
+        let group =
+            Group2d::from_shapes([shape.clone(), shape.clone()]).unwrap();
+        assert_eq!(group.shapes(), [shape.clone(), shape]);
+
+        let empty = Group2d::from_shapes(Vec::<Shape2d>::new());
+        assert_eq!(empty, Err(InvalidGroup2d::Empty));
+    }
+
+    #[test]
+    fn test_group_2d_rc() {
+        let assert_rc = |group: &Group2d, expected_rc: usize| {
+            let rc = unsafe { (*group.rc).load(atomic::Ordering::Acquire) };
+            assert_eq!(
+                rc, expected_rc,
+                "Group has rc = {rc}, expected {expected_rc}"
+            );
+        };
+
+        let shape: Shape2d = Sketch::from_points(test_points()).into();
+        let group = Group2d::from_shapes([shape]).unwrap();
+        assert_rc(&group, 1);
+
+        let clone = group.clone();
+        assert_rc(&group, 2);
+        assert_eq!(clone.shapes(), group.shapes());
+
+        drop(clone);
+        assert_rc(&group, 1);
+    }
+
     #[test]
     fn test_poly_chain_preserve_points() {
         let points = test_points();
@@ -362,6 +1791,235 @@ mod tests {
         // rc is deallocated after the last drop, so we can't assert that it's 0
     }
 
+    #[test]
+    fn test_sketch_try_from_points() {
+        assert!(Sketch::try_from_points(test_points()).is_ok());
+
+        assert_eq!(
+            Sketch::try_from_points(vec![[0., 0.], [1., 0.]]),
+            Err(InvalidPolygon::TooFewPoints(2))
+        );
+
+        // A bowtie, whose second and fourth edges cross.
+        assert_eq!(
+            Sketch::try_from_points(vec![
+                [0., 0.],
+                [1., 0.],
+                [0., 1.],
+                [1., 1.]
+            ]),
+            Err(InvalidPolygon::SelfIntersecting { edges: [1, 3] })
+        );
+
+        // The third point doubles back onto the first edge.
+        assert_eq!(
+            Sketch::try_from_points(vec![[0., 0.], [2., 0.], [1., 0.]]),
+            Err(InvalidPolygon::SelfIntersecting { edges: [0, 1] })
+        );
+    }
+
+    #[test]
+    fn test_annulus_from_radii() {
+        let annulus = Annulus::from_radii(2., 1.).unwrap();
+        assert_eq!(annulus.outer(), 2.);
+        assert_eq!(annulus.inner(), 1.);
+
+        assert_eq!(
+            Annulus::from_radii(2., 0.),
+            Err(InvalidAnnulus::InnerNotPositive(0.))
+        );
+        assert_eq!(
+            Annulus::from_radii(1., 1.),
+            Err(InvalidAnnulus::InnerNotSmaller {
+                outer: 1.,
+                inner: 1.
+            })
+        );
+    }
+
+    #[test]
+    fn test_regular_polygon_from_sides_and_radius() {
+        let hexagon = RegularPolygon::from_sides_and_radius(6, 2.).unwrap();
+        let vertices = hexagon.vertices();
+        assert_eq!(vertices.len(), 6);
+        assert_eq!(vertices[0], [2., 0.]);
+        for [x, y] in vertices {
+            assert!((x.hypot(y) - 2.).abs() < 1e-12);
+        }
+
+        // Without rotation, the hexagon has a side on top.
+        let [a, b] = [hexagon.vertices()[1], hexagon.vertices()[2]];
+        assert!((a[1] - b[1]).abs() < 1e-12);
+
+        // Rotating it by half a side puts a vertex on top instead.
+        let hexagon = hexagon.with_rotation(Angle::from_deg(30.));
+        let [x, y] = hexagon.vertices()[1];
+        assert!(x.abs() < 1e-12 && (y - 2.).abs() < 1e-12);
+
+        assert_eq!(
+            RegularPolygon::from_sides_and_radius(2, 1.),
+            Err(InvalidRegularPolygon::TooFewSides(2))
+        );
+        assert_eq!(
+            RegularPolygon::from_sides_and_radius(3, 0.),
+            Err(InvalidRegularPolygon::RadiusNotPositive(0.))
+        );
+    }
+
+    #[test]
+    fn test_rounded_rectangle_from_size_and_radius() {
+        let rectangle =
+            RoundedRectangle::from_size_and_radius(4., 2., 0.5).unwrap();
+        assert_eq!(rectangle.x(), 4.);
+        assert_eq!(rectangle.y(), 2.);
+        assert_eq!(rectangle.radius(), 0.5);
+
+        // The radius may be exactly half the smaller side.
+        assert!(RoundedRectangle::from_size_and_radius(4., 2., 1.).is_ok());
+
+        assert_eq!(
+            RoundedRectangle::from_size_and_radius(4., 0., 0.5),
+            Err(InvalidRoundedRectangle::SizeNotPositive { x: 4., y: 0. })
+        );
+        assert_eq!(
+            RoundedRectangle::from_size_and_radius(4., 2., 0.),
+            Err(InvalidRoundedRectangle::RadiusNotPositive(0.))
+        );
+        assert_eq!(
+            RoundedRectangle::from_size_and_radius(4., 2., 1.5),
+            Err(InvalidRoundedRectangle::RadiusTooLarge {
+                x: 4.,
+                y: 2.,
+                radius: 1.5
+            })
+        );
+    }
+
+    #[test]
+    fn test_ellipse_from_semi_axes() {
+        let ellipse = Ellipse::from_semi_axes(3., 1.).unwrap();
+        assert_eq!(ellipse.a(), 3.);
+        assert_eq!(ellipse.b(), 1.);
+
+        assert_eq!(
+            Ellipse::from_semi_axes(3., 0.),
+            Err(InvalidEllipse::SemiAxisNotPositive { a: 3., b: 0. })
+        );
+        assert_eq!(
+            Ellipse::from_semi_axes(-3., 1.),
+            Err(InvalidEllipse::SemiAxisNotPositive { a: -3., b: 1. })
+        );
+        assert!(Ellipse::from_semi_axes(f64::NAN, 1.).is_err());
+    }
+
+    #[test]
+    fn test_slot_from_length_and_width() {
+        let slot = Slot::from_length_and_width(3., 1.).unwrap();
+        assert_eq!(slot.length(), 3.);
+        assert_eq!(slot.width(), 1.);
+
+        assert_eq!(
+            Slot::from_length_and_width(3., 0.),
+            Err(InvalidSlot::WidthNotPositive(0.))
+        );
+        assert_eq!(
+            Slot::from_length_and_width(1., 1.),
+            Err(InvalidSlot::LengthNotLarger {
+                length: 1.,
+                width: 1.
+            })
+        );
+    }
+
+    #[test]
+    fn test_star_from_points_and_radii() {
+        let star = Star::from_points_and_radii(5, 2., 1.).unwrap();
+        let vertices = star.vertices();
+        assert_eq!(vertices.len(), 10);
+        assert_eq!(vertices[0], [2., 0.]);
+        for (i, [x, y]) in vertices.into_iter().enumerate() {
+            let expected = if i % 2 == 0 { 2. } else { 1. };
+            assert!((x.hypot(y) - expected).abs() < 1e-12);
+        }
+
+        assert_eq!(
+            Star::from_points_and_radii(1, 2., 1.),
+            Err(InvalidStar::TooFewPoints(1))
+        );
+        assert_eq!(
+            Star::from_points_and_radii(5, 2., 0.),
+            Err(InvalidStar::InnerNotPositive(0.))
+        );
+        assert_eq!(
+            Star::from_points_and_radii(5, 2., 2.),
+            Err(InvalidStar::InnerNotSmaller {
+                outer: 2.,
+                inner: 2.
+            })
+        );
+    }
+
+    #[test]
+    fn test_sketch_from_triangle() {
+        let sketch = Sketch::from_triangle([[0., 0.], [1., 0.], [0., 1.]]);
+        assert!(sketch.is_ok());
+
+        let collinear = Sketch::from_triangle([[0., 0.], [1., 0.], [2., 0.]]);
+        assert_eq!(collinear, Err(InvalidPolygon::Collinear));
+    }
+
+    #[test]
+    fn test_shape_approx_eq() {
+        let square = |size: f64| {
+            Shape2d::from(Sketch::from_points(vec![
+                [0., 0.],
+                [size, 0.],
+                [size, size],
+                [0., size],
+            ]))
+        };
+        let circle = |radius: f64| {
+            Shape2d::from(Sketch::from_circle(Circle::from_radius(radius)))
+        };
+        let difference = |size: f64, radius: f64| {
+            Shape2d::from(Difference2d::from_shapes([
+                square(size),
+                circle(radius),
+            ]))
+        };
+
+        let tolerance = 1e-9;
+
+        assert!(square(1.).approx_eq(&square(1. + 1e-12), tolerance));
+        assert!(!square(1.).approx_eq(&square(1.1), tolerance));
+
+        assert!(circle(0.1 + 0.2).approx_eq(&circle(0.3), tolerance));
+        assert!(!circle(0.3).approx_eq(&square(0.3), tolerance));
+
+        assert!(
+            difference(2., 0.1 * 3.).approx_eq(&difference(2., 0.3), tolerance)
+        );
+        assert!(!difference(2., 0.3).approx_eq(&difference(2., 0.4), tolerance));
+
+        let red = square(1.);
+        let blue = match square(1.) {
+            Shape2d::Sketch(sketch) => {
+                sketch.with_color([0, 0, 255, 255]).into()
+            }
+            Shape2d::Annulus(_)
+            | Shape2d::Difference(_)
+            | Shape2d::Group(_)
+            | Shape2d::Intersection(_)
+            | Shape2d::Offset(_)
+            | Shape2d::RegularPolygon(_)
+            | Shape2d::RoundedRectangle(_)
+            | Shape2d::Slot(_)
+            | Shape2d::Star(_)
+            | Shape2d::Union(_) => unreachable!(),
+        };
+        assert!(!red.approx_eq(&blue, tolerance));
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn test_poly_chain_serialize_loopback() {
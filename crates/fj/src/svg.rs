@@ -0,0 +1,473 @@
+//! Import of 2D profiles from SVG paths
+//!
+//! See [`import_svg_path`].
+
+use std::{error, fmt};
+
+use crate::{
+    dxf::{combine, Loop},
+    Shape2d,
+};
+
+/// Import a 2D profile from the `d` attribute of an SVG path
+///
+/// Supports the `M`, `L`, `H`, `V`, `C`, `Q`, and `Z` commands, in their
+/// absolute (uppercase) and relative (lowercase) variants. Every subpath is
+/// a closed loop. Subpaths that are not closed explicitly, using `Z`, are
+/// closed implicitly, as SVG does when filling a path.
+///
+/// Lines are imported directly. Bézier curves are flattened into line
+/// segments, such that no point of the curve is further than `tolerance` from
+/// the segments that approximate it. Smaller values result in more segments.
+///
+/// The y-axis of SVG points down, so y-coordinates are negated, to keep the
+/// profile from being mirrored.
+///
+/// Nested subpaths are interpreted like the loops of a DXF file: A subpath
+/// inside another one is a hole. See [`crate::import_dxf`] for details.
+///
+/// Returns an error, if `tolerance` is not positive, if the path data can't be
+/// parsed, or if it contains any other commands.
+pub fn import_svg_path(d: &str, tolerance: f64) -> Result<Shape2d, SvgError> {
+    if tolerance.is_nan() || tolerance <= 0. {
+        return Err(SvgError::InvalidTolerance(tolerance));
+    }
+
+    let mut parser = Parser {
+        source: d,
+        position: 0,
+    };
+
+    let mut loops = Vec::new();
+    let mut subpath = Vec::new();
+    let mut start = [0., 0.];
+    let mut current = [0., 0.];
+    let mut command = None;
+
+    loop {
+        parser.skip_separators();
+        if parser.is_at_end() {
+            break;
+        }
+
+        if let Some(next) = parser.command() {
+            command = Some(next);
+        }
+        let command_char = match command {
+            Some(command) => command,
+            None => return Err(parser.error("Expected command")),
+        };
+
+        let is_relative = command_char.is_ascii_lowercase();
+        let offset = if is_relative { current } else { [0., 0.] };
+        let point = |parser: &mut Parser| -> Result<_, SvgError> {
+            let x = parser.number()?;
+            let y = parser.number()?;
+            Ok([offset[0] + x, offset[1] + y])
+        };
+
+        match command_char.to_ascii_uppercase() {
+            'M' => {
+                current = point(&mut parser)?;
+                start = current;
+                finish_subpath(&mut subpath, &mut loops, tolerance);
+                subpath.push(current);
+
+                // Further coordinates after a move are implicit lines.
+                command = Some(if is_relative { 'l' } else { 'L' });
+            }
+            'L' => {
+                current = point(&mut parser)?;
+                subpath.push(current);
+            }
+            'H' => {
+                current[0] = offset[0] + parser.number()?;
+                subpath.push(current);
+            }
+            'V' => {
+                current[1] = offset[1] + parser.number()?;
+                subpath.push(current);
+            }
+            'C' => {
+                let controls = [
+                    current,
+                    point(&mut parser)?,
+                    point(&mut parser)?,
+                    point(&mut parser)?,
+                ];
+                subpath.extend(flatten_cubic(controls, tolerance));
+                current = controls[3];
+            }
+            'Q' => {
+                let controls =
+                    [current, point(&mut parser)?, point(&mut parser)?];
+                subpath.extend(flatten_quadratic(controls, tolerance));
+                current = controls[2];
+            }
+            'Z' => {
+                finish_subpath(&mut subpath, &mut loops, tolerance);
+                current = start;
+                subpath.push(current);
+
+                // `Z` takes no arguments, so it can't be repeated.
+                command = None;
+            }
+            _ => return Err(SvgError::UnsupportedCommand(command_char)),
+        }
+    }
+
+    finish_subpath(&mut subpath, &mut loops, tolerance);
+
+    let loops = loops
+        .into_iter()
+        .map(|points| {
+            let points = points.into_iter().map(|[x, y]| [x, -y]).collect();
+            Loop::Polygon(points)
+        })
+        .collect();
+
+    combine(loops).ok_or(SvgError::NoGeometry)
+}
+
+/// Error importing an SVG path
+///
+/// See [`import_svg_path`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum SvgError {
+    /// The flattening tolerance is not positive
+    InvalidTolerance(f64),
+
+    /// The path data could not be parsed
+    Parse {
+        /// The byte offset at which the error was detected
+        position: usize,
+
+        /// A description of the error
+        message: String,
+    },
+
+    /// The path data contains a command that can't be imported
+    UnsupportedCommand(char),
+
+    /// The path data doesn't contain any closed shapes
+    NoGeometry,
+}
+
+impl fmt::Display for SvgError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidTolerance(tolerance) => write!(
+                f,
+                "SVG flattening tolerance must be positive (is {tolerance})"
+            ),
+            Self::Parse { position, message } => write!(
+                f,
+                "Invalid SVG path data (position {position}): {message}"
+            ),
+            Self::UnsupportedCommand(command) => write!(
+                f,
+                "Unsupported SVG path command `{command}`; supported are `M`, \
+                `L`, `H`, `V`, `C`, `Q`, and `Z`"
+            ),
+            Self::NoGeometry => write!(f, "SVG path contains no geometry"),
+        }
+    }
+}
+
+impl error::Error for SvgError {}
+
+struct Parser<'a> {
+    source: &'a str,
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn is_at_end(&self) -> bool {
+        self.position >= self.source.len()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.position..].chars().next()
+    }
+
+    fn skip_separators(&mut self) {
+        while let Some(c) = self.peek() {
+            if !c.is_whitespace() && c != ',' {
+                break;
+            }
+            self.position += c.len_utf8();
+        }
+    }
+
+    fn command(&mut self) -> Option<char> {
+        let c = self.peek().filter(char::is_ascii_alphabetic)?;
+        self.position += 1;
+        Some(c)
+    }
+
+    fn number(&mut self) -> Result<f64, SvgError> {
+        self.skip_separators();
+
+        let start = self.position;
+        let bytes = self.source.as_bytes();
+        let digits = |position: &mut usize| {
+            let start = *position;
+            while *position < bytes.len() && bytes[*position].is_ascii_digit() {
+                *position += 1;
+            }
+            *position > start
+        };
+
+        let mut end = start;
+        if end < bytes.len() && matches!(bytes[end], b'+' | b'-') {
+            end += 1;
+        }
+        let mut has_digits = digits(&mut end);
+        if end < bytes.len() && bytes[end] == b'.' {
+            end += 1;
+            has_digits |= digits(&mut end);
+        }
+        if !has_digits {
+            return Err(self.error("Expected number"));
+        }
+
+        // Only consume an exponent, if it is complete. Otherwise the `e`
+        // might be something else.
+        if end < bytes.len() && matches!(bytes[end], b'e' | b'E') {
+            let mut exponent = end + 1;
+            if exponent < bytes.len() && matches!(bytes[exponent], b'+' | b'-')
+            {
+                exponent += 1;
+            }
+            if digits(&mut exponent) {
+                end = exponent;
+            }
+        }
+
+        let number = self.source[start..end]
+            .parse()
+            .map_err(|_| self.error("Invalid number"))?;
+        self.position = end;
+
+        Ok(number)
+    }
+
+    fn error(&self, message: &str) -> SvgError {
+        SvgError::Parse {
+            position: self.position,
+            message: message.to_string(),
+        }
+    }
+}
+
+/// Add a subpath to the list of loops, if it encloses an area
+///
+/// Subpaths whose last point is the same as their first are closed already.
+/// All others are closed implicitly.
+fn finish_subpath(
+    subpath: &mut Vec<[f64; 2]>,
+    loops: &mut Vec<Vec<[f64; 2]>>,
+    tolerance: f64,
+) {
+    let mut points = std::mem::take(subpath);
+
+    if let (Some(first), Some(last)) = (points.first(), points.last()) {
+        if points.len() > 1
+            && (first[0] - last[0]).hypot(first[1] - last[1]) <= tolerance
+        {
+            points.pop();
+        }
+    }
+
+    if points.len() >= 3 {
+        loops.push(points);
+    }
+}
+
+/// Flatten a cubic Bézier curve, omitting its first point
+///
+/// The distance between a curve and the polyline that evaluates it at `n`
+/// uniform steps is at most `max |B''| / (8 * n^2)`. For a cubic curve,
+/// `max |B''|` is at most 6 times the largest second difference of its control
+/// points.
+fn flatten_cubic(
+    [p0, p1, p2, p3]: [[f64; 2]; 4],
+    tolerance: f64,
+) -> Vec<[f64; 2]> {
+    let second_difference =
+        second_difference(p0, p1, p2).max(second_difference(p1, p2, p3));
+    let num_segments = num_segments(6. * second_difference, tolerance);
+
+    (1..=num_segments)
+        .map(|i| {
+            let t = i as f64 / num_segments as f64;
+            let s = 1. - t;
+            let weights =
+                [s * s * s, 3. * s * s * t, 3. * s * t * t, t * t * t];
+            combine_points(&[p0, p1, p2, p3], &weights)
+        })
+        .collect()
+}
+
+/// Flatten a quadratic Bézier curve, omitting its first point
+///
+/// See [`flatten_cubic`]. For a quadratic curve, `B''` is constant, and twice
+/// the second difference of its control points.
+fn flatten_quadratic(
+    [p0, p1, p2]: [[f64; 2]; 3],
+    tolerance: f64,
+) -> Vec<[f64; 2]> {
+    let num_segments =
+        num_segments(2. * second_difference(p0, p1, p2), tolerance);
+
+    (1..=num_segments)
+        .map(|i| {
+            let t = i as f64 / num_segments as f64;
+            let s = 1. - t;
+            let weights = [s * s, 2. * s * t, t * t];
+            combine_points(&[p0, p1, p2], &weights)
+        })
+        .collect()
+}
+
+fn second_difference(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> f64 {
+    (a[0] - 2. * b[0] + c[0]).hypot(a[1] - 2. * b[1] + c[1])
+}
+
+fn num_segments(max_second_derivative: f64, tolerance: f64) -> usize {
+    let n = (max_second_derivative / (8. * tolerance)).sqrt().ceil();
+    n.max(1.) as usize
+}
+
+fn combine_points(points: &[[f64; 2]], weights: &[f64]) -> [f64; 2] {
+    points
+        .iter()
+        .zip(weights)
+        .fold([0., 0.], |[x, y], (point, weight)| {
+            [x + point[0] * weight, y + point[1] * weight]
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Chain, Shape2d};
+
+    use super::{import_svg_path, SvgError};
+
+    fn polygon_points(shape: &Shape2d) -> Vec<[f64; 2]> {
+        match shape {
+            Shape2d::Sketch(sketch) => match sketch.chain() {
+                Chain::PolyChain(chain) => chain.to_points(),
+                chain => panic!("Expected polygon, got {chain:?}"),
+            },
+            shape => panic!("Expected sketch, got {shape:?}"),
+        }
+    }
+
+    #[test]
+    fn test_import_svg_path_cubic_bezier() {
+        let tolerance = 0.01;
+        let shape =
+            import_svg_path("M 0,0 L 10,0 C 10,5 5,10 0,10 Z", tolerance)
+                .unwrap();
+
+        // The y-axis is flipped.
+        let points = polygon_points(&shape);
+        for point in [[0., 0.], [10., 0.], [0., -10.]] {
+            assert!(points.contains(&point));
+        }
+
+        // Two points from the line, and 20 segments for the curve.
+        assert_eq!(points.len(), 22);
+
+        // All segments that approximate the curve are close to it.
+        let curve = (0..=10_000)
+            .map(|i| {
+                let t = f64::from(i) / 10_000.;
+                let s = 1. - t;
+                let x = 3. * s * s * t * 10. + 3. * s * t * t * 5.;
+                let y = 3. * s * s * t * -5.
+                    + 3. * s * t * t * -10.
+                    + t * t * t * -10.;
+                [x + s * s * s * 10., y]
+            })
+            .collect::<Vec<_>>();
+        for segment in points.windows(2) {
+            let [a, b] = [segment[0], segment[1]];
+            if a[1] == 0. && b[1] == 0. {
+                continue;
+            }
+
+            let middle = [(a[0] + b[0]) / 2., (a[1] + b[1]) / 2.];
+            let distance = curve
+                .iter()
+                .map(|p| (p[0] - middle[0]).hypot(p[1] - middle[1]))
+                .fold(f64::INFINITY, f64::min);
+            assert!(distance <= tolerance + 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_import_svg_path_relative_commands() {
+        let shape =
+            import_svg_path("m 1 1 h 10 v 10 q -5 5 -10 0 z", 0.01).unwrap();
+
+        let points = polygon_points(&shape);
+        for point in [[1., -1.], [11., -1.], [11., -11.], [1., -11.]] {
+            assert!(points.contains(&point));
+        }
+        assert_eq!(points.len(), 3 + 16);
+
+        for [x, y] in points {
+            assert!((1. ..=11.).contains(&x));
+            assert!((-16. ..=-1.).contains(&y));
+        }
+    }
+
+    #[test]
+    fn test_import_svg_path_subpaths() {
+        let shape =
+            import_svg_path("M0 0 H10 V10 H0 Z M2 2 H4 V4 H2 Z", 0.1).unwrap();
+        match shape {
+            Shape2d::Difference(difference) => {
+                let [outer, hole] = difference.shapes();
+                assert_eq!(polygon_points(outer).len(), 4);
+                assert_eq!(polygon_points(hole).len(), 4);
+            }
+            shape => panic!("Expected difference, got {shape:?}"),
+        }
+
+        // Subpaths are closed implicitly, and coordinates after a move are
+        // lines. The y-axis is flipped, so the polygon is reversed to keep it
+        // counter-clockwise.
+        let shape = import_svg_path("M0,0 1e1,0 10,10", 0.1).unwrap();
+        assert_eq!(
+            polygon_points(&shape),
+            vec![[10., -10.], [10., 0.], [0., 0.]]
+        );
+    }
+
+    #[test]
+    fn test_import_svg_path_errors() {
+        assert_eq!(
+            import_svg_path("M0 0 H10 V10 Z", 0.),
+            Err(SvgError::InvalidTolerance(0.))
+        );
+        assert_eq!(
+            import_svg_path("M 0 0 A 1 1 0 0 1 2 2", 0.1),
+            Err(SvgError::UnsupportedCommand('A'))
+        );
+        assert!(matches!(
+            import_svg_path("M 0 0 L 1 x", 0.1),
+            Err(SvgError::Parse { position: 10, .. })
+        ));
+        assert!(matches!(
+            import_svg_path("10 10", 0.1),
+            Err(SvgError::Parse { position: 0, .. })
+        ));
+        assert_eq!(
+            import_svg_path("M 0 0 L 10 0", 0.1),
+            Err(SvgError::NoGeometry)
+        );
+    }
+}
@@ -13,12 +13,47 @@ pub struct Sweep {
 
     /// The length and direction of the sweep
     path: [f64; 3],
+
+    /// The angle by which the shape is rotated over the sweep, in radians
+    twist: f64,
+
+    /// Whether the sweep extends to both sides of the shape
+    symmetric: bool,
 }
 
 impl Sweep {
     /// Create a `Sweep` along a straight path
     pub fn from_path(shape: Shape2d, path: [f64; 3]) -> Self {
-        Self { shape, path }
+        Self {
+            shape,
+            path,
+            twist: 0.,
+            symmetric: false,
+        }
+    }
+
+    /// Rotate the shape around the path, while it is being swept
+    ///
+    /// The shape is rotated around an axis that points in the direction of the
+    /// path and goes through the origin. `twist` is the total angle of that
+    /// rotation, in radians. Unlike [`Angle`], it is not limited to a single
+    /// turn, and can be negative to rotate in the other direction.
+    ///
+    /// [`Angle`]: crate::Angle
+    pub fn with_twist(mut self, twist: f64) -> Self {
+        self.twist = twist;
+        self
+    }
+
+    /// Sweep the shape symmetrically, to both sides of its plane
+    ///
+    /// The sweep starts half the path behind the shape and ends half the path
+    /// in front of it, which means the plane of the shape becomes the plane of
+    /// symmetry of the result. The total length of the sweep is the length of
+    /// the path, as without this option.
+    pub fn with_symmetric(mut self, symmetric: bool) -> Self {
+        self.symmetric = symmetric;
+        self
     }
 
     /// Access the shape being swept
@@ -30,6 +65,16 @@ impl Sweep {
     pub fn path(&self) -> [f64; 3] {
         self.path
     }
+
+    /// Access the twist of the sweep, in radians
+    pub fn twist(&self) -> f64 {
+        self.twist
+    }
+
+    /// Indicate whether the sweep extends to both sides of the shape
+    pub fn is_symmetric(&self) -> bool {
+        self.symmetric
+    }
 }
 
 impl From<Sweep> for Shape {
@@ -28,6 +28,74 @@ where
     }
 }
 
+/// Convenient syntax to create an [`fj::Intersection2d`]
+///
+/// [`fj::Intersection2d`]: crate::Intersection2d
+pub trait Intersection {
+    /// Create an intersection of `self` and `other`
+    fn intersection<Other>(&self, other: &Other) -> crate::Intersection2d
+    where
+        Other: Clone + Into<crate::Shape2d>;
+}
+
+impl<T> Intersection for T
+where
+    T: Clone + Into<crate::Shape2d>,
+{
+    fn intersection<Other>(&self, other: &Other) -> crate::Intersection2d
+    where
+        Other: Clone + Into<crate::Shape2d>,
+    {
+        let a = self.clone().into();
+        let b = other.clone().into();
+
+        crate::Intersection2d::from_shapes([a, b])
+    }
+}
+
+/// Convenient syntax to create an [`fj::Offset2d`]
+///
+/// [`fj::Offset2d`]: crate::Offset2d
+pub trait Offset {
+    /// Offset the boundary of `self` by `distance`
+    fn offset(&self, distance: f64) -> crate::Offset2d;
+}
+
+impl<T> Offset for T
+where
+    T: Clone + Into<crate::Shape2d>,
+{
+    fn offset(&self, distance: f64) -> crate::Offset2d {
+        let shape = self.clone().into();
+        crate::Offset2d::from_shape_and_distance(shape, distance)
+    }
+}
+
+/// Convenient syntax to create an [`fj::Union2d`]
+///
+/// [`fj::Union2d`]: crate::Union2d
+pub trait Union {
+    /// Create a union of `self` and `other`
+    fn union<Other>(&self, other: &Other) -> crate::Union2d
+    where
+        Other: Clone + Into<crate::Shape2d>;
+}
+
+impl<T> Union for T
+where
+    T: Clone + Into<crate::Shape2d>,
+{
+    fn union<Other>(&self, other: &Other) -> crate::Union2d
+    where
+        Other: Clone + Into<crate::Shape2d>,
+    {
+        let a = self.clone().into();
+        let b = other.clone().into();
+
+        crate::Union2d::from_shapes([a, b])
+    }
+}
+
 /// Convenient syntax to create an [`fj::Group`]
 ///
 /// [`fj::Group`]: crate::Group